@@ -0,0 +1,295 @@
+use rust_decimal::Decimal;
+
+use crate::book::LocalOrderBook;
+use crate::error::{Error, Result};
+use crate::orders::walk_book_shares;
+use crate::types::{OrderBookSummary, PriceLevel, Side};
+
+/// Levels of one side of a book, best price first
+pub type Levels<'a> = Box<dyn Iterator<Item = PriceLevel> + 'a>;
+
+/// Read access to both sides of an order book
+///
+/// Levels are borrowed from the book and yielded one at a time, so metrics
+/// that only look at the top of the book never copy the whole side.
+pub trait BookLevels {
+    /// Bids, best (highest) price first
+    fn bid_levels(&self) -> Levels<'_>;
+
+    /// Asks, best (lowest) price first
+    fn ask_levels(&self) -> Levels<'_>;
+
+    /// Levels of one side of the book, best price first
+    ///
+    /// `Side::Buy` is the bid side and `Side::Sell` the ask side.
+    fn levels(&self, side: Side) -> Levels<'_> {
+        match side {
+            Side::Buy => self.bid_levels(),
+            Side::Sell => self.ask_levels(),
+        }
+    }
+}
+
+impl BookLevels for LocalOrderBook {
+    fn bid_levels(&self) -> Levels<'_> {
+        Box::new(self.bids())
+    }
+
+    fn ask_levels(&self) -> Levels<'_> {
+        Box::new(self.asks())
+    }
+}
+
+/// The server sends bids ascending and asks descending, which is walked in
+/// reverse. Levels in any other order are sorted once per call.
+impl BookLevels for OrderBookSummary {
+    fn bid_levels(&self) -> Levels<'_> {
+        best_first(&self.bids, |a, b| a.price > b.price)
+    }
+
+    fn ask_levels(&self) -> Levels<'_> {
+        best_first(&self.asks, |a, b| a.price < b.price)
+    }
+}
+
+/// Iterate `levels` best first, where `better(a, b)` is true if `a` ranks
+/// strictly ahead of `b`
+fn best_first(
+    levels: &[PriceLevel],
+    better: impl Fn(&PriceLevel, &PriceLevel) -> bool,
+) -> Levels<'_> {
+    if levels.windows(2).all(|w| !better(&w[0], &w[1])) {
+        return Box::new(levels.iter().rev().cloned());
+    }
+    if levels.windows(2).all(|w| !better(&w[1], &w[0])) {
+        return Box::new(levels.iter().cloned());
+    }
+
+    let mut sorted: Vec<&PriceLevel> = levels.iter().collect();
+    sorted.sort_by(|a, b| {
+        if better(a, b) {
+            std::cmp::Ordering::Less
+        } else if better(b, a) {
+            std::cmp::Ordering::Greater
+        } else {
+            std::cmp::Ordering::Equal
+        }
+    });
+    Box::new(sorted.into_iter().cloned())
+}
+
+/// Microprice from the top of the book
+///
+/// `(bid * ask_size + ask * bid_size) / (bid_size + ask_size)`: the midpoint
+/// leans towards the side with less resting size, which is the side more
+/// likely to be taken out next.
+///
+/// Returns `None` if either side is empty.
+pub fn microprice(book: &impl BookLevels) -> Option<Decimal> {
+    let bid = book.bid_levels().next()?;
+    let ask = book.ask_levels().next()?;
+
+    let total = bid.size + ask.size;
+    if total.is_zero() {
+        return None;
+    }
+    Some((bid.price * ask.size + ask.price * bid.size) / total)
+}
+
+/// Total resting size within `ticks` ticks of the best price on one side
+///
+/// `Side::Buy` measures the bids and `Side::Sell` the asks. The best level
+/// counts as zero ticks away, so `ticks = 0` returns the size at the best
+/// price.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidParameter`] if `tick_size` is not positive.
+pub fn depth_within_ticks(
+    book: &impl BookLevels,
+    side: Side,
+    ticks: u32,
+    tick_size: Decimal,
+) -> Result<Decimal> {
+    check_tick_size(tick_size)?;
+
+    let mut levels = book.levels(side).peekable();
+    let Some(best) = levels.peek().map(|l| l.price) else {
+        return Ok(Decimal::ZERO);
+    };
+
+    let distance = tick_size * Decimal::from(ticks);
+    Ok(levels
+        .take_while(|l| (l.price - best).abs() <= distance)
+        .map(|l| l.size)
+        .sum())
+}
+
+/// Bid/ask size imbalance over the top `levels` levels of each side
+///
+/// `(bid_size - ask_size) / (bid_size + ask_size)`, ranging from `-1` (only
+/// asks) to `1` (only bids).
+///
+/// Returns `None` if both sides are empty.
+pub fn imbalance(book: &impl BookLevels, levels: usize) -> Option<Decimal> {
+    let bid_size: Decimal = book.bid_levels().take(levels).map(|l| l.size).sum();
+    let ask_size: Decimal = book.ask_levels().take(levels).map(|l| l.size).sum();
+
+    let total = bid_size + ask_size;
+    if total.is_zero() {
+        return None;
+    }
+    Some((bid_size - ask_size) / total)
+}
+
+/// Volume-weighted average price to take `size` shares
+///
+/// `side` is the taker side: a BUY walks the asks and a SELL walks the bids.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidOrder`] if the book cannot fill `size` shares.
+pub fn vwap(book: &impl BookLevels, side: Side, size: Decimal) -> Result<Decimal> {
+    let levels: Vec<PriceLevel> = book.levels(side.opposite()).collect();
+    walk_book_shares(&levels, size, side).map(|walk| walk.average_price)
+}
+
+/// Bid/ask spread in ticks
+///
+/// Returns `None` if either side is empty.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidParameter`] if `tick_size` is not positive.
+pub fn spread_ticks(book: &impl BookLevels, tick_size: Decimal) -> Result<Option<Decimal>> {
+    check_tick_size(tick_size)?;
+
+    let bid = book.bid_levels().next();
+    let ask = book.ask_levels().next();
+    Ok(bid
+        .zip(ask)
+        .map(|(bid, ask)| (ask.price - bid.price) / tick_size))
+}
+
+/// Midpoint of the size-weighted average bid and ask prices
+///
+/// Each side's price is averaged over its top `levels` levels, weighted by
+/// size, and the two averages are then averaged. Compared to the plain
+/// midpoint this is less sensitive to small orders at the top of the book.
+///
+/// Returns `None` if either side has no size within `levels`.
+pub fn liquidity_weighted_mid(book: &impl BookLevels, levels: usize) -> Option<Decimal> {
+    let bid = weighted_price(book.bid_levels(), levels)?;
+    let ask = weighted_price(book.ask_levels(), levels)?;
+    Some((bid + ask) / Decimal::TWO)
+}
+
+fn weighted_price(levels: Levels<'_>, count: usize) -> Option<Decimal> {
+    let (size, notional) = levels
+        .take(count)
+        .fold((Decimal::ZERO, Decimal::ZERO), |(size, notional), l| {
+            (size + l.size, notional + l.price * l.size)
+        });
+    if size.is_zero() {
+        return None;
+    }
+    Some(notional / size)
+}
+
+fn check_tick_size(tick_size: Decimal) -> Result<()> {
+    if tick_size <= Decimal::ZERO {
+        return Err(Error::InvalidParameter(format!(
+            "tick size must be positive, got {}",
+            tick_size
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn level(price: Decimal, size: Decimal) -> PriceLevel {
+        PriceLevel { price, size }
+    }
+
+    fn summary() -> OrderBookSummary {
+        // Server order: bids ascending, asks descending
+        OrderBookSummary {
+            market: "m".to_string(),
+            asset_id: "1".to_string(),
+            hash: String::new(),
+            timestamp: 0,
+            bids: vec![
+                level(dec!(0.46), dec!(300)),
+                level(dec!(0.47), dec!(100)),
+                level(dec!(0.48), dec!(100)),
+            ],
+            asks: vec![level(dec!(0.53), dec!(100)), level(dec!(0.52), dec!(300))],
+        }
+    }
+
+    #[test]
+    fn test_microprice_and_imbalance() {
+        let book = summary();
+        // (0.48 * 300 + 0.52 * 100) / 400
+        assert_eq!(microprice(&book), Some(dec!(0.49)));
+        // top level: (100 - 300) / 400
+        assert_eq!(imbalance(&book, 1), Some(dec!(-0.5)));
+        // all levels: (500 - 400) / 900
+        assert_eq!(imbalance(&book, 10).unwrap().round_dp(4), dec!(0.1111));
+    }
+
+    #[test]
+    fn test_depth_spread_and_vwap() {
+        let book = summary();
+        assert_eq!(
+            depth_within_ticks(&book, Side::Buy, 0, dec!(0.01)).unwrap(),
+            dec!(100)
+        );
+        assert_eq!(
+            depth_within_ticks(&book, Side::Buy, 1, dec!(0.01)).unwrap(),
+            dec!(200)
+        );
+        assert_eq!(
+            depth_within_ticks(&book, Side::Sell, 5, dec!(0.01)).unwrap(),
+            dec!(400)
+        );
+        assert!(depth_within_ticks(&book, Side::Sell, 5, Decimal::ZERO).is_err());
+
+        assert_eq!(spread_ticks(&book, dec!(0.01)).unwrap(), Some(dec!(4)));
+        assert!(spread_ticks(&book, Decimal::ZERO).is_err());
+
+        // 300 @ 0.52 + 100 @ 0.53
+        assert_eq!(vwap(&book, Side::Buy, dec!(400)).unwrap(), dec!(0.5225));
+        assert!(vwap(&book, Side::Buy, dec!(401)).is_err());
+    }
+
+    #[test]
+    fn test_local_book_matches_summary() {
+        let book = summary();
+        let local = LocalOrderBook::from_summary(&book);
+
+        // bids: (0.48*100 + 0.47*100) / 200 = 0.475, asks: (0.52*300 + 0.53*100) / 400 = 0.5225
+        assert_eq!(liquidity_weighted_mid(&book, 2), Some(dec!(0.49875)));
+        assert_eq!(liquidity_weighted_mid(&local, 2), Some(dec!(0.49875)));
+        assert_eq!(microprice(&local), microprice(&book));
+    }
+
+    #[test]
+    fn test_summary_levels_in_any_order() {
+        let mut book = summary();
+        let expected: Vec<Decimal> = book.bid_levels().map(|l| l.price).collect();
+        assert_eq!(expected, vec![dec!(0.48), dec!(0.47), dec!(0.46)]);
+
+        book.bids.reverse();
+        let bids: Vec<Decimal> = book.bid_levels().map(|l| l.price).collect();
+        assert_eq!(bids, expected);
+
+        book.bids.swap(0, 1);
+        let bids: Vec<Decimal> = book.bid_levels().map(|l| l.price).collect();
+        assert_eq!(bids, expected);
+    }
+}
@@ -0,0 +1,19 @@
+//! Order book microstructure measures.
+//!
+//! All measures are computed with `Decimal` precision from any book that
+//! implements [`BookLevels`], which includes [`OrderBookSummary`](crate::types::OrderBookSummary)
+//! and [`LocalOrderBook`](crate::book::LocalOrderBook):
+//!
+//! - [`microprice`]: Top-of-book midpoint weighted by the opposite side's size
+//! - [`depth_within_ticks`]: Resting size within a number of ticks of the best price
+//! - [`imbalance`]: Bid/ask size imbalance over the top levels
+//! - [`vwap`]: Average fill price for a given number of shares
+//! - [`spread_ticks`]: Bid/ask spread measured in ticks
+//! - [`liquidity_weighted_mid`]: Midpoint of the size-weighted bid and ask prices
+
+mod metrics;
+
+pub use metrics::{
+    depth_within_ticks, imbalance, liquidity_weighted_mid, microprice, spread_ticks, vwap,
+    BookLevels, Levels,
+};
@@ -0,0 +1,204 @@
+use futures_util::{Stream, StreamExt};
+
+use super::{BacktestReport, SimExchange, Strategy};
+use crate::types::WsEvent;
+
+/// Runs a [`Strategy`] against a stream of market events
+///
+/// For every event the simulated exchange is updated first, then the
+/// strategy is notified in this order: fills caused by the event, changed
+/// books, the trade (for `last_trade_price` events), and finally fills caused
+/// by orders the strategy submitted from its callbacks.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::backtest::{Backtest, SimExchange, Strategy};
+/// use polymarket_rs::book::LocalOrderBook;
+/// use polymarket_rs::recording::Replay;
+/// use polymarket_rs::types::{Market, OrderArgs, Side};
+/// use rust_decimal_macros::dec;
+///
+/// struct JoinBid;
+///
+/// impl Strategy for JoinBid {
+///     fn on_book(&mut self, exchange: &mut SimExchange, book: &LocalOrderBook) {
+///         if exchange.open_orders().next().is_none() {
+///             if let Some(bid) = book.best_bid() {
+///                 let args = OrderArgs::new(book.asset_id(), bid.price, dec!(10), Side::Buy);
+///                 let _ = exchange.submit_order(&args);
+///             }
+///         }
+///     }
+/// }
+///
+/// # async fn run(market: Market) -> Result<(), Box<dyn std::error::Error>> {
+/// let exchange = SimExchange::new(dec!(1000)).with_market(&market);
+/// let mut backtest = Backtest::new(JoinBid, exchange);
+///
+/// let report = backtest.run(Replay::from_dir("recordings", "market")?.stream()).await;
+/// println!("PnL {} over {} fills", report.total_pnl, report.fills.len());
+/// # Ok(())
+/// # }
+/// ```
+pub struct Backtest<S> {
+    strategy: S,
+    exchange: SimExchange,
+}
+
+impl<S: Strategy> Backtest<S> {
+    pub fn new(strategy: S, exchange: SimExchange) -> Self {
+        Self { strategy, exchange }
+    }
+
+    pub fn strategy(&self) -> &S {
+        &self.strategy
+    }
+
+    pub fn exchange(&self) -> &SimExchange {
+        &self.exchange
+    }
+
+    /// Process a single market event
+    pub fn process_event(&mut self, event: &WsEvent) {
+        let changed = self.exchange.apply_event(event);
+        self.dispatch_fills();
+
+        for token_id in changed {
+            if let Some(book) = self.exchange.book(&token_id).cloned() {
+                self.strategy.on_book(&mut self.exchange, &book);
+                self.dispatch_fills();
+            }
+        }
+
+        if let WsEvent::LastTradePrice(trade) = event {
+            self.strategy.on_trade(&mut self.exchange, trade);
+            self.dispatch_fills();
+        }
+    }
+
+    /// Process every event of a stream and report the result
+    ///
+    /// Stream errors, such as messages that cannot be parsed, are logged and
+    /// skipped.
+    pub async fn run<St>(&mut self, events: St) -> BacktestReport
+    where
+        St: Stream<Item = crate::error::Result<WsEvent>>,
+    {
+        futures_util::pin_mut!(events);
+        while let Some(event) = events.next().await {
+            match event {
+                Ok(event) => self.process_event(&event),
+                Err(e) => log::warn!("Skipping event: {}", e),
+            }
+        }
+        self.report()
+    }
+
+    /// Summary of the backtest so far
+    pub fn report(&self) -> BacktestReport {
+        self.exchange.report()
+    }
+
+    /// Notify the strategy of new fills, including fills of orders it submits
+    /// while handling them
+    fn dispatch_fills(&mut self) {
+        loop {
+            let fills = self.exchange.take_new_fills();
+            if fills.is_empty() {
+                break;
+            }
+            for fill in &fills {
+                self.strategy.on_fill(&mut self.exchange, fill);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backtest::{Fill, MarketRules};
+    use crate::book::LocalOrderBook;
+    use crate::types::{BookEvent, LastTradePriceEvent, OrderArgs, PriceLevel, Side};
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    /// Bids at the best bid, then offers every filled share one tick higher
+    #[derive(Default)]
+    struct Scalper {
+        books: usize,
+        fills: Vec<Fill>,
+    }
+
+    impl Strategy for Scalper {
+        fn on_book(&mut self, exchange: &mut SimExchange, book: &LocalOrderBook) {
+            self.books += 1;
+            if let Some(bid) = book.best_bid() {
+                let args = OrderArgs::new(book.asset_id(), bid.price, dec!(10), Side::Buy);
+                exchange.submit_order(&args).unwrap();
+            }
+        }
+
+        fn on_fill(&mut self, exchange: &mut SimExchange, fill: &Fill) {
+            self.fills.push(fill.clone());
+            if fill.side == Side::Buy {
+                let args = OrderArgs::new(
+                    &fill.token_id,
+                    fill.price + dec!(0.01),
+                    fill.size,
+                    Side::Sell,
+                );
+                exchange.submit_order(&args).unwrap();
+            }
+        }
+    }
+
+    fn trade(side: Side, price: Decimal, size: Decimal) -> WsEvent {
+        WsEvent::LastTradePrice(LastTradePriceEvent {
+            event_type: "last_trade_price".to_string(),
+            market: "m".to_string(),
+            asset_id: "1".to_string(),
+            price,
+            size,
+            fee_rate_bps: Decimal::ZERO,
+            side,
+            timestamp: "2".to_string(),
+            transaction_hash: "0x".to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_strategy_round_trip() {
+        let mut exchange = SimExchange::new(dec!(100));
+        exchange.add_token("1", MarketRules::new(dec!(0.01), dec!(5)));
+
+        let events = vec![
+            Ok(WsEvent::Book(BookEvent {
+                event_type: "book".to_string(),
+                market: "m".to_string(),
+                asset_id: "1".to_string(),
+                timestamp: "1".to_string(),
+                hash: String::new(),
+                bids: vec![PriceLevel {
+                    price: dec!(0.40),
+                    size: dec!(5),
+                }],
+                asks: vec![],
+                last_trade_price: None,
+            })),
+            Ok(trade(Side::Sell, dec!(0.40), dec!(15))),
+            Ok(trade(Side::Buy, dec!(0.41), dec!(10))),
+        ];
+
+        let mut backtest = Backtest::new(Scalper::default(), exchange);
+        let report = backtest.run(futures_util::stream::iter(events)).await;
+
+        assert_eq!(backtest.strategy().books, 1);
+        let sides: Vec<_> = backtest.strategy().fills.iter().map(|f| f.side).collect();
+        assert_eq!(sides, vec![Side::Buy, Side::Sell]);
+        assert_eq!(report.realized_pnl, dec!(0.10));
+        assert_eq!(report.inventory["1"], Decimal::ZERO);
+        assert!(report.open_orders.is_empty());
+    }
+}
@@ -0,0 +1,939 @@
+use rust_decimal::Decimal;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use crate::book::LocalOrderBook;
+use crate::error::{Error, Result};
+use crate::orders::walk_market_order_protected;
+use crate::types::{
+    LastTradePriceEvent, Market, MarketOrderArgs, OrderArgs, OrderType, PriceLevel, Side, WsEvent,
+};
+
+/// Trading rules of a token
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MarketRules {
+    /// Price increment orders must be placed on
+    pub tick_size: Decimal,
+    /// Smallest order size, in shares
+    pub minimum_order_size: Decimal,
+}
+
+impl MarketRules {
+    pub fn new(tick_size: Decimal, minimum_order_size: Decimal) -> Self {
+        Self {
+            tick_size,
+            minimum_order_size,
+        }
+    }
+
+    /// Rules shared by both tokens of a market
+    pub fn from_market(market: &Market) -> Self {
+        Self::new(market.minimum_tick_size, market.minimum_order_size)
+    }
+
+    /// Check a limit price and size against the rules
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidOrder`] if the price is off the tick grid or
+    /// outside `[tick_size, 1 - tick_size]`, or the size is below the minimum.
+    pub fn validate(&self, price: Decimal, size: Decimal) -> Result<()> {
+        if !self.tick_size.is_zero() && !(price % self.tick_size).is_zero() {
+            return Err(Error::InvalidOrder(format!(
+                "Price {} is not a multiple of tick size {}",
+                price, self.tick_size
+            )));
+        }
+        if price < self.tick_size || price > Decimal::ONE - self.tick_size {
+            return Err(Error::InvalidOrder(format!(
+                "Price {} is outside [{}, {}]",
+                price,
+                self.tick_size,
+                Decimal::ONE - self.tick_size
+            )));
+        }
+        if size <= Decimal::ZERO || size < self.minimum_order_size {
+            return Err(Error::InvalidOrder(format!(
+                "Size {} is below the minimum order size {}",
+                size, self.minimum_order_size
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Whether a fill added or removed liquidity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Liquidity {
+    /// The order was resting and was matched by someone else
+    Maker,
+    /// The order matched resting liquidity when it was submitted
+    Taker,
+}
+
+/// A simulated fill
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fill {
+    pub order_id: u64,
+    pub token_id: String,
+    pub side: Side,
+    pub price: Decimal,
+    pub size: Decimal,
+    /// Simulated time of the fill, Unix milliseconds
+    pub timestamp: u64,
+    pub liquidity: Liquidity,
+}
+
+/// A resting simulated limit order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimOrder {
+    pub id: u64,
+    pub token_id: String,
+    pub side: Side,
+    pub price: Decimal,
+    pub original_size: Decimal,
+    pub remaining: Decimal,
+    /// Simulated time the order was placed, Unix milliseconds
+    pub created_at: u64,
+    /// Size resting ahead of this order at its price
+    pub queue_ahead: Decimal,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Position {
+    size: Decimal,
+    /// Total cost basis of `size`
+    cost: Decimal,
+}
+
+/// Summary of a simulation
+#[derive(Debug, Clone)]
+pub struct BacktestReport {
+    pub initial_cash: Decimal,
+    pub cash: Decimal,
+    /// PnL of closed inventory, using average cost
+    pub realized_pnl: Decimal,
+    /// PnL of open inventory marked to the last trade price (or the midpoint)
+    pub unrealized_pnl: Decimal,
+    pub total_pnl: Decimal,
+    /// Every fill, in the order it happened
+    pub fills: Vec<Fill>,
+    /// Shares held per token
+    pub inventory: HashMap<String, Decimal>,
+    /// Orders still resting at the end
+    pub open_orders: Vec<SimOrder>,
+}
+
+/// Simulated CLOB that fills orders against a replayed market
+///
+/// The replayed books are the liquidity of everyone else. Simulated orders
+/// are matched with the exchange's price-time priority:
+///
+/// - A marketable order takes the best replayed levels first, at their price.
+///   Liquidity taken from a level is not available again until the level
+///   changes in the replay.
+/// - The rest of a limit order rests at its price behind the size that was
+///   already there. That queue shrinks as trades print at the price and when
+///   the level shrinks. The order is filled at its own price by trades that
+///   reach it, or when the replayed book crosses it.
+///
+/// Orders are checked against the [`MarketRules`] of their token and against
+/// the available cash (BUY) or shares (SELL), counting what open orders
+/// already reserve. Fees are not simulated.
+///
+/// `last_trade_price` events are assumed to carry the taker's side.
+#[derive(Debug, Clone)]
+pub struct SimExchange {
+    rules: HashMap<String, MarketRules>,
+    books: HashMap<String, LocalOrderBook>,
+    /// Resting orders; ids increase over time, so iteration is time priority
+    orders: BTreeMap<u64, SimOrder>,
+    /// Liquidity taken per (token, book side, price) since the level last changed
+    taken: HashMap<(String, Side, Decimal), Decimal>,
+    initial_cash: Decimal,
+    cash: Decimal,
+    positions: HashMap<String, Position>,
+    realized_pnl: Decimal,
+    last_prices: HashMap<String, Decimal>,
+    fills: Vec<Fill>,
+    new_fills: VecDeque<Fill>,
+    next_id: u64,
+    now: u64,
+}
+
+impl SimExchange {
+    /// Create an exchange with a starting cash balance
+    pub fn new(initial_cash: Decimal) -> Self {
+        Self {
+            rules: HashMap::new(),
+            books: HashMap::new(),
+            orders: BTreeMap::new(),
+            taken: HashMap::new(),
+            initial_cash,
+            cash: initial_cash,
+            positions: HashMap::new(),
+            realized_pnl: Decimal::ZERO,
+            last_prices: HashMap::new(),
+            fills: Vec::new(),
+            new_fills: VecDeque::new(),
+            next_id: 1,
+            now: 0,
+        }
+    }
+
+    /// Allow trading both tokens of a market
+    pub fn with_market(mut self, market: &Market) -> Self {
+        self.add_market(market);
+        self
+    }
+
+    /// Allow trading both tokens of a market
+    pub fn add_market(&mut self, market: &Market) {
+        let rules = MarketRules::from_market(market);
+        for token in &market.tokens {
+            self.add_token(token.token_id.clone(), rules);
+        }
+    }
+
+    /// Allow trading a token with the given rules
+    pub fn add_token(&mut self, token_id: impl Into<String>, rules: MarketRules) {
+        self.rules.insert(token_id.into(), rules);
+    }
+
+    /// Trading rules of a token
+    pub fn rules(&self, token_id: &str) -> Option<&MarketRules> {
+        self.rules.get(token_id)
+    }
+
+    /// Simulated time of the latest event, Unix milliseconds
+    pub fn now_millis(&self) -> u64 {
+        self.now
+    }
+
+    /// Replayed book of a token
+    pub fn book(&self, token_id: &str) -> Option<&LocalOrderBook> {
+        self.books.get(token_id)
+    }
+
+    pub fn cash(&self) -> Decimal {
+        self.cash
+    }
+
+    /// Cash not reserved by open BUY orders
+    pub fn available_cash(&self) -> Decimal {
+        let reserved: Decimal = self
+            .orders
+            .values()
+            .filter(|o| o.side == Side::Buy)
+            .map(|o| o.price * o.remaining)
+            .sum();
+        self.cash - reserved
+    }
+
+    /// Shares held of a token
+    pub fn position(&self, token_id: &str) -> Decimal {
+        self.positions
+            .get(token_id)
+            .map_or(Decimal::ZERO, |p| p.size)
+    }
+
+    /// Shares of a token not reserved by open SELL orders
+    pub fn available_position(&self, token_id: &str) -> Decimal {
+        let reserved: Decimal = self
+            .orders
+            .values()
+            .filter(|o| o.side == Side::Sell && o.token_id == token_id)
+            .map(|o| o.remaining)
+            .sum();
+        self.position(token_id) - reserved
+    }
+
+    /// Average cost of the shares held of a token
+    pub fn average_cost(&self, token_id: &str) -> Option<Decimal> {
+        self.positions
+            .get(token_id)
+            .filter(|p| !p.size.is_zero())
+            .map(|p| p.cost / p.size)
+    }
+
+    /// Price used to value a token: the last trade, else the book midpoint
+    pub fn mark_price(&self, token_id: &str) -> Option<Decimal> {
+        self.last_prices
+            .get(token_id)
+            .copied()
+            .or_else(|| self.books.get(token_id).and_then(|b| b.midpoint()))
+    }
+
+    pub fn realized_pnl(&self) -> Decimal {
+        self.realized_pnl
+    }
+
+    /// PnL of the shares held, at the mark price
+    pub fn unrealized_pnl(&self) -> Decimal {
+        self.positions
+            .iter()
+            .filter_map(|(token, p)| self.mark_price(token).map(|m| p.size * m - p.cost))
+            .sum()
+    }
+
+    /// Resting orders, oldest first
+    pub fn open_orders(&self) -> impl Iterator<Item = &SimOrder> + '_ {
+        self.orders.values()
+    }
+
+    /// A resting order
+    pub fn order(&self, order_id: u64) -> Option<&SimOrder> {
+        self.orders.get(&order_id)
+    }
+
+    /// Every fill so far
+    pub fn fills(&self) -> &[Fill] {
+        &self.fills
+    }
+
+    /// Fills since the last call
+    pub fn take_new_fills(&mut self) -> Vec<Fill> {
+        self.new_fills.drain(..).collect()
+    }
+
+    /// Submit a limit order
+    ///
+    /// The marketable part fills immediately against the replayed book and
+    /// the rest rests at `args.price`. Returns the order ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidOrder`] if the token has no rules, the order
+    /// breaks them, or the balance is insufficient.
+    pub fn submit_order(&mut self, args: &OrderArgs) -> Result<u64> {
+        self.rules_for(&args.token_id)?
+            .validate(args.price, args.size)?;
+        self.check_balance(&args.token_id, args.side, args.price * args.size, args.size)?;
+
+        let id = self.next_order_id();
+        let mut remaining = args.size;
+        for level in self.available_levels(&args.token_id, args.side.opposite()) {
+            let crosses = match args.side {
+                Side::Buy => level.price <= args.price,
+                Side::Sell => level.price >= args.price,
+            };
+            if !crosses || remaining.is_zero() {
+                break;
+            }
+            let size = remaining.min(level.size);
+            self.take(&args.token_id, args.side.opposite(), level.price, size);
+            self.record_fill(
+                id,
+                &args.token_id,
+                args.side,
+                level.price,
+                size,
+                Liquidity::Taker,
+            );
+            remaining -= size;
+        }
+
+        if !remaining.is_zero() {
+            let queue_ahead = self
+                .books
+                .get(&args.token_id)
+                .map_or(Decimal::ZERO, |b| b.size_at(args.side, args.price));
+            self.orders.insert(
+                id,
+                SimOrder {
+                    id,
+                    token_id: args.token_id.clone(),
+                    side: args.side,
+                    price: args.price,
+                    original_size: args.size,
+                    remaining,
+                    created_at: self.now,
+                    queue_ahead,
+                },
+            );
+        }
+        Ok(id)
+    }
+
+    /// Submit a limit order with the exchange's time-in-force rules
+    ///
+    /// GTC and GTD orders behave like [`submit_order`](Self::submit_order)
+    /// (expirations are not simulated). FAK orders cancel whatever does not
+    /// fill immediately, and FOK orders are rejected unless they fill
+    /// completely. Returns the order ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`submit_order`](Self::submit_order), and
+    /// [`Error::InvalidOrder`] if a FOK order cannot be filled completely.
+    pub fn submit_order_with_type(
+        &mut self,
+        args: &OrderArgs,
+        order_type: OrderType,
+    ) -> Result<u64> {
+        if order_type == OrderType::Fok {
+            self.rules_for(&args.token_id)?
+                .validate(args.price, args.size)?;
+            let fillable: Decimal = self
+                .available_levels(&args.token_id, args.side.opposite())
+                .iter()
+                .filter(|level| match args.side {
+                    Side::Buy => level.price <= args.price,
+                    Side::Sell => level.price >= args.price,
+                })
+                .map(|level| level.size)
+                .sum();
+            if fillable < args.size {
+                return Err(Error::InvalidOrder(format!(
+                    "FOK order for {} cannot be filled completely, {} available",
+                    args.size, fillable
+                )));
+            }
+        }
+
+        let id = self.submit_order(args)?;
+        if matches!(order_type, OrderType::Fak | OrderType::Fok) {
+            self.cancel(id);
+        }
+        Ok(id)
+    }
+
+    /// Submit a market order, which fills completely or not at all
+    ///
+    /// The amount and any price protection are interpreted as for
+    /// [`TradingClient::create_market_order`](crate::client::TradingClient::create_market_order).
+    /// Returns the order ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidOrder`] if the token has no rules, the book
+    /// cannot fill the order, the filled size is below the minimum order size,
+    /// or the balance is insufficient.
+    pub fn submit_market_order(&mut self, args: &MarketOrderArgs) -> Result<u64> {
+        let rules = *self.rules_for(&args.token_id)?;
+        let levels = self.available_levels(&args.token_id, args.side.opposite());
+        let walk = walk_market_order_protected(&levels, args)?;
+        if walk.size < rules.minimum_order_size {
+            return Err(Error::InvalidOrder(format!(
+                "Size {} is below the minimum order size {}",
+                walk.size, rules.minimum_order_size
+            )));
+        }
+        self.check_balance(&args.token_id, args.side, walk.cost, walk.size)?;
+
+        let id = self.next_order_id();
+        for fill in &walk.fills {
+            self.take(&args.token_id, args.side.opposite(), fill.price, fill.size);
+            self.record_fill(
+                id,
+                &args.token_id,
+                args.side,
+                fill.price,
+                fill.size,
+                Liquidity::Taker,
+            );
+        }
+        Ok(id)
+    }
+
+    /// Cancel a resting order; returns `false` if it is not open
+    pub fn cancel(&mut self, order_id: u64) -> bool {
+        self.orders.remove(&order_id).is_some()
+    }
+
+    /// Cancel every resting order; returns how many were cancelled
+    pub fn cancel_all(&mut self) -> usize {
+        let count = self.orders.len();
+        self.orders.clear();
+        count
+    }
+
+    /// Advance the simulation by one market event
+    ///
+    /// Updates the replayed books and fills resting orders that the event
+    /// reaches. Returns the tokens whose book changed.
+    pub fn apply_event(&mut self, event: &WsEvent) -> Vec<String> {
+        match event {
+            WsEvent::Book(snapshot) => {
+                self.advance_clock(&snapshot.timestamp);
+                let token = snapshot.asset_id.clone();
+                let applied = self
+                    .books
+                    .entry(token.clone())
+                    .or_insert_with(|| LocalOrderBook::new(token.clone()))
+                    .apply_book(snapshot);
+                if let Err(e) = applied {
+                    log::warn!("Skipping book for {}: {}", token, e);
+                    return Vec::new();
+                }
+                self.taken.retain(|(t, _, _), _| *t != token);
+                self.match_book(&token);
+                vec![token]
+            }
+            WsEvent::PriceChange(event) => {
+                if let Some(ts) = &event.timestamp {
+                    self.advance_clock(ts);
+                }
+                let mut changed: Vec<String> = Vec::new();
+                for change in &event.price_changes {
+                    let Some(book) = self.books.get_mut(&change.asset_id) else {
+                        continue;
+                    };
+                    if book.apply_change(change) {
+                        self.taken
+                            .remove(&(change.asset_id.clone(), change.side, change.price));
+                        if !changed.contains(&change.asset_id) {
+                            changed.push(change.asset_id.clone());
+                        }
+                    }
+                }
+                for token in &changed {
+                    self.match_book(token);
+                }
+                changed
+            }
+            WsEvent::LastTradePrice(trade) => {
+                self.advance_clock(&trade.timestamp);
+                self.last_prices.insert(trade.asset_id.clone(), trade.price);
+                self.match_trade(trade);
+                Vec::new()
+            }
+            WsEvent::TickSizeChange(change) => {
+                self.advance_clock(&change.timestamp);
+                if let Some(rules) = self.rules.get_mut(&change.asset_id) {
+                    rules.tick_size = change.new_tick_size;
+                }
+                Vec::new()
+            }
+        }
+    }
+
+    /// Summary of the simulation so far
+    pub fn report(&self) -> BacktestReport {
+        let unrealized_pnl = self.unrealized_pnl();
+        BacktestReport {
+            initial_cash: self.initial_cash,
+            cash: self.cash,
+            realized_pnl: self.realized_pnl,
+            unrealized_pnl,
+            total_pnl: self.realized_pnl + unrealized_pnl,
+            fills: self.fills.clone(),
+            inventory: self
+                .positions
+                .iter()
+                .map(|(token, p)| (token.clone(), p.size))
+                .collect(),
+            open_orders: self.orders.values().cloned().collect(),
+        }
+    }
+
+    fn rules_for(&self, token_id: &str) -> Result<&MarketRules> {
+        self.rules
+            .get(token_id)
+            .ok_or_else(|| Error::InvalidOrder(format!("No market rules for token {}", token_id)))
+    }
+
+    fn check_balance(
+        &self,
+        token_id: &str,
+        side: Side,
+        cost: Decimal,
+        size: Decimal,
+    ) -> Result<()> {
+        match side {
+            Side::Buy if cost > self.available_cash() => Err(Error::InvalidOrder(format!(
+                "Insufficient cash: need {}, available {}",
+                cost,
+                self.available_cash()
+            ))),
+            Side::Sell if size > self.available_position(token_id) => {
+                Err(Error::InvalidOrder(format!(
+                    "Insufficient shares: need {}, available {}",
+                    size,
+                    self.available_position(token_id)
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn next_order_id(&mut self) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    fn advance_clock(&mut self, timestamp: &str) {
+        if let Ok(ts) = timestamp.parse::<u64>() {
+            self.now = self.now.max(ts);
+        }
+    }
+
+    /// Replayed levels of one book side, best first, minus liquidity already taken
+    fn available_levels(&self, token_id: &str, book_side: Side) -> Vec<PriceLevel> {
+        let Some(book) = self.books.get(token_id) else {
+            return Vec::new();
+        };
+        let levels: Vec<PriceLevel> = match book_side {
+            Side::Buy => book.bids().collect(),
+            Side::Sell => book.asks().collect(),
+        };
+        levels
+            .into_iter()
+            .filter_map(|level| {
+                let taken = self
+                    .taken
+                    .get(&(token_id.to_string(), book_side, level.price))
+                    .copied()
+                    .unwrap_or_default();
+                let size = level.size - taken;
+                (size > Decimal::ZERO).then_some(PriceLevel {
+                    price: level.price,
+                    size,
+                })
+            })
+            .collect()
+    }
+
+    fn take(&mut self, token_id: &str, book_side: Side, price: Decimal, size: Decimal) {
+        *self
+            .taken
+            .entry((token_id.to_string(), book_side, price))
+            .or_default() += size;
+    }
+
+    fn record_fill(
+        &mut self,
+        order_id: u64,
+        token_id: &str,
+        side: Side,
+        price: Decimal,
+        size: Decimal,
+        liquidity: Liquidity,
+    ) {
+        let position = self.positions.entry(token_id.to_string()).or_default();
+        match side {
+            Side::Buy => {
+                self.cash -= price * size;
+                position.size += size;
+                position.cost += price * size;
+            }
+            Side::Sell => {
+                let average = if position.size.is_zero() {
+                    Decimal::ZERO
+                } else {
+                    position.cost / position.size
+                };
+                self.realized_pnl += (price - average) * size;
+                self.cash += price * size;
+                position.cost -= average * size;
+                position.size -= size;
+            }
+        }
+
+        let fill = Fill {
+            order_id,
+            token_id: token_id.to_string(),
+            side,
+            price,
+            size,
+            timestamp: self.now,
+            liquidity,
+        };
+        self.fills.push(fill.clone());
+        self.new_fills.push_back(fill);
+    }
+
+    /// Resting orders of a token on one side, in price-time priority
+    fn priority_order(&self, token_id: &str, side: Side) -> Vec<u64> {
+        let mut orders: Vec<&SimOrder> = self
+            .orders
+            .values()
+            .filter(|o| o.token_id == token_id && o.side == side)
+            .collect();
+        // Stable sort keeps time priority within a price
+        match side {
+            Side::Buy => orders.sort_by_key(|o| std::cmp::Reverse(o.price)),
+            Side::Sell => orders.sort_by_key(|o| o.price),
+        }
+        orders.into_iter().map(|o| o.id).collect()
+    }
+
+    /// Update queues after a book change and fill orders the book now crosses
+    fn match_book(&mut self, token_id: &str) {
+        let Some(book) = self.books.get(token_id) else {
+            return;
+        };
+        for order in self.orders.values_mut().filter(|o| o.token_id == token_id) {
+            order.queue_ahead = order.queue_ahead.min(book.size_at(order.side, order.price));
+        }
+
+        for side in [Side::Buy, Side::Sell] {
+            for id in self.priority_order(token_id, side) {
+                let Some(order) = self.orders.get(&id).cloned() else {
+                    continue;
+                };
+                let mut remaining = order.remaining;
+                for level in self.available_levels(token_id, side.opposite()) {
+                    let crosses = match side {
+                        Side::Buy => level.price <= order.price,
+                        Side::Sell => level.price >= order.price,
+                    };
+                    if !crosses || remaining.is_zero() {
+                        break;
+                    }
+                    let size = remaining.min(level.size);
+                    self.take(token_id, side.opposite(), level.price, size);
+                    self.record_fill(id, token_id, side, order.price, size, Liquidity::Maker);
+                    remaining -= size;
+                }
+                self.set_remaining(id, remaining);
+            }
+        }
+    }
+
+    /// Fill resting orders that a printed trade reaches
+    fn match_trade(&mut self, trade: &LastTradePriceEvent) {
+        let side = trade.side.opposite();
+        let mut left = trade.size;
+        // Outside queue at the trade price that this trade has used up. Each
+        // of our orders there counts the same queue, so it is taken only once.
+        let mut queue_taken = Decimal::ZERO;
+
+        for id in self.priority_order(&trade.asset_id, side) {
+            if left.is_zero() {
+                break;
+            }
+            let Some(order) = self.orders.get(&id) else {
+                continue;
+            };
+            let reached = match side {
+                Side::Buy => order.price >= trade.price,
+                Side::Sell => order.price <= trade.price,
+            };
+            if !reached {
+                break;
+            }
+
+            // Orders already resting at the trade price go first
+            if order.price == trade.price {
+                let ahead = (order.queue_ahead - queue_taken)
+                    .max(Decimal::ZERO)
+                    .min(left);
+                queue_taken += ahead;
+                left -= ahead;
+            }
+            let size = order.remaining.min(left);
+            if size.is_zero() {
+                continue;
+            }
+            let (price, remaining) = (order.price, order.remaining - size);
+            left -= size;
+            self.record_fill(id, &trade.asset_id, side, price, size, Liquidity::Maker);
+            self.set_remaining(id, remaining);
+        }
+
+        for order in self
+            .orders
+            .values_mut()
+            .filter(|o| o.token_id == trade.asset_id && o.side == side && o.price == trade.price)
+        {
+            order.queue_ahead = (order.queue_ahead - queue_taken).max(Decimal::ZERO);
+        }
+    }
+
+    fn set_remaining(&mut self, order_id: u64, remaining: Decimal) {
+        if remaining.is_zero() {
+            self.orders.remove(&order_id);
+        } else if let Some(order) = self.orders.get_mut(&order_id) {
+            order.remaining = remaining;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{BookEvent, PriceChange, PriceChangeEvent};
+    use rust_decimal_macros::dec;
+
+    fn level(price: Decimal, size: Decimal) -> PriceLevel {
+        PriceLevel { price, size }
+    }
+
+    fn book(bids: Vec<PriceLevel>, asks: Vec<PriceLevel>) -> WsEvent {
+        WsEvent::Book(BookEvent {
+            event_type: "book".to_string(),
+            market: "m".to_string(),
+            asset_id: "1".to_string(),
+            timestamp: "1000".to_string(),
+            hash: String::new(),
+            bids,
+            asks,
+            last_trade_price: None,
+        })
+    }
+
+    fn change(side: Side, price: Decimal, size: Decimal) -> WsEvent {
+        WsEvent::PriceChange(PriceChangeEvent {
+            event_type: "price_change".to_string(),
+            market: "m".to_string(),
+            timestamp: Some("2000".to_string()),
+            hash: None,
+            price_changes: vec![PriceChange {
+                asset_id: "1".to_string(),
+                side,
+                price,
+                size,
+                hash: None,
+            }],
+        })
+    }
+
+    fn trade(side: Side, price: Decimal, size: Decimal) -> WsEvent {
+        WsEvent::LastTradePrice(LastTradePriceEvent {
+            event_type: "last_trade_price".to_string(),
+            market: "m".to_string(),
+            asset_id: "1".to_string(),
+            price,
+            size,
+            fee_rate_bps: Decimal::ZERO,
+            side,
+            timestamp: "3000".to_string(),
+            transaction_hash: "0x".to_string(),
+        })
+    }
+
+    fn exchange() -> SimExchange {
+        let mut exchange = SimExchange::new(dec!(100));
+        exchange.add_token("1", MarketRules::new(dec!(0.01), dec!(5)));
+        exchange.apply_event(&book(
+            vec![level(dec!(0.48), dec!(50))],
+            vec![level(dec!(0.52), dec!(10)), level(dec!(0.53), dec!(100))],
+        ));
+        exchange
+    }
+
+    #[test]
+    fn test_rejects_orders_breaking_market_rules() {
+        let mut exchange = exchange();
+        let off_tick = OrderArgs::new("1", dec!(0.505), dec!(10), Side::Buy);
+        assert!(exchange.submit_order(&off_tick).is_err());
+        let too_small = OrderArgs::new("1", dec!(0.50), dec!(4), Side::Buy);
+        assert!(exchange.submit_order(&too_small).is_err());
+        let no_shares = OrderArgs::new("1", dec!(0.50), dec!(10), Side::Sell);
+        assert!(exchange.submit_order(&no_shares).is_err());
+        let unknown = OrderArgs::new("2", dec!(0.50), dec!(10), Side::Buy);
+        assert!(exchange.submit_order(&unknown).is_err());
+    }
+
+    #[test]
+    fn test_marketable_order_takes_then_rests() {
+        let mut exchange = exchange();
+        let id = exchange
+            .submit_order(&OrderArgs::new("1", dec!(0.52), dec!(15), Side::Buy))
+            .unwrap();
+
+        let fills = exchange.take_new_fills();
+        assert_eq!(fills.len(), 1);
+        assert_eq!((fills[0].price, fills[0].size), (dec!(0.52), dec!(10)));
+        assert_eq!(fills[0].liquidity, Liquidity::Taker);
+
+        let order = exchange.order(id).unwrap();
+        assert_eq!(order.remaining, dec!(5));
+        assert_eq!(exchange.position("1"), dec!(10));
+        assert_eq!(exchange.cash(), dec!(94.8));
+
+        // The replayed ask at 0.52 is used up until the level changes
+        let again = exchange
+            .submit_order(&OrderArgs::new("1", dec!(0.52), dec!(5), Side::Buy))
+            .unwrap();
+        assert!(exchange.take_new_fills().is_empty());
+        assert!(exchange.order(again).is_some());
+
+        let market = MarketOrderArgs::new("1", dec!(5), Side::Buy);
+        exchange.submit_market_order(&market).unwrap();
+        let fills = exchange.take_new_fills();
+        assert_eq!(fills[0].price, dec!(0.53));
+    }
+
+    #[test]
+    fn test_resting_order_waits_for_queue_ahead() {
+        let mut exchange = exchange();
+        let id = exchange
+            .submit_order(&OrderArgs::new("1", dec!(0.48), dec!(20), Side::Buy))
+            .unwrap();
+        assert_eq!(exchange.order(id).unwrap().queue_ahead, dec!(50));
+
+        // 40 of the 50 ahead trade, then the replayed level shrinks to 5
+        exchange.apply_event(&trade(Side::Sell, dec!(0.48), dec!(40)));
+        assert!(exchange.take_new_fills().is_empty());
+        exchange.apply_event(&change(Side::Buy, dec!(0.48), dec!(5)));
+        assert_eq!(exchange.order(id).unwrap().queue_ahead, dec!(5));
+
+        exchange.apply_event(&trade(Side::Sell, dec!(0.48), dec!(15)));
+        let fills = exchange.take_new_fills();
+        assert_eq!(fills[0].size, dec!(10));
+        assert_eq!(fills[0].liquidity, Liquidity::Maker);
+        assert_eq!(fills[0].timestamp, 3000);
+        assert_eq!(exchange.order(id).unwrap().remaining, dec!(10));
+    }
+
+    #[test]
+    fn test_orders_at_one_price_share_the_queue_ahead() {
+        let mut exchange = exchange();
+        let first = exchange
+            .submit_order(&OrderArgs::new("1", dec!(0.48), dec!(5), Side::Buy))
+            .unwrap();
+        let second = exchange
+            .submit_order(&OrderArgs::new("1", dec!(0.48), dec!(5), Side::Buy))
+            .unwrap();
+
+        // The 50 ahead of both trade once, then each order fills in turn
+        exchange.apply_event(&trade(Side::Sell, dec!(0.48), dec!(58)));
+        let fills = exchange.take_new_fills();
+        assert_eq!(fills.len(), 2);
+        assert_eq!((fills[0].order_id, fills[0].size), (first, dec!(5)));
+        assert_eq!((fills[1].order_id, fills[1].size), (second, dec!(3)));
+        assert_eq!(exchange.order(second).unwrap().queue_ahead, dec!(0));
+    }
+
+    #[test]
+    fn test_crossing_book_fills_resting_order_at_its_price() {
+        let mut exchange = exchange();
+        let id = exchange
+            .submit_order(&OrderArgs::new("1", dec!(0.50), dec!(10), Side::Buy))
+            .unwrap();
+
+        exchange.apply_event(&change(Side::Sell, dec!(0.49), dec!(30)));
+        let fills = exchange.take_new_fills();
+        assert_eq!((fills[0].price, fills[0].size), (dec!(0.50), dec!(10)));
+        assert!(exchange.order(id).is_none());
+    }
+
+    #[test]
+    fn test_report_pnl() {
+        let mut exchange = exchange();
+        exchange
+            .submit_order(&OrderArgs::new("1", dec!(0.52), dec!(10), Side::Buy))
+            .unwrap();
+        exchange
+            .submit_order(&OrderArgs::new("1", dec!(0.48), dec!(5), Side::Sell))
+            .unwrap();
+        exchange.apply_event(&trade(Side::Buy, dec!(0.60), dec!(1)));
+
+        let report = exchange.report();
+        assert_eq!(report.realized_pnl, dec!(-0.20));
+        assert_eq!(report.unrealized_pnl, dec!(0.40));
+        assert_eq!(report.total_pnl, dec!(0.20));
+        assert_eq!(report.inventory["1"], dec!(5));
+        assert_eq!(report.cash, dec!(97.2));
+    }
+
+    #[test]
+    fn test_market_order_is_fill_or_kill() {
+        let mut exchange = exchange();
+        let args = MarketOrderArgs::new("1", dec!(1000), Side::Buy);
+        assert!(exchange.submit_market_order(&args).is_err());
+        assert!(exchange.fills().is_empty());
+    }
+}
@@ -0,0 +1,17 @@
+//! Event-driven backtesting against recorded market data.
+//!
+//! - [`Strategy`]: Callbacks for book changes, trades and fills
+//! - [`SimExchange`]: Simulated CLOB that fills `OrderArgs` and `MarketOrderArgs`
+//!   against replayed books with price-time priority, enforcing each market's
+//!   tick size and minimum order size
+//! - [`Backtest`]: Feeds a stream of market events, such as a
+//!   [`Replay`](crate::recording::Replay), through the exchange and the strategy
+//! - [`BacktestReport`]: PnL, fills and inventory at the end of a run
+
+mod engine;
+mod exchange;
+mod strategy;
+
+pub use engine::Backtest;
+pub use exchange::{BacktestReport, Fill, Liquidity, MarketRules, SimExchange, SimOrder};
+pub use strategy::Strategy;
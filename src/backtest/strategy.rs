@@ -0,0 +1,19 @@
+use super::{Fill, SimExchange};
+use crate::book::LocalOrderBook;
+use crate::types::LastTradePriceEvent;
+
+/// A trading strategy driven by market events
+///
+/// Every callback receives the [`SimExchange`], which the strategy uses to
+/// inspect balances and books and to submit or cancel orders. All callbacks
+/// default to doing nothing.
+pub trait Strategy {
+    /// Called after the book of a token changed
+    fn on_book(&mut self, _exchange: &mut SimExchange, _book: &LocalOrderBook) {}
+
+    /// Called for every trade printed in the market
+    fn on_trade(&mut self, _exchange: &mut SimExchange, _trade: &LastTradePriceEvent) {}
+
+    /// Called for every fill of one of the strategy's orders
+    fn on_fill(&mut self, _exchange: &mut SimExchange, _fill: &Fill) {}
+}
@@ -0,0 +1,245 @@
+use rust_decimal::Decimal;
+
+use super::LocalOrderBook;
+use crate::types::{Market, PriceLevel, Side};
+
+/// Which token a quote on a [`ComplementaryBook`] executes against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Leg {
+    /// Trade the outcome's own token on the requested side
+    Direct,
+    /// The complementary token's opposite side at `1 - price`, which the CLOB
+    /// matches against an order on the outcome's own token by minting or
+    /// merging a full set
+    Complement,
+}
+
+/// Best executable price for one outcome across both legs of a binary market
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComplementaryQuote {
+    /// Effective price for the requested outcome
+    pub price: Decimal,
+    /// Size available at that price
+    pub size: Decimal,
+    /// Which leg provides the liquidity
+    pub leg: Leg,
+}
+
+/// Combined view of the two books of a binary market
+///
+/// In a binary market a bid on one outcome at `p` is equivalent to an ask on
+/// the other outcome at `1 - p`, and the CLOB cross-matches the two books.
+/// This view merges both books into one effective book per outcome and finds
+/// the cheaper leg to trade for a given outcome and side.
+///
+/// Quotes are always executed as an order on the requested outcome itself:
+/// buying YES against a NO bid is matched by the exchange minting a full set,
+/// so it needs USDC, not NO inventory, and selling YES against a NO ask is
+/// matched by merging.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::book::{BookManager, Leg};
+/// use polymarket_rs::types::Market;
+/// use polymarket_rs::Side;
+///
+/// fn route(manager: &BookManager, market: &Market) {
+///     let yes = &market.tokens[0].token_id;
+///     if let Some(pair) = manager.complementary_book(market) {
+///         if let Some(quote) = pair.best_price(yes, Side::Buy) {
+///             let source = match quote.leg {
+///                 Leg::Direct => "YES asks",
+///                 Leg::Complement => "NO bids",
+///             };
+///             // Either way the order is a YES BUY at the effective price
+///             println!("buy YES at {} from {}", quote.price, source);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ComplementaryBook {
+    books: [LocalOrderBook; 2],
+}
+
+impl ComplementaryBook {
+    /// Pair the books of the two tokens of a binary market
+    pub fn new(first: LocalOrderBook, second: LocalOrderBook) -> Self {
+        Self {
+            books: [first, second],
+        }
+    }
+
+    /// Pair books in the token order of a market
+    ///
+    /// Returns `None` if the books do not belong to the market's tokens.
+    pub fn from_market(
+        market: &Market,
+        first: LocalOrderBook,
+        second: LocalOrderBook,
+    ) -> Option<Self> {
+        let (first, second) = if first.asset_id() == market.tokens[0].token_id {
+            (first, second)
+        } else {
+            (second, first)
+        };
+
+        if first.asset_id() != market.tokens[0].token_id
+            || second.asset_id() != market.tokens[1].token_id
+        {
+            return None;
+        }
+        Some(Self::new(first, second))
+    }
+
+    /// The underlying book of a token
+    pub fn book(&self, token_id: &str) -> Option<&LocalOrderBook> {
+        self.books.iter().find(|b| b.asset_id() == token_id)
+    }
+
+    /// Effective book for a token, merging in the complement's liquidity
+    ///
+    /// The complement's asks become bids at `1 - price` and its bids become
+    /// asks at `1 - price`. Sizes at the same price are added together. The
+    /// result has no server hash.
+    pub fn effective_book(&self, token_id: &str) -> Option<LocalOrderBook> {
+        let (own, other) = self.legs(token_id)?;
+
+        let mut book = LocalOrderBook::from_levels(own.market(), token_id, own.bids(), own.asks());
+        for level in other.asks() {
+            let price = Decimal::ONE - level.price;
+            book.set_level(
+                Side::Buy,
+                price,
+                book.size_at(Side::Buy, price) + level.size,
+            );
+        }
+        for level in other.bids() {
+            let price = Decimal::ONE - level.price;
+            book.set_level(
+                Side::Sell,
+                price,
+                book.size_at(Side::Sell, price) + level.size,
+            );
+        }
+        Some(book)
+    }
+
+    /// Best price to trade an outcome on a side, across both legs
+    ///
+    /// For `Side::Buy` this is the lower of the token's best ask and one minus
+    /// the complement's best bid; for `Side::Sell` the higher of the token's
+    /// best bid and one minus the complement's best ask. Ties go to the direct
+    /// leg.
+    pub fn best_price(&self, token_id: &str, side: Side) -> Option<ComplementaryQuote> {
+        let (own, other) = self.legs(token_id)?;
+
+        let direct = match side {
+            Side::Buy => own.best_ask(),
+            Side::Sell => own.best_bid(),
+        }
+        .map(|level| ComplementaryQuote {
+            price: level.price,
+            size: level.size,
+            leg: Leg::Direct,
+        });
+
+        // A BUY of this outcome crosses the complement's bids by minting, and
+        // a SELL crosses its asks by merging, so the order stays on this token
+        let complement = match side {
+            Side::Buy => other.best_bid(),
+            Side::Sell => other.best_ask(),
+        }
+        .map(|level: PriceLevel| ComplementaryQuote {
+            price: Decimal::ONE - level.price,
+            size: level.size,
+            leg: Leg::Complement,
+        });
+
+        match (direct, complement) {
+            (Some(d), Some(c)) => {
+                let complement_better = match side {
+                    Side::Buy => c.price < d.price,
+                    Side::Sell => c.price > d.price,
+                };
+                Some(if complement_better { c } else { d })
+            }
+            (d, c) => d.or(c),
+        }
+    }
+
+    /// Best effective bid for an outcome across both legs
+    pub fn best_bid(&self, token_id: &str) -> Option<ComplementaryQuote> {
+        self.best_price(token_id, Side::Sell)
+    }
+
+    /// Best effective ask for an outcome across both legs
+    pub fn best_ask(&self, token_id: &str) -> Option<ComplementaryQuote> {
+        self.best_price(token_id, Side::Buy)
+    }
+
+    fn legs(&self, token_id: &str) -> Option<(&LocalOrderBook, &LocalOrderBook)> {
+        let [first, second] = &self.books;
+        if first.asset_id() == token_id {
+            Some((first, second))
+        } else if second.asset_id() == token_id {
+            Some((second, first))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn level(price: Decimal, size: Decimal) -> PriceLevel {
+        PriceLevel { price, size }
+    }
+
+    fn pair() -> ComplementaryBook {
+        let yes = LocalOrderBook::from_levels(
+            "m",
+            "yes",
+            vec![level(dec!(0.40), dec!(10))],
+            vec![level(dec!(0.45), dec!(10))],
+        );
+        let no = LocalOrderBook::from_levels(
+            "m",
+            "no",
+            vec![level(dec!(0.57), dec!(5))],
+            vec![level(dec!(0.60), dec!(20))],
+        );
+        ComplementaryBook::new(yes, no)
+    }
+
+    #[test]
+    fn test_effective_book_merges_complement() {
+        let book = pair().effective_book("yes").unwrap();
+
+        // NO ask 0.60 -> YES bid 0.40 (merged with the direct bid)
+        assert_eq!(book.best_bid().unwrap(), level(dec!(0.40), dec!(30)));
+        // NO bid 0.57 -> YES ask 0.43
+        assert_eq!(book.best_ask().unwrap(), level(dec!(0.43), dec!(5)));
+        assert_eq!(book.level_count(Side::Sell), 2);
+    }
+
+    #[test]
+    fn test_best_price_routes_to_cheaper_leg() {
+        let pair = pair();
+
+        let buy_yes = pair.best_price("yes", Side::Buy).unwrap();
+        assert_eq!(buy_yes.leg, Leg::Complement);
+        assert_eq!(buy_yes.price, dec!(0.43));
+
+        // Tie at 0.40 goes to the direct leg
+        let sell_yes = pair.best_price("yes", Side::Sell).unwrap();
+        assert_eq!(sell_yes.leg, Leg::Direct);
+        assert_eq!(sell_yes.price, dec!(0.40));
+
+        assert!(pair.best_price("other", Side::Buy).is_none());
+    }
+}
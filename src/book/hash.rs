@@ -0,0 +1,159 @@
+use alloy_primitives::hex;
+use serde::Serialize;
+use sha1::{Digest, Sha1};
+
+use crate::types::PriceLevel;
+
+/// How a book engine reacts to a server hash that does not match the local book
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrityMode {
+    /// Do not verify hashes
+    #[default]
+    Off,
+    /// Verify hashes and flag diverged books
+    Flag,
+    /// Verify hashes and rebuild diverged books from a REST snapshot
+    Resync,
+}
+
+impl IntegrityMode {
+    /// Whether hashes are verified in this mode
+    pub fn is_enabled(self) -> bool {
+        self != IntegrityMode::Off
+    }
+}
+
+/// Book layout hashed by the server
+///
+/// Field order matters: the hash is taken over the compact JSON encoding of
+/// this struct with an empty `hash` field.
+#[derive(Serialize)]
+struct HashedBook<'a> {
+    market: &'a str,
+    asset_id: &'a str,
+    timestamp: &'a str,
+    bids: Vec<HashedLevel>,
+    asks: Vec<HashedLevel>,
+    hash: &'a str,
+}
+
+#[derive(Serialize)]
+struct HashedLevel {
+    price: String,
+    size: String,
+}
+
+impl From<&PriceLevel> for HashedLevel {
+    fn from(level: &PriceLevel) -> Self {
+        Self {
+            price: level.price.to_string(),
+            size: level.size.to_string(),
+        }
+    }
+}
+
+/// Compute the order book hash the way the CLOB server does
+///
+/// This follows the reference Python client: SHA-1 over the compact JSON of
+/// `{market, asset_id, timestamp, bids, asks, hash: ""}`, hex encoded without
+/// a `0x` prefix. Levels must be passed in server order, which is bids
+/// ascending and asks descending by price (best price last).
+///
+/// Prices and sizes are hashed using their decimal string form, so values
+/// must keep the scale the server sent (e.g. `0.50`, not `0.5`).
+pub fn compute_book_hash<'a>(
+    market: &str,
+    asset_id: &str,
+    timestamp: &str,
+    bids: impl IntoIterator<Item = &'a PriceLevel>,
+    asks: impl IntoIterator<Item = &'a PriceLevel>,
+) -> String {
+    let book = HashedBook {
+        market,
+        asset_id,
+        timestamp,
+        bids: bids.into_iter().map(HashedLevel::from).collect(),
+        asks: asks.into_iter().map(HashedLevel::from).collect(),
+        hash: "",
+    };
+
+    // Serializing plain strings into JSON cannot fail
+    let json = serde_json::to_string(&book).unwrap_or_default();
+    hex::encode(Sha1::digest(json.as_bytes()))
+}
+
+/// Compare a locally computed hash with a server hash
+///
+/// The comparison ignores case and an optional `0x` prefix.
+pub fn hashes_match(local: &str, server: &str) -> bool {
+    let strip = |h: &str| {
+        h.strip_prefix("0x")
+            .or_else(|| h.strip_prefix("0X"))
+            .unwrap_or(h)
+            .to_string()
+    };
+    strip(local).eq_ignore_ascii_case(&strip(server))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::BookEvent;
+    use rust_decimal_macros::dec;
+
+    /// `book` message in the server's wire format, with levels in server
+    /// order. Its hash was computed outside this crate with the reference
+    /// Python client's `generate_orderbook_summary_hash`.
+    const BOOK_MESSAGE: &str = r#"{
+        "event_type": "book",
+        "market": "0xbd31dc8a20211944f6b70f31557f1001557b59905b7738480ca09bd4532f84af",
+        "asset_id": "65818619657568813474341868652308942079804919287380422192892211131408793125422",
+        "timestamp": "1729084877448",
+        "hash": "eeb540b59d04e8b7f891dc27093514405f4a8372",
+        "bids": [
+            {"price": "0.48", "size": "30"},
+            {"price": "0.49", "size": "20.5"},
+            {"price": "0.5", "size": "15"}
+        ],
+        "asks": [
+            {"price": "0.54", "size": "10"},
+            {"price": "0.53", "size": "60"},
+            {"price": "0.52", "size": "25.25"}
+        ]
+    }"#;
+
+    #[test]
+    fn test_hash_of_book_message() {
+        let book: BookEvent = serde_json::from_str(BOOK_MESSAGE).unwrap();
+        let hash = compute_book_hash(
+            &book.market,
+            &book.asset_id,
+            &book.timestamp,
+            &book.bids,
+            &book.asks,
+        );
+        assert_eq!(hash, book.hash);
+    }
+
+    #[test]
+    fn test_hash_depends_on_scale() {
+        let a = [PriceLevel {
+            price: dec!(0.50),
+            size: dec!(1),
+        }];
+        let b = [PriceLevel {
+            price: dec!(0.5),
+            size: dec!(1),
+        }];
+        assert_ne!(
+            compute_book_hash("m", "1", "1", &a, []),
+            compute_book_hash("m", "1", "1", &b, [])
+        );
+    }
+
+    #[test]
+    fn test_hashes_match() {
+        assert!(hashes_match("abcdef", "0xABCDEF"));
+        assert!(!hashes_match("abcdef", "abcdee"));
+    }
+}
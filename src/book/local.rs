@@ -0,0 +1,610 @@
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+
+use super::hash::{compute_book_hash, hashes_match};
+use crate::error::{Error, Result};
+use crate::orders::{walk_market_order, BookWalk};
+use crate::types::{
+    BookEvent, OrderBookSummary, PriceChange, PriceChangeEvent, PriceLevel, Side, WsEvent,
+};
+
+/// Local L2 order book for a single token
+///
+/// The book is seeded from a full snapshot ([`WsEvent::Book`] or a REST
+/// [`OrderBookSummary`]) and then kept up to date by applying
+/// [`PriceChangeEvent`] deltas, where a size of zero removes the level.
+///
+/// Price levels are kept in sorted maps, so best bid/ask lookups and level
+/// updates are `O(log n)` and depth queries never need to re-sort.
+///
+/// # Integrity checks
+///
+/// With [`set_hash_verification`](Self::set_hash_verification) enabled, the
+/// book recomputes the server hash after every snapshot and every delta that
+/// carries a hash, and flags itself as [diverged](Self::is_diverged) on the
+/// first mismatch. Each check hashes the whole book, so it costs `O(n)` per
+/// update. The flag is cleared by the next snapshot.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::book::LocalOrderBook;
+/// use polymarket_rs::websocket::MarketWsClient;
+/// use futures_util::StreamExt;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let token_id = "token_id".to_string();
+///     let client = MarketWsClient::new();
+///     let mut stream = client.subscribe(vec![token_id.clone()]).await?;
+///
+///     let mut book = LocalOrderBook::new(token_id);
+///     while let Some(event) = stream.next().await {
+///         if book.apply_event(&event?)? {
+///             println!("bid={:?} ask={:?}", book.best_bid(), book.best_ask());
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LocalOrderBook {
+    market: String,
+    asset_id: String,
+    timestamp: String,
+    timestamp_ms: u64,
+    hash: String,
+    initialized: bool,
+    verify_hashes: bool,
+    diverged: bool,
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+}
+
+impl LocalOrderBook {
+    /// Create an empty, uninitialized book for a token
+    ///
+    /// The book only starts tracking levels once a snapshot has been applied.
+    pub fn new(asset_id: impl Into<String>) -> Self {
+        Self {
+            asset_id: asset_id.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Build a book from a WebSocket snapshot event
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if the event timestamp is not a
+    /// number.
+    pub fn from_book_event(event: &BookEvent) -> Result<Self> {
+        let mut book = Self::new(event.asset_id.clone());
+        book.apply_book(event)?;
+        Ok(book)
+    }
+
+    /// Build a book from a REST order book summary
+    pub fn from_summary(summary: &OrderBookSummary) -> Self {
+        let mut book = Self::new(summary.asset_id.clone());
+        book.apply_summary(summary);
+        book
+    }
+
+    /// Build an initialized book directly from price levels
+    ///
+    /// Levels may be given in any order; zero-size levels are skipped. The
+    /// book has no timestamp or server hash.
+    pub fn from_levels(
+        market: impl Into<String>,
+        asset_id: impl Into<String>,
+        bids: impl IntoIterator<Item = PriceLevel>,
+        asks: impl IntoIterator<Item = PriceLevel>,
+    ) -> Self {
+        let mut book = Self::new(asset_id);
+        book.market = market.into();
+        let bids: Vec<PriceLevel> = bids.into_iter().collect();
+        let asks: Vec<PriceLevel> = asks.into_iter().collect();
+        book.replace_levels(&bids, &asks);
+        book
+    }
+
+    /// Token/asset ID this book tracks
+    pub fn asset_id(&self) -> &str {
+        &self.asset_id
+    }
+
+    /// Market (condition) ID, known once a snapshot has been applied
+    pub fn market(&self) -> &str {
+        &self.market
+    }
+
+    /// Timestamp of the last applied snapshot or delta
+    pub fn timestamp(&self) -> &str {
+        &self.timestamp
+    }
+
+    /// Server hash from the last applied snapshot or delta
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+
+    /// Whether a snapshot has been applied
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    /// Enable or disable verification of server hashes
+    pub fn set_hash_verification(&mut self, enabled: bool) {
+        self.verify_hashes = enabled;
+        if !enabled {
+            self.diverged = false;
+        }
+    }
+
+    /// Whether a server hash has failed to match the local book since the last snapshot
+    ///
+    /// Always `false` unless hash verification is enabled.
+    pub fn is_diverged(&self) -> bool {
+        self.diverged
+    }
+
+    /// Recompute the server hash for the current book
+    ///
+    /// See [`compute_book_hash`] for the algorithm.
+    pub fn compute_hash(&self) -> String {
+        // The server hashes bids ascending and asks descending
+        let bids: Vec<PriceLevel> = self.bids.iter().map(to_level).collect();
+        let asks: Vec<PriceLevel> = self.asks.iter().rev().map(to_level).collect();
+        compute_book_hash(&self.market, &self.asset_id, &self.timestamp, &bids, &asks)
+    }
+
+    /// Check whether a server hash matches the current book
+    pub fn verify_hash(&self, server_hash: &str) -> bool {
+        hashes_match(&self.compute_hash(), server_hash)
+    }
+
+    /// Whether both sides of the book are empty
+    pub fn is_empty(&self) -> bool {
+        self.bids.is_empty() && self.asks.is_empty()
+    }
+
+    /// Replace the whole book with a WebSocket snapshot
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if the event timestamp is not a
+    /// number; the book is left unchanged.
+    pub fn apply_book(&mut self, event: &BookEvent) -> Result<()> {
+        let timestamp_ms = parse_timestamp(&event.timestamp)?;
+        self.market = event.market.clone();
+        self.timestamp = event.timestamp.clone();
+        self.timestamp_ms = timestamp_ms;
+        self.hash = event.hash.clone();
+        self.replace_levels(&event.bids, &event.asks);
+        self.check_hash();
+        Ok(())
+    }
+
+    /// Replace the whole book with a REST order book summary
+    pub fn apply_summary(&mut self, summary: &OrderBookSummary) {
+        self.market = summary.market.clone();
+        self.timestamp = summary.timestamp.to_string();
+        self.timestamp_ms = summary.timestamp;
+        self.hash = summary.hash.clone();
+        self.replace_levels(&summary.bids, &summary.asks);
+        self.check_hash();
+    }
+
+    /// Apply the changes from a price change event that belong to this token
+    ///
+    /// Changes are ignored until a snapshot has been applied. Returns the
+    /// number of levels that were updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if the event timestamp is not a
+    /// number; the book is left unchanged.
+    pub fn apply_price_change(&mut self, event: &PriceChangeEvent) -> Result<usize> {
+        if !self.initialized {
+            return Ok(0);
+        }
+
+        let mut applied = 0;
+        let mut checked = false;
+        for change in &event.price_changes {
+            if change.asset_id != self.asset_id {
+                continue;
+            }
+            if applied == 0 {
+                if let Some(ts) = &event.timestamp {
+                    self.timestamp_ms = parse_timestamp(ts)?;
+                    self.timestamp = ts.clone();
+                }
+            }
+            self.apply_change(change);
+            checked |= change.hash.is_some();
+            applied += 1;
+        }
+
+        if applied > 0 && !checked {
+            if let Some(hash) = &event.hash {
+                self.hash = hash.clone();
+                self.check_hash();
+            }
+        }
+
+        Ok(applied)
+    }
+
+    /// Apply a single level change
+    ///
+    /// If the change carries a hash, it is recorded and verified. Returns
+    /// `false` if the change belongs to another token or the book has not been
+    /// initialized yet.
+    pub fn apply_change(&mut self, change: &PriceChange) -> bool {
+        if !self.initialized || change.asset_id != self.asset_id {
+            return false;
+        }
+        self.set_level(change.side, change.price, change.size);
+        if let Some(hash) = &change.hash {
+            self.hash = hash.clone();
+            self.check_hash();
+        }
+        true
+    }
+
+    /// Apply any market WebSocket event
+    ///
+    /// Book snapshots for this token replace the book and price changes are
+    /// applied as deltas. Other events are ignored. Returns `true` if the book
+    /// changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if the event timestamp is not a
+    /// number; the book is left unchanged.
+    pub fn apply_event(&mut self, event: &WsEvent) -> Result<bool> {
+        match event {
+            WsEvent::Book(book) if book.asset_id == self.asset_id => {
+                self.apply_book(book)?;
+                Ok(true)
+            }
+            WsEvent::PriceChange(change) => Ok(self.apply_price_change(change)? > 0),
+            _ => Ok(false),
+        }
+    }
+
+    /// Set the size at a price level, removing it when the size is zero
+    ///
+    /// `Side::Buy` updates the bids and `Side::Sell` updates the asks.
+    pub fn set_level(&mut self, side: Side, price: Decimal, size: Decimal) {
+        let levels = match side {
+            Side::Buy => &mut self.bids,
+            Side::Sell => &mut self.asks,
+        };
+        if size.is_zero() {
+            levels.remove(&price);
+        } else {
+            levels.insert(price, size);
+        }
+    }
+
+    /// Highest bid
+    pub fn best_bid(&self) -> Option<PriceLevel> {
+        self.bids.last_key_value().map(to_level)
+    }
+
+    /// Lowest ask
+    pub fn best_ask(&self) -> Option<PriceLevel> {
+        self.asks.first_key_value().map(to_level)
+    }
+
+    /// Midpoint between best bid and best ask
+    pub fn midpoint(&self) -> Option<Decimal> {
+        let bid = self.best_bid()?.price;
+        let ask = self.best_ask()?.price;
+        Some((bid + ask) / Decimal::TWO)
+    }
+
+    /// Difference between best ask and best bid
+    pub fn spread(&self) -> Option<Decimal> {
+        Some(self.best_ask()?.price - self.best_bid()?.price)
+    }
+
+    /// Size resting at an exact price on one side of the book
+    pub fn size_at(&self, side: Side, price: Decimal) -> Decimal {
+        let levels = match side {
+            Side::Buy => &self.bids,
+            Side::Sell => &self.asks,
+        };
+        levels.get(&price).copied().unwrap_or(Decimal::ZERO)
+    }
+
+    /// Bids, best (highest) price first
+    pub fn bids(&self) -> impl Iterator<Item = PriceLevel> + '_ {
+        self.bids.iter().rev().map(to_level)
+    }
+
+    /// Asks, best (lowest) price first
+    pub fn asks(&self) -> impl Iterator<Item = PriceLevel> + '_ {
+        self.asks.iter().map(to_level)
+    }
+
+    /// Number of price levels on one side
+    pub fn level_count(&self, side: Side) -> usize {
+        match side {
+            Side::Buy => self.bids.len(),
+            Side::Sell => self.asks.len(),
+        }
+    }
+
+    /// Top `levels` price levels on one side, best price first
+    pub fn depth(&self, side: Side, levels: usize) -> Vec<PriceLevel> {
+        match side {
+            Side::Buy => self.bids().take(levels).collect(),
+            Side::Sell => self.asks().take(levels).collect(),
+        }
+    }
+
+    /// Total size on one side at prices at least as good as `limit`
+    ///
+    /// For bids this sums levels priced at or above `limit`, for asks levels
+    /// priced at or below it.
+    pub fn depth_to_price(&self, side: Side, limit: Decimal) -> Decimal {
+        match side {
+            Side::Buy => self.bids.range(limit..).map(|(_, size)| *size).sum(),
+            Side::Sell => self.asks.range(..=limit).map(|(_, size)| *size).sum(),
+        }
+    }
+
+    /// Walk the book for a market order amount
+    ///
+    /// A BUY `amount` is USDC notional and walks the asks; a SELL `amount` is
+    /// shares and walks the bids. See [`walk_market_order`].
+    pub fn walk_market_order(&self, side: Side, amount: Decimal) -> Result<BookWalk> {
+        let levels: Vec<PriceLevel> = match side {
+            Side::Buy => self.asks().collect(),
+            Side::Sell => self.bids().collect(),
+        };
+        walk_market_order(&levels, amount, side)
+    }
+
+    /// Snapshot of the current book as an [`OrderBookSummary`]
+    ///
+    /// Bids are ordered best first (descending) and asks best first (ascending).
+    pub fn snapshot(&self) -> OrderBookSummary {
+        OrderBookSummary {
+            market: self.market.clone(),
+            asset_id: self.asset_id.clone(),
+            hash: self.hash.clone(),
+            timestamp: self.timestamp_ms,
+            bids: self.bids().collect(),
+            asks: self.asks().collect(),
+        }
+    }
+
+    fn replace_levels(&mut self, bids: &[PriceLevel], asks: &[PriceLevel]) {
+        self.bids = collect_levels(bids);
+        self.asks = collect_levels(asks);
+        self.initialized = true;
+        self.diverged = false;
+    }
+
+    /// Flag the book as diverged if the current server hash does not match
+    fn check_hash(&mut self) {
+        if self.verify_hashes && !self.diverged && !self.hash.is_empty() {
+            self.diverged = !self.verify_hash(&self.hash);
+        }
+    }
+}
+
+impl From<&OrderBookSummary> for LocalOrderBook {
+    fn from(summary: &OrderBookSummary) -> Self {
+        Self::from_summary(summary)
+    }
+}
+
+impl TryFrom<&BookEvent> for LocalOrderBook {
+    type Error = Error;
+
+    fn try_from(event: &BookEvent) -> Result<Self> {
+        Self::from_book_event(event)
+    }
+}
+
+impl From<&LocalOrderBook> for OrderBookSummary {
+    fn from(book: &LocalOrderBook) -> Self {
+        book.snapshot()
+    }
+}
+
+fn collect_levels(levels: &[PriceLevel]) -> BTreeMap<Decimal, Decimal> {
+    levels
+        .iter()
+        .filter(|l| !l.size.is_zero())
+        .map(|l| (l.price, l.size))
+        .collect()
+}
+
+/// Parse a server timestamp, Unix milliseconds
+fn parse_timestamp(timestamp: &str) -> Result<u64> {
+    timestamp
+        .parse()
+        .map_err(|_| Error::InvalidParameter(format!("Invalid book timestamp {:?}", timestamp)))
+}
+
+fn to_level((price, size): (&Decimal, &Decimal)) -> PriceLevel {
+    PriceLevel {
+        price: *price,
+        size: *size,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn level(price: Decimal, size: Decimal) -> PriceLevel {
+        PriceLevel { price, size }
+    }
+
+    fn book_event() -> BookEvent {
+        BookEvent {
+            event_type: "book".to_string(),
+            market: "0xmarket".to_string(),
+            asset_id: "123".to_string(),
+            timestamp: "1700000000000".to_string(),
+            hash: "0xhash".to_string(),
+            bids: vec![
+                level(dec!(0.48), dec!(100)),
+                level(dec!(0.50), dec!(10)),
+                level(dec!(0.49), dec!(50)),
+            ],
+            asks: vec![level(dec!(0.53), dec!(30)), level(dec!(0.52), dec!(20))],
+            last_trade_price: None,
+        }
+    }
+
+    fn change(asset_id: &str, side: Side, price: Decimal, size: Decimal) -> PriceChange {
+        PriceChange {
+            asset_id: asset_id.to_string(),
+            side,
+            price,
+            size,
+            hash: None,
+        }
+    }
+
+    fn price_change(changes: Vec<PriceChange>) -> PriceChangeEvent {
+        PriceChangeEvent {
+            event_type: "price_change".to_string(),
+            market: "0xmarket".to_string(),
+            timestamp: Some("1700000000500".to_string()),
+            hash: Some("0xnext".to_string()),
+            price_changes: changes,
+        }
+    }
+
+    #[test]
+    fn test_snapshot_sorts_levels() {
+        let book = LocalOrderBook::from_book_event(&book_event()).unwrap();
+
+        assert_eq!(book.best_bid().unwrap().price, dec!(0.50));
+        assert_eq!(book.best_ask().unwrap().price, dec!(0.52));
+        assert_eq!(book.spread(), Some(dec!(0.02)));
+        assert_eq!(book.midpoint(), Some(dec!(0.51)));
+
+        let bids: Vec<Decimal> = book.bids().map(|l| l.price).collect();
+        assert_eq!(bids, vec![dec!(0.50), dec!(0.49), dec!(0.48)]);
+    }
+
+    #[test]
+    fn test_price_change_updates_and_removes_levels() {
+        let mut book = LocalOrderBook::from_book_event(&book_event()).unwrap();
+
+        let applied = book
+            .apply_price_change(&price_change(vec![
+                change("123", Side::Buy, dec!(0.50), dec!(0)),
+                change("123", Side::Sell, dec!(0.51), dec!(5)),
+                change("456", Side::Sell, dec!(0.40), dec!(5)),
+            ]))
+            .unwrap();
+
+        assert_eq!(applied, 2);
+        assert_eq!(book.best_bid().unwrap().price, dec!(0.49));
+        assert_eq!(book.best_ask().unwrap(), level(dec!(0.51), dec!(5)));
+        assert_eq!(book.hash(), "0xnext");
+        assert_eq!(book.level_count(Side::Sell), 3);
+    }
+
+    #[test]
+    fn test_deltas_ignored_before_snapshot() {
+        let mut book = LocalOrderBook::new("123");
+        let event = WsEvent::PriceChange(price_change(vec![change(
+            "123",
+            Side::Buy,
+            dec!(0.50),
+            dec!(10),
+        )]));
+
+        assert!(!book.apply_event(&event).unwrap());
+        assert!(book.is_empty());
+        assert!(!book.is_initialized());
+    }
+
+    #[test]
+    fn test_depth_queries() {
+        let book = LocalOrderBook::from_book_event(&book_event()).unwrap();
+
+        assert_eq!(book.depth(Side::Buy, 2).len(), 2);
+        assert_eq!(book.depth_to_price(Side::Buy, dec!(0.49)), dec!(60));
+        assert_eq!(book.depth_to_price(Side::Sell, dec!(0.53)), dec!(50));
+        assert_eq!(book.size_at(Side::Sell, dec!(0.52)), dec!(20));
+    }
+
+    #[test]
+    fn test_hash_verification_flags_divergence() {
+        let mut event = book_event();
+        let mut book = LocalOrderBook::from_book_event(&event).unwrap();
+        event.hash = book.compute_hash();
+
+        book.set_hash_verification(true);
+        book.apply_book(&event).unwrap();
+        assert!(!book.is_diverged());
+
+        // Hash for the book after removing the 0.50 bid
+        let mut expected = book.clone();
+        expected.timestamp = "1700000000500".to_string();
+        expected.set_level(Side::Buy, dec!(0.50), dec!(0));
+        let good_hash = expected.compute_hash();
+
+        let mut delta = price_change(vec![change("123", Side::Buy, dec!(0.50), dec!(0))]);
+        delta.hash = Some(good_hash);
+        book.apply_price_change(&delta).unwrap();
+        assert!(!book.is_diverged());
+
+        delta.price_changes[0].price = dec!(0.49);
+        book.apply_price_change(&delta).unwrap();
+        assert!(book.is_diverged());
+
+        // A fresh snapshot clears the flag
+        book.apply_book(&event).unwrap();
+        assert!(!book.is_diverged());
+    }
+
+    #[test]
+    fn test_bad_timestamp_is_rejected() {
+        let mut event = book_event();
+        event.timestamp = "not-a-number".to_string();
+        assert!(matches!(
+            LocalOrderBook::from_book_event(&event),
+            Err(Error::InvalidParameter(_))
+        ));
+
+        let mut book = LocalOrderBook::from_book_event(&book_event()).unwrap();
+        assert!(book.apply_book(&event).is_err());
+        let mut delta = price_change(vec![change("123", Side::Buy, dec!(0.50), dec!(0))]);
+        delta.timestamp = Some(String::new());
+        assert!(book.apply_price_change(&delta).is_err());
+
+        // The book keeps its last good state
+        assert_eq!(book.best_bid().unwrap().price, dec!(0.50));
+        assert_eq!(book.snapshot().timestamp, 1700000000000);
+    }
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let book = LocalOrderBook::from_book_event(&book_event()).unwrap();
+        let summary = book.snapshot();
+
+        assert_eq!(summary.timestamp, 1700000000000);
+        assert_eq!(summary.bids[0].price, dec!(0.50));
+        assert_eq!(summary.asks[0].price, dec!(0.52));
+
+        let rebuilt = LocalOrderBook::from(&summary);
+        assert_eq!(rebuilt.best_bid(), book.best_bid());
+        assert_eq!(rebuilt.level_count(Side::Buy), 3);
+    }
+}
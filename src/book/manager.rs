@@ -0,0 +1,567 @@
+use futures_util::{Stream, StreamExt};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::{broadcast, watch};
+use tokio::task::JoinHandle;
+
+use super::{ComplementaryBook, IntegrityMode, LocalOrderBook};
+use crate::client::ClobClient;
+use crate::error::{Error, Result};
+use crate::types::{BookParams, Market, Side, TokenId, WsEvent};
+use crate::websocket::{MarketWsClient, ReconnectConfig, ReconnectingStream};
+
+/// Capacity of the resync notification channel
+const RESYNC_CHANNEL_CAPACITY: usize = 64;
+
+/// Why a book was rebuilt from a REST snapshot
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResyncReason {
+    /// The WebSocket connection was re-established and deltas may have been missed
+    Reconnected,
+    /// A price change arrived for a token that has no snapshot yet
+    MissingSnapshot,
+    /// The server hash did not match the local book
+    HashMismatch,
+}
+
+/// Notification that books were rebuilt from a REST snapshot
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResyncEvent {
+    /// Token IDs whose books were rebuilt
+    pub token_ids: Vec<String>,
+    /// What triggered the resync
+    pub reason: ResyncReason,
+}
+
+/// Live order books for many tokens over a single market WebSocket connection
+///
+/// The manager keeps one [`LocalOrderBook`] per subscribed token and publishes
+/// every update through a [`tokio::sync::watch`] channel, so callers can wait
+/// for changes on a single token without matching on `asset_id` strings.
+///
+/// The manager is cheap to clone; clones share the same books.
+///
+/// # Resync
+///
+/// When a [`ClobClient`] is attached with [`with_clob_client`](Self::with_clob_client),
+/// books are refetched over REST and rebuilt whenever they may have gone stale:
+/// after the WebSocket reconnects, or when a delta arrives for a token that has
+/// no snapshot yet. Every rebuild is announced on [`resync_events`](Self::resync_events).
+///
+/// With [`with_integrity_mode`](Self::with_integrity_mode), every book also
+/// verifies the server hash and either flags itself as diverged or is resynced.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::book::BookManager;
+/// use polymarket_rs::websocket::MarketWsClient;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let token_ids = vec!["token_a".to_string(), "token_b".to_string()];
+///     let manager = BookManager::new(MarketWsClient::new(), token_ids);
+///
+///     let mut rx = manager.subscribe("token_a").expect("token is tracked");
+///     let _task = manager.spawn();
+///
+///     while rx.changed().await.is_ok() {
+///         let book = rx.borrow_and_update();
+///         println!("bid={:?} ask={:?}", book.best_bid(), book.best_ask());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct BookManager {
+    client: MarketWsClient,
+    clob_client: Option<Arc<ClobClient>>,
+    reconnect_config: Option<ReconnectConfig>,
+    integrity_mode: IntegrityMode,
+    token_ids: Arc<Vec<String>>,
+    books: Arc<HashMap<String, watch::Sender<LocalOrderBook>>>,
+    resync_tx: broadcast::Sender<ResyncEvent>,
+}
+
+impl BookManager {
+    /// Create a manager for a fixed set of tokens
+    ///
+    /// Polymarket does not support changing subscriptions on a live connection,
+    /// so the token set is fixed for the lifetime of the manager.
+    pub fn new(client: MarketWsClient, token_ids: Vec<String>) -> Self {
+        let books = token_ids
+            .iter()
+            .map(|id| {
+                let (tx, _rx) = watch::channel(LocalOrderBook::new(id.clone()));
+                (id.clone(), tx)
+            })
+            .collect();
+
+        let (resync_tx, _rx) = broadcast::channel(RESYNC_CHANNEL_CAPACITY);
+
+        Self {
+            client,
+            clob_client: None,
+            reconnect_config: None,
+            integrity_mode: IntegrityMode::Off,
+            token_ids: Arc::new(token_ids),
+            books: Arc::new(books),
+            resync_tx,
+        }
+    }
+
+    /// Attach a REST client used to refetch books when they may be stale
+    pub fn with_clob_client(mut self, clob_client: ClobClient) -> Self {
+        self.clob_client = Some(Arc::new(clob_client));
+        self
+    }
+
+    /// Reconnect automatically when the WebSocket connection drops
+    ///
+    /// Without this, [`run`](Self::run) returns as soon as the connection ends.
+    pub fn with_reconnect(mut self, config: ReconnectConfig) -> Self {
+        self.reconnect_config = Some(config);
+        self
+    }
+
+    /// Verify server hashes against the local books
+    ///
+    /// [`IntegrityMode::Resync`] requires a [`ClobClient`]; without one it
+    /// behaves like [`IntegrityMode::Flag`].
+    pub fn with_integrity_mode(mut self, mode: IntegrityMode) -> Self {
+        self.integrity_mode = mode;
+        for tx in self.books.values() {
+            tx.send_modify(|book| book.set_hash_verification(mode.is_enabled()));
+        }
+        self
+    }
+
+    /// Tracked tokens whose books have failed hash verification
+    pub fn diverged_tokens(&self) -> Vec<String> {
+        self.token_ids
+            .iter()
+            .filter(|id| self.books[id.as_str()].borrow().is_diverged())
+            .cloned()
+            .collect()
+    }
+
+    /// Subscribe to resync notifications
+    pub fn resync_events(&self) -> broadcast::Receiver<ResyncEvent> {
+        self.resync_tx.subscribe()
+    }
+
+    /// Token IDs tracked by this manager
+    pub fn token_ids(&self) -> &[String] {
+        &self.token_ids
+    }
+
+    /// Subscribe to updates for a single token
+    ///
+    /// Returns `None` if the token is not tracked by this manager.
+    pub fn subscribe(&self, token_id: &str) -> Option<watch::Receiver<LocalOrderBook>> {
+        self.books.get(token_id).map(|tx| tx.subscribe())
+    }
+
+    /// Copy of the current book for a token
+    pub fn book(&self, token_id: &str) -> Option<LocalOrderBook> {
+        self.books.get(token_id).map(|tx| tx.borrow().clone())
+    }
+
+    /// Combined view of both books of a binary market
+    ///
+    /// Returns `None` unless both of the market's tokens are tracked.
+    pub fn complementary_book(&self, market: &Market) -> Option<ComplementaryBook> {
+        let [first, second] = &market.tokens;
+        ComplementaryBook::from_market(
+            market,
+            self.book(&first.token_id)?,
+            self.book(&second.token_id)?,
+        )
+    }
+
+    /// Apply a market event to the books it touches
+    ///
+    /// Subscribers are only notified for books that actually changed. Returns
+    /// the token IDs that were updated.
+    pub fn apply_event(&self, event: &WsEvent) -> Vec<String> {
+        match event {
+            WsEvent::Book(book) => match self.books.get(&book.asset_id) {
+                Some(tx) => {
+                    let applied = tx.send_if_modified(|local| match local.apply_book(book) {
+                        Ok(()) => true,
+                        Err(e) => {
+                            log::warn!("Skipping book for {}: {}", book.asset_id, e);
+                            false
+                        }
+                    });
+                    if applied {
+                        vec![book.asset_id.clone()]
+                    } else {
+                        Vec::new()
+                    }
+                }
+                None => Vec::new(),
+            },
+            WsEvent::PriceChange(change) => {
+                let mut updated: Vec<String> = Vec::new();
+                for pc in &change.price_changes {
+                    if updated.contains(&pc.asset_id) {
+                        continue;
+                    }
+                    if let Some(tx) = self.books.get(&pc.asset_id) {
+                        let applied =
+                            tx.send_if_modified(|local| match local.apply_price_change(change) {
+                                Ok(applied) => applied > 0,
+                                Err(e) => {
+                                    log::warn!("Skipping price change for {}: {}", pc.asset_id, e);
+                                    false
+                                }
+                            });
+                        if applied {
+                            updated.push(pc.asset_id.clone());
+                        }
+                    }
+                }
+                updated
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Tracked tokens touched by an event whose book has no snapshot yet
+    pub fn missing_snapshots(&self, event: &WsEvent) -> Vec<String> {
+        let mut missing: Vec<String> = Vec::new();
+        if let WsEvent::PriceChange(change) = event {
+            for pc in &change.price_changes {
+                let stale = self
+                    .books
+                    .get(&pc.asset_id)
+                    .is_some_and(|tx| !tx.borrow().is_initialized());
+                if stale && !missing.contains(&pc.asset_id) {
+                    missing.push(pc.asset_id.clone());
+                }
+            }
+        }
+        missing
+    }
+
+    /// Refetch books over REST and rebuild them
+    ///
+    /// Untracked token IDs are ignored. Subscribers of the rebuilt books are
+    /// notified through their watch channels and a [`ResyncEvent`] is broadcast.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if no [`ClobClient`] is attached, or the REST
+    /// error if the books cannot be fetched.
+    pub async fn resync(&self, token_ids: &[String], reason: ResyncReason) -> Result<()> {
+        let clob_client = self
+            .clob_client
+            .as_ref()
+            .ok_or_else(|| Error::Config("BookManager resync requires a ClobClient".to_string()))?;
+
+        let token_ids: Vec<&String> = token_ids
+            .iter()
+            .filter(|id| self.books.contains_key(id.as_str()))
+            .collect();
+
+        let summaries = match token_ids.as_slice() {
+            [] => return Ok(()),
+            [token_id] => vec![
+                clob_client
+                    .get_order_book(&TokenId::new(token_id.as_str()))
+                    .await?,
+            ],
+            _ => {
+                let params: Vec<BookParams> = token_ids
+                    .iter()
+                    .map(|id| BookParams::new(id.as_str(), Side::Buy))
+                    .collect();
+                clob_client.get_order_books(&params).await?
+            }
+        };
+
+        let mut rebuilt = Vec::with_capacity(summaries.len());
+        for summary in &summaries {
+            if let Some(tx) = self.books.get(&summary.asset_id) {
+                tx.send_modify(|local| local.apply_summary(summary));
+                rebuilt.push(summary.asset_id.clone());
+            }
+        }
+
+        log::debug!("Resynced {} book(s) after {:?}", rebuilt.len(), reason);
+        let _ = self.resync_tx.send(ResyncEvent {
+            token_ids: rebuilt,
+            reason,
+        });
+        Ok(())
+    }
+
+    /// Drive the books from an existing event stream until it ends
+    ///
+    /// This allows feeding the manager from any source of [`WsEvent`]s.
+    /// If a [`ClobClient`] is attached, tokens that receive deltas before a
+    /// snapshot are resynced over REST. Returns the first error produced by the
+    /// stream.
+    pub async fn run_stream<S>(&self, stream: S) -> Result<()>
+    where
+        S: Stream<Item = Result<WsEvent>>,
+    {
+        futures_util::pin_mut!(stream);
+        while let Some(event) = stream.next().await {
+            self.process_event(&event?).await;
+        }
+        Ok(())
+    }
+
+    /// Connect to the market WebSocket and drive the books until the connection ends
+    ///
+    /// With [`with_reconnect`](Self::with_reconnect), the connection is
+    /// re-established automatically and every reconnect triggers a resync of
+    /// all tracked books.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection fails or the stream yields an error.
+    /// A graceful close is reported as [`Error::ConnectionClosed`].
+    pub async fn run(&self) -> Result<()> {
+        let Some(config) = self.reconnect_config.clone() else {
+            let stream = self.client.subscribe(self.token_ids.to_vec()).await?;
+            self.run_stream(stream).await?;
+            return Err(Error::ConnectionClosed);
+        };
+
+        let client = self.client.clone();
+        let token_ids = self.token_ids.clone();
+        let mut stream = ReconnectingStream::new(config, move || {
+            let client = client.clone();
+            let token_ids = token_ids.to_vec();
+            async move { client.subscribe(token_ids).await }
+        });
+
+        let mut connections = 0;
+        while let Some(event) = stream.next().await {
+            if stream.connection_count() != connections {
+                let reconnected = connections > 0;
+                connections = stream.connection_count();
+                if reconnected {
+                    self.try_resync(&self.token_ids, ResyncReason::Reconnected)
+                        .await;
+                }
+            }
+
+            match event {
+                Ok(event) => self.process_event(&event).await,
+                Err(e @ Error::ReconnectFailed { .. }) => return Err(e),
+                Err(e) => log::warn!("Market stream error: {}", e),
+            }
+        }
+        Err(Error::ConnectionClosed)
+    }
+
+    /// Run the manager on a background tokio task
+    pub fn spawn(&self) -> JoinHandle<Result<()>> {
+        let manager = self.clone();
+        tokio::spawn(async move { manager.run().await })
+    }
+
+    /// Apply an event, resyncing tokens that are missing a snapshot or diverged
+    async fn process_event(&self, event: &WsEvent) {
+        let missing = self.missing_snapshots(event);
+        let already_diverged = self.diverged_among(&touched_tokens(event));
+
+        let updated = self.apply_event(event);
+        if !missing.is_empty() {
+            self.try_resync(&missing, ResyncReason::MissingSnapshot)
+                .await;
+        }
+
+        if self.integrity_mode == IntegrityMode::Resync {
+            // Only resync on a fresh divergence, so a book whose REST snapshot
+            // still mismatches does not trigger a refetch on every delta
+            let diverged: Vec<String> = updated
+                .into_iter()
+                .filter(|id| !already_diverged.contains(id))
+                .filter(|id| self.books[id.as_str()].borrow().is_diverged())
+                .collect();
+            if !diverged.is_empty() {
+                self.try_resync(&diverged, ResyncReason::HashMismatch).await;
+            }
+        }
+    }
+
+    /// Tokens among `token_ids` whose books have failed hash verification
+    fn diverged_among(&self, token_ids: &[&str]) -> Vec<String> {
+        token_ids
+            .iter()
+            .filter(|id| {
+                self.books
+                    .get(**id)
+                    .is_some_and(|tx| tx.borrow().is_diverged())
+            })
+            .map(|id| id.to_string())
+            .collect()
+    }
+
+    /// Resync if a REST client is attached, logging failures
+    ///
+    /// A failed resync leaves the book stale; the next trigger retries it.
+    async fn try_resync(&self, token_ids: &[String], reason: ResyncReason) {
+        if self.clob_client.is_none() {
+            return;
+        }
+        if let Err(e) = self.resync(token_ids, reason).await {
+            log::warn!("Failed to resync books after {:?}: {}", reason, e);
+        }
+    }
+}
+
+/// Token IDs an event applies to, without duplicates
+fn touched_tokens(event: &WsEvent) -> Vec<&str> {
+    match event {
+        WsEvent::Book(book) => vec![book.asset_id.as_str()],
+        WsEvent::PriceChange(change) => {
+            let mut tokens: Vec<&str> = Vec::new();
+            for pc in &change.price_changes {
+                if !tokens.contains(&pc.asset_id.as_str()) {
+                    tokens.push(&pc.asset_id);
+                }
+            }
+            tokens
+        }
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{BookEvent, PriceChange, PriceChangeEvent, PriceLevel, Side};
+    use rust_decimal_macros::dec;
+
+    fn book(asset_id: &str) -> WsEvent {
+        WsEvent::Book(BookEvent {
+            event_type: "book".to_string(),
+            market: "0xmarket".to_string(),
+            asset_id: asset_id.to_string(),
+            timestamp: "1".to_string(),
+            hash: "h".to_string(),
+            bids: vec![PriceLevel {
+                price: dec!(0.40),
+                size: dec!(10),
+            }],
+            asks: vec![PriceLevel {
+                price: dec!(0.60),
+                size: dec!(10),
+            }],
+            last_trade_price: None,
+        })
+    }
+
+    fn manager() -> BookManager {
+        BookManager::new(
+            MarketWsClient::new(),
+            vec!["a".to_string(), "b".to_string()],
+        )
+    }
+
+    #[tokio::test]
+    async fn test_updates_only_notify_affected_token() {
+        let manager = manager();
+        let mut rx_a = manager.subscribe("a").unwrap();
+        let rx_b = manager.subscribe("b").unwrap();
+
+        assert_eq!(manager.apply_event(&book("a")), vec!["a".to_string()]);
+        assert!(rx_a.has_changed().unwrap());
+        assert!(!rx_b.has_changed().unwrap());
+        assert_eq!(
+            rx_a.borrow_and_update().best_bid().unwrap().price,
+            dec!(0.40)
+        );
+
+        manager.apply_event(&book("b"));
+        let updated = manager.apply_event(&WsEvent::PriceChange(PriceChangeEvent {
+            event_type: "price_change".to_string(),
+            market: "0xmarket".to_string(),
+            timestamp: None,
+            hash: None,
+            price_changes: vec![PriceChange {
+                asset_id: "b".to_string(),
+                side: Side::Buy,
+                price: dec!(0.45),
+                size: dec!(5),
+                hash: None,
+            }],
+        }));
+
+        assert_eq!(updated, vec!["b".to_string()]);
+        assert!(!rx_a.has_changed().unwrap());
+        assert!(rx_b.has_changed().unwrap());
+        assert_eq!(
+            manager.book("b").unwrap().best_bid().unwrap().price,
+            dec!(0.45)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_untracked_tokens_are_ignored() {
+        let manager = manager();
+        assert!(manager.subscribe("c").is_none());
+        assert!(manager.apply_event(&book("c")).is_empty());
+    }
+
+    #[test]
+    fn test_missing_snapshots() {
+        let manager = manager();
+        manager.apply_event(&book("a"));
+
+        let event = WsEvent::PriceChange(PriceChangeEvent {
+            event_type: "price_change".to_string(),
+            market: "0xmarket".to_string(),
+            timestamp: None,
+            hash: None,
+            price_changes: ["a", "b", "b", "c"]
+                .iter()
+                .map(|id| PriceChange {
+                    asset_id: id.to_string(),
+                    side: Side::Sell,
+                    price: dec!(0.55),
+                    size: dec!(1),
+                    hash: None,
+                })
+                .collect(),
+        });
+
+        assert_eq!(manager.missing_snapshots(&event), vec!["b".to_string()]);
+    }
+
+    #[test]
+    fn test_integrity_mode_flags_diverged_books() {
+        let manager = manager().with_integrity_mode(IntegrityMode::Flag);
+
+        // The test snapshot carries a hash that cannot match
+        manager.apply_event(&book("a"));
+        assert_eq!(manager.diverged_tokens(), vec!["a".to_string()]);
+        assert!(manager.book("a").unwrap().is_diverged());
+        assert!(!manager.book("b").unwrap().is_diverged());
+    }
+
+    #[tokio::test]
+    async fn test_resync_requires_clob_client() {
+        let manager = manager();
+        let result = manager
+            .resync(&["a".to_string()], ResyncReason::Reconnected)
+            .await;
+        assert!(matches!(result, Err(Error::Config(_))));
+    }
+
+    #[tokio::test]
+    async fn test_run_stream() {
+        let manager = manager();
+        let events = futures_util::stream::iter(vec![Ok(book("a")), Ok(book("b"))]);
+
+        manager.run_stream(events).await.unwrap();
+        assert!(manager.book("a").unwrap().is_initialized());
+        assert!(manager.book("b").unwrap().is_initialized());
+    }
+}
@@ -0,0 +1,19 @@
+//! Local order book engine built from market WebSocket events.
+//!
+//! - [`LocalOrderBook`]: Sorted L2 book for a single token, seeded from a snapshot
+//!   and kept current with price change deltas
+//! - [`BookManager`]: Live books for many tokens over one WebSocket connection,
+//!   with a `watch` channel per token and automatic REST resync of stale books
+//! - [`ComplementaryBook`]: Merged view of the YES/NO books of a binary market
+//! - [`compute_book_hash`]: Recomputes the server's order book hash so local books
+//!   can be verified against the exchange
+
+mod complementary;
+mod hash;
+mod local;
+mod manager;
+
+pub use complementary::{ComplementaryBook, ComplementaryQuote, Leg};
+pub use hash::{compute_book_hash, hashes_match, IntegrityMode};
+pub use local::LocalOrderBook;
+pub use manager::{BookManager, ResyncEvent, ResyncReason};
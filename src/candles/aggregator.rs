@@ -0,0 +1,372 @@
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use crate::client::ClobClient;
+use crate::error::Result;
+use crate::request::PriceHistoryParams;
+use crate::types::{LastTradePriceEvent, PriceHistory, TokenId, WsEvent};
+
+/// A single OHLCV bar
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Candle {
+    /// Start of the bar, Unix milliseconds
+    pub start: u64,
+    pub open: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+    pub close: Decimal,
+    /// Traded size in shares (always zero for bars built from price history)
+    pub volume: Decimal,
+    /// Number of trades in the bar (always zero for bars built from price history)
+    pub trades: u64,
+}
+
+/// A candle plus the timestamps of its first and last update, so out-of-order
+/// updates only move `open`/`close` when they are really earlier/later
+#[derive(Debug, Clone)]
+struct Bar {
+    candle: Candle,
+    first_ts: u64,
+    last_ts: u64,
+}
+
+/// Fixed-interval OHLCV bars for a single token
+///
+/// Bars are aligned to multiples of the interval since the Unix epoch and are
+/// only created when there is an update, so intervals without trades have no
+/// bar.
+///
+/// # History and live data
+///
+/// The bar series can be seeded from [`ClobClient::get_prices_history`] with
+/// [`seed_history`](Self::seed_history). Price history points only carry a
+/// price, so they shape `open`/`high`/`low`/`close` but add no volume.
+///
+/// History and live trades are joined at a single cut-over point so nothing is
+/// counted twice, regardless of the order they are fed in:
+/// - live trades at or before the last seeded history point are dropped
+/// - history points at or after the first live trade are dropped
+///
+/// Subscribing to the market stream first and seeding afterwards therefore
+/// leaves no gap between the two series.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::candles::CandleAggregator;
+/// use polymarket_rs::client::ClobClient;
+/// use polymarket_rs::request::{PriceHistoryInterval, PriceHistoryParams};
+/// use polymarket_rs::websocket::MarketWsClient;
+/// use polymarket_rs::StreamExt;
+/// use std::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let token_id = "token_id".to_string();
+///     let mut candles = CandleAggregator::new(&token_id, Duration::from_secs(60));
+///
+///     let mut stream = MarketWsClient::new().subscribe(vec![token_id]).await?;
+///     let clob = ClobClient::new("https://clob.polymarket.com");
+///     let params = PriceHistoryParams::new()
+///         .with_interval(PriceHistoryInterval::OneDay)
+///         .with_fidelity(1);
+///     candles.seed_from_clob(&clob, &params).await?;
+///
+///     while let Some(event) = stream.next().await {
+///         if candles.apply_event(&event?) {
+///             println!("{:?}", candles.latest());
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CandleAggregator {
+    asset_id: String,
+    interval_ms: u64,
+    max_candles: Option<usize>,
+    bars: BTreeMap<u64, Bar>,
+    /// Timestamp of the last seeded history point
+    history_end: Option<u64>,
+    /// Timestamp of the first live trade
+    live_start: Option<u64>,
+}
+
+impl CandleAggregator {
+    /// Create an aggregator for a token
+    ///
+    /// Intervals are truncated to whole milliseconds, with a minimum of one.
+    pub fn new(asset_id: impl Into<String>, interval: Duration) -> Self {
+        Self {
+            asset_id: asset_id.into(),
+            interval_ms: (interval.as_millis() as u64).max(1),
+            max_candles: None,
+            bars: BTreeMap::new(),
+            history_end: None,
+            live_start: None,
+        }
+    }
+
+    /// Keep at most `max_candles` bars, dropping the oldest first
+    pub fn with_max_candles(mut self, max_candles: usize) -> Self {
+        self.max_candles = Some(max_candles);
+        self.prune();
+        self
+    }
+
+    /// The token whose trades are aggregated
+    pub fn asset_id(&self) -> &str {
+        &self.asset_id
+    }
+
+    /// The bar interval
+    pub fn interval(&self) -> Duration {
+        Duration::from_millis(self.interval_ms)
+    }
+
+    /// Seed bars from price history points
+    ///
+    /// Returns the number of points applied; points that overlap live trades
+    /// already received are skipped.
+    pub fn seed_history(&mut self, history: &[PriceHistory]) -> usize {
+        let mut applied = 0;
+        for point in history {
+            let ts = point.timestamp.saturating_mul(1000);
+            if self.live_start.is_some_and(|start| ts >= start) {
+                continue;
+            }
+            self.update(ts, point.price, Decimal::ZERO, false);
+            self.history_end = Some(self.history_end.map_or(ts, |end| end.max(ts)));
+            applied += 1;
+        }
+        self.prune();
+        applied
+    }
+
+    /// Fetch the token's price history and seed bars from it
+    ///
+    /// Pick a fidelity no coarser than the bar interval, or bars will be
+    /// missing points.
+    ///
+    /// Returns the number of points applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `params` are invalid or the history cannot be
+    /// fetched.
+    pub async fn seed_from_clob(
+        &mut self,
+        client: &ClobClient,
+        params: &PriceHistoryParams,
+    ) -> Result<usize> {
+        let response = client
+            .get_prices_history(&TokenId::new(self.asset_id.as_str()), params)
+            .await?;
+        Ok(self.seed_history(&response.history))
+    }
+
+    /// Add a live trade
+    ///
+    /// Returns `true` if the trade updated a bar. Trades for other tokens,
+    /// trades already covered by the seeded history and trades with an
+    /// unparseable timestamp are ignored.
+    pub fn apply_trade(&mut self, trade: &LastTradePriceEvent) -> bool {
+        if trade.asset_id != self.asset_id {
+            return false;
+        }
+        let Ok(ts) = trade.timestamp.parse::<u64>() else {
+            log::warn!(
+                "Ignoring trade with invalid timestamp {:?}",
+                trade.timestamp
+            );
+            return false;
+        };
+        if self.history_end.is_some_and(|end| ts <= end) {
+            return false;
+        }
+
+        self.live_start = Some(self.live_start.map_or(ts, |start| start.min(ts)));
+        self.update(ts, trade.price, trade.size, true);
+        self.prune();
+        true
+    }
+
+    /// Add a market event, ignoring everything but `last_trade_price`
+    ///
+    /// Returns `true` if the event updated a bar.
+    pub fn apply_event(&mut self, event: &WsEvent) -> bool {
+        match event {
+            WsEvent::LastTradePrice(trade) => self.apply_trade(trade),
+            _ => false,
+        }
+    }
+
+    /// All bars, oldest first
+    pub fn candles(&self) -> impl DoubleEndedIterator<Item = &Candle> + '_ {
+        self.bars.values().map(|bar| &bar.candle)
+    }
+
+    /// The most recent bar, which may still be forming
+    pub fn latest(&self) -> Option<&Candle> {
+        self.bars.values().next_back().map(|bar| &bar.candle)
+    }
+
+    /// The bar starting at `start` (Unix milliseconds)
+    pub fn candle_at(&self, start: u64) -> Option<&Candle> {
+        self.bars.get(&start).map(|bar| &bar.candle)
+    }
+
+    /// Number of bars
+    pub fn len(&self) -> usize {
+        self.bars.len()
+    }
+
+    /// Whether there are no bars
+    pub fn is_empty(&self) -> bool {
+        self.bars.is_empty()
+    }
+
+    fn update(&mut self, ts: u64, price: Decimal, size: Decimal, is_trade: bool) {
+        let start = ts - ts % self.interval_ms;
+        let bar = self.bars.entry(start).or_insert_with(|| Bar {
+            candle: Candle {
+                start,
+                open: price,
+                high: price,
+                low: price,
+                close: price,
+                volume: Decimal::ZERO,
+                trades: 0,
+            },
+            first_ts: ts,
+            last_ts: ts,
+        });
+
+        let candle = &mut bar.candle;
+        candle.high = candle.high.max(price);
+        candle.low = candle.low.min(price);
+        candle.volume += size;
+        if is_trade {
+            candle.trades += 1;
+        }
+        if ts < bar.first_ts {
+            bar.first_ts = ts;
+            candle.open = price;
+        }
+        if ts >= bar.last_ts {
+            bar.last_ts = ts;
+            candle.close = price;
+        }
+    }
+
+    fn prune(&mut self) {
+        if let Some(max) = self.max_candles {
+            while self.bars.len() > max {
+                self.bars.pop_first();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Side;
+    use rust_decimal_macros::dec;
+
+    fn trade(ts: u64, price: Decimal, size: Decimal) -> LastTradePriceEvent {
+        LastTradePriceEvent {
+            event_type: "last_trade_price".to_string(),
+            market: "m".to_string(),
+            asset_id: "1".to_string(),
+            price,
+            size,
+            fee_rate_bps: Decimal::ZERO,
+            side: Side::Buy,
+            timestamp: ts.to_string(),
+            transaction_hash: "0x".to_string(),
+        }
+    }
+
+    fn point(secs: u64, price: Decimal) -> PriceHistory {
+        PriceHistory {
+            price,
+            timestamp: secs,
+        }
+    }
+
+    #[test]
+    fn test_trades_bucketed_into_bars() {
+        let mut agg = CandleAggregator::new("1", Duration::from_secs(60));
+        assert!(agg.apply_trade(&trade(60_500, dec!(0.50), dec!(10))));
+        assert!(agg.apply_trade(&trade(61_000, dec!(0.55), dec!(5))));
+        // Out of order: earlier than the first trade, becomes the open
+        assert!(agg.apply_trade(&trade(60_100, dec!(0.45), dec!(1))));
+        assert!(agg.apply_trade(&trade(125_000, dec!(0.52), dec!(2))));
+
+        let candles: Vec<_> = agg.candles().cloned().collect();
+        assert_eq!(candles.len(), 2);
+        assert_eq!(
+            candles[0],
+            Candle {
+                start: 60_000,
+                open: dec!(0.45),
+                high: dec!(0.55),
+                low: dec!(0.45),
+                close: dec!(0.55),
+                volume: dec!(16),
+                trades: 3,
+            }
+        );
+        assert_eq!(agg.latest().unwrap().start, 120_000);
+
+        let mut other = trade(61_000, dec!(0.9), dec!(1));
+        other.asset_id = "2".to_string();
+        assert!(!agg.apply_trade(&other));
+    }
+
+    #[test]
+    fn test_history_then_live_has_no_overlap() {
+        let mut agg = CandleAggregator::new("1", Duration::from_secs(60));
+        assert_eq!(
+            agg.seed_history(&[point(0, dec!(0.40)), point(90, dec!(0.42))]),
+            2
+        );
+
+        // Covered by history
+        assert!(!agg.apply_trade(&trade(90_000, dec!(0.99), dec!(1))));
+        assert!(agg.apply_trade(&trade(95_000, dec!(0.43), dec!(3))));
+
+        let bar = agg.candle_at(60_000).unwrap();
+        assert_eq!(bar.open, dec!(0.42));
+        assert_eq!(bar.close, dec!(0.43));
+        assert_eq!(bar.high, dec!(0.43));
+        assert_eq!(bar.volume, dec!(3));
+        assert_eq!(bar.trades, 1);
+    }
+
+    #[test]
+    fn test_live_then_history_has_no_overlap() {
+        let mut agg = CandleAggregator::new("1", Duration::from_secs(60));
+        agg.apply_trade(&trade(95_000, dec!(0.43), dec!(3)));
+
+        // The point at 120s is already covered by live trades
+        assert_eq!(
+            agg.seed_history(&[point(30, dec!(0.40)), point(120, dec!(0.99))]),
+            1
+        );
+        assert_eq!(agg.len(), 2);
+        assert!(agg.candle_at(120_000).is_none());
+    }
+
+    #[test]
+    fn test_max_candles_drops_oldest() {
+        let mut agg = CandleAggregator::new("1", Duration::from_secs(1)).with_max_candles(2);
+        for i in 0..5 {
+            agg.apply_trade(&trade(i * 1000, dec!(0.5), dec!(1)));
+        }
+        assert_eq!(agg.len(), 2);
+        assert_eq!(agg.candles().next().unwrap().start, 3000);
+    }
+}
@@ -0,0 +1,9 @@
+//! OHLCV candles built from market trades.
+//!
+//! - [`CandleAggregator`]: Buckets `last_trade_price` events for one token into
+//!   fixed-interval bars, optionally seeded from the REST price history
+//! - [`Candle`]: A single OHLCV bar
+
+mod aggregator;
+
+pub use aggregator::{Candle, CandleAggregator};
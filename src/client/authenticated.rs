@@ -1,7 +1,12 @@
+use crate::config::get_contract_config;
 use crate::error::{Error, Result};
 use crate::http::{create_l1_headers, create_l2_headers, HttpClient};
-use crate::signing::EthSigner;
-use crate::types::{ApiCreds, ApiKeysResponse, BalanceAllowanceParams};
+use crate::orders::{validate_order, CollateralState};
+use crate::signing::AsyncEthSigner;
+use crate::types::{
+    ApiCreds, ApiKeysResponse, AssetType, BalanceAllowanceParams, Market, OrderArgs, Side,
+    SignatureType,
+};
 use alloy_primitives::{Address, U256};
 
 /// Client for authenticated operations
@@ -13,7 +18,7 @@ use alloy_primitives::{Address, U256};
 /// while the funder address is used as the order maker.
 pub struct AuthenticatedClient {
     http_client: HttpClient,
-    signer: Box<dyn EthSigner>,
+    signer: Box<dyn AsyncEthSigner>,
     chain_id: u64,
     api_creds: Option<ApiCreds>,
     funder: Option<Address>,
@@ -24,7 +29,8 @@ impl AuthenticatedClient {
     ///
     /// # Arguments
     /// * `host` - The base URL for the API
-    /// * `signer` - The Ethereum signer (used for API authentication)
+    /// * `signer` - The Ethereum signer (used for API authentication); remote
+    ///   signers such as [`RemoteSigner`](crate::signing::RemoteSigner) work too
     /// * `chain_id` - The chain ID (137 for Polygon, 80002 for Amoy testnet)
     /// * `api_creds` - Optional API credentials for L2 operations
     /// * `funder` - Optional funder address (for PolyProxy wallets, this is the proxy wallet address)
@@ -37,7 +43,7 @@ impl AuthenticatedClient {
     /// - Orders are made by the funder address
     pub fn new(
         host: impl Into<String>,
-        signer: impl EthSigner + 'static,
+        signer: impl AsyncEthSigner + 'static,
         chain_id: u64,
         api_creds: Option<ApiCreds>,
         funder: Option<Address>,
@@ -132,7 +138,7 @@ impl AuthenticatedClient {
     /// This creates a new API key for the signer's address.
     /// Requires wallet signature.
     pub async fn create_api_key(&self, nonce: Option<U256>) -> Result<ApiCreds> {
-        let headers = create_l1_headers(&self.signer, self.chain_id, nonce)?;
+        let headers = create_l1_headers(&self.signer, self.chain_id, nonce).await?;
         self.http_client
             .post("/auth/api-key", &serde_json::json!({}), Some(headers))
             .await
@@ -140,7 +146,7 @@ impl AuthenticatedClient {
 
     /// Derive API key from existing credentials (L1 authentication required)
     pub async fn derive_api_key(&self) -> Result<ApiCreds> {
-        let headers = create_l1_headers(&self.signer, self.chain_id, None)?;
+        let headers = create_l1_headers(&self.signer, self.chain_id, None).await?;
         self.http_client
             .get("/auth/derive-api-key", Some(headers))
             .await
@@ -218,6 +224,64 @@ impl AuthenticatedClient {
         self.http_client.get(&request_path, Some(headers)).await
     }
 
+    /// Check an order against its market and the account's collateral before signing
+    ///
+    /// Runs [`validate_order`] with the market rules and, for a BUY, the USDC
+    /// balance and the allowance of the exchange contract the order would go
+    /// to (neg risk or standard, from `market.neg_risk`).
+    ///
+    /// Open orders are not fetched, so the collateral check is an upper bound:
+    /// USDC reserved by the account's open BUY orders still counts as
+    /// available. For the exact check, build a [`CollateralState`] with
+    /// [`with_open_orders`](CollateralState::with_open_orders) and call
+    /// [`validate_order`] directly.
+    ///
+    /// # Arguments
+    /// * `args` - The order to check
+    /// * `market` - The market the order's token belongs to
+    /// * `signature_type` - Signature type the order will be signed with, which
+    ///   selects the wallet whose balance is checked
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Validation`] listing every violated rule, or the error
+    /// from fetching the balance for a BUY.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polymarket_rs::client::{AuthenticatedClient, ClobClient};
+    /// # use polymarket_rs::types::{OrderArgs, Side, SignatureType};
+    /// # use rust_decimal_macros::dec;
+    /// # async fn example(client: AuthenticatedClient, clob: ClobClient) -> polymarket_rs::Result<()> {
+    /// let market = clob.get_market(&"0xabc...".into()).await?;
+    /// let args = OrderArgs::new(&market.tokens[0].token_id, dec!(0.45), dec!(10), Side::Buy);
+    /// client.validate_order(&args, &market, SignatureType::Eoa).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn validate_order(
+        &self,
+        args: &OrderArgs,
+        market: &Market,
+        signature_type: SignatureType,
+    ) -> Result<()> {
+        let collateral = match args.side {
+            Side::Buy => {
+                let params = BalanceAllowanceParams::new()
+                    .asset_type(AssetType::Collateral)
+                    .signature_type(signature_type.to_u8());
+                let response = self.get_balance_allowance(params).await?;
+                let exchange = get_contract_config(self.chain_id, market.neg_risk)?.exchange;
+                Some(CollateralState::from_response(&response, &exchange)?)
+            }
+            Side::Sell => None,
+        };
+
+        validate_order(args, market, collateral.as_ref())?;
+        Ok(())
+    }
+
     /// Update balance allowance (L2 authentication required)
     pub async fn update_balance_allowance(&self) -> Result<serde_json::Value> {
         let api_creds = self
@@ -1,10 +1,10 @@
 use crate::error::Result;
 use crate::http::HttpClient;
-use crate::request::PaginationParams;
+use crate::request::{PaginationParams, PriceHistoryParams};
 use crate::types::{
-    BookParams, ConditionId, Market, MarketsResponse, MidpointResponse, NegRiskResponse,
-    OrderBookSummary, PriceHistoryResponse, PriceResponse, SimplifiedMarketsResponse,
-    SpreadResponse, TickSizeResponse, TokenId,
+    BookParams, ConditionId, FeeRateResponse, Market, MarketsResponse, MidpointResponse,
+    NegRiskResponse, OrderBookSummary, PriceHistoryResponse, PriceResponse,
+    SimplifiedMarketsResponse, SpreadResponse, TickSizeResponse, TokenId,
 };
 use crate::Side;
 
@@ -80,27 +80,24 @@ impl ClobClient {
     ///
     /// # Arguments
     /// * `token_id` - The token ID to query
-    /// * `start_ts` - Optional start timestamp (seconds)
-    /// * `end_ts` - Optional end timestamp (seconds)
-    /// * `interval` - Optional interval (e.g., "1m", "5m", "1h")
-    /// * `fidelity` - Optional fidelity (the resolution of the data, in minutes)
+    /// * `params` - Either an interval ending now or a `startTs`/`endTs` range,
+    ///   plus an optional fidelity
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`](crate::Error::InvalidParameter) if
+    /// `params` combine options the server rejects.
     pub async fn get_prices_history(
         &self,
         token_id: &TokenId,
-        interval: &str,
-        start_ts: Option<u64>,
-        end_ts: Option<u64>,
-        fidelity: Option<u64>,
+        params: &PriceHistoryParams,
     ) -> Result<PriceHistoryResponse> {
-        let mut path = format!("/prices-history?market={}", token_id.as_str());
-        path.push_str(&format!("&interval={}", interval));
-        path.push_str(&format!("&fidelity={}", fidelity.unwrap_or(10)));
-        if let Some(start) = start_ts {
-            path.push_str(&format!("&startTs={}", start));
-        }
-        if let Some(end) = end_ts {
-            path.push_str(&format!("&endTs={}", end));
-        }
+        params.validate()?;
+        let path = format!(
+            "/prices-history?market={}{}",
+            token_id.as_str(),
+            params.to_query_string()
+        );
         self.http_client.get(&path, None).await
     }
 
@@ -130,6 +127,20 @@ impl ClobClient {
         self.http_client.get(&path, None).await
     }
 
+    /// Get whether the market of a token uses negative risk
+    pub async fn get_neg_risk_for_token(&self, token_id: &TokenId) -> Result<NegRiskResponse> {
+        let path = format!("/neg-risk?token_id={}", token_id.as_str());
+        self.http_client.get(&path, None).await
+    }
+
+    /// Get the taker fee rate of a token's market, in basis points
+    ///
+    /// Orders must be signed with this rate as their `fee_rate_bps`.
+    pub async fn get_fee_rate(&self, token_id: &TokenId) -> Result<FeeRateResponse> {
+        let path = format!("/fee-rate?token_id={}", token_id.as_str());
+        self.http_client.get(&path, None).await
+    }
+
     /// Get the order book for a token
     ///
     /// # Arguments
@@ -2,10 +2,12 @@ mod authenticated;
 mod clob;
 mod data;
 mod gamma;
+mod order_options;
 mod trading;
 
 pub use authenticated::AuthenticatedClient;
 pub use clob::ClobClient;
 pub use data::DataClient;
 pub use gamma::GammaClient;
+pub use order_options::OrderOptionsCache;
 pub use trading::TradingClient;
@@ -0,0 +1,487 @@
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+use tokio::time::Instant;
+
+use super::ClobClient;
+use crate::error::{Error, Result};
+use crate::types::{CreateOrderOptions, ExtraOrderArgs, TokenId, WsEvent};
+
+/// How long resolved values are kept by default
+const DEFAULT_TTL: Duration = Duration::from_secs(300);
+
+#[derive(Debug, Default, Clone, Copy)]
+struct CachedOptions {
+    tick_size: Option<(Decimal, Instant)>,
+    neg_risk: Option<(bool, Instant)>,
+    fee_rate_bps: Option<(u32, Instant)>,
+}
+
+/// Cache of the tick size, neg risk flag and fee rate of tokens
+///
+/// Fills in the fields of [`CreateOrderOptions`] the caller left unset by
+/// querying [`ClobClient::get_tick_size`] and
+/// [`ClobClient::get_neg_risk_for_token`], and the fee rate of
+/// [`ExtraOrderArgs`] from [`ClobClient::get_fee_rate`], keeping the answers
+/// for a TTL.
+/// Feed it market events with [`apply_event`](Self::apply_event) or
+/// [`run_stream`](Self::run_stream) so a [`WsEvent::TickSizeChange`] replaces
+/// the cached tick size as soon as the market changes it.
+///
+/// Attach it to a [`TradingClient`](crate::client::TradingClient) with
+/// [`with_order_options_cache`](crate::client::TradingClient::with_order_options_cache).
+/// The cache is cheap to clone; clones share the same entries.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::client::{ClobClient, OrderOptionsCache};
+/// use polymarket_rs::types::CreateOrderOptions;
+///
+/// # async fn run() -> polymarket_rs::Result<()> {
+/// let cache = OrderOptionsCache::new(ClobClient::new("https://clob.polymarket.com"));
+/// let options = cache.resolve("token_id", CreateOrderOptions::default()).await?;
+/// assert!(options.tick_size.is_some() && options.neg_risk.is_some());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct OrderOptionsCache {
+    clob_client: Arc<ClobClient>,
+    ttl: Duration,
+    entries: Arc<Mutex<HashMap<String, CachedOptions>>>,
+}
+
+impl OrderOptionsCache {
+    /// Create a cache that queries `clob_client`, with a five minute TTL
+    pub fn new(clob_client: ClobClient) -> Self {
+        Self {
+            clob_client: Arc::new(clob_client),
+            ttl: DEFAULT_TTL,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Set how long resolved values are kept
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// How long resolved values are kept
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Fill in the tick size and neg risk flag `options` leaves unset
+    ///
+    /// Values already set in `options` are kept. Missing values come from the
+    /// cache, or are fetched and cached if absent or expired.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - Token the order is for
+    /// * `options` - Options supplied by the caller
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the tick size or neg risk request if a value has
+    /// to be fetched and the request fails.
+    pub async fn resolve(
+        &self,
+        token_id: &str,
+        options: CreateOrderOptions,
+    ) -> Result<CreateOrderOptions> {
+        let cached = self.cached(token_id);
+        let now = Instant::now();
+        let fresh = |at: Instant| now.duration_since(at) < self.ttl;
+
+        let tick_size = match (options.tick_size, cached.tick_size) {
+            (Some(tick_size), _) => tick_size,
+            (None, Some((tick_size, at))) if fresh(at) => tick_size,
+            (None, _) => {
+                let tick_size = self
+                    .clob_client
+                    .get_tick_size(&TokenId::new(token_id))
+                    .await?
+                    .minimum_tick_size;
+                self.store(token_id, |entry| {
+                    entry.tick_size = Some((tick_size, Instant::now()))
+                });
+                tick_size
+            }
+        };
+
+        let neg_risk = match (options.neg_risk, cached.neg_risk) {
+            (Some(neg_risk), _) => neg_risk,
+            (None, Some((neg_risk, at))) if fresh(at) => neg_risk,
+            (None, _) => {
+                let neg_risk = self
+                    .clob_client
+                    .get_neg_risk_for_token(&TokenId::new(token_id))
+                    .await?
+                    .neg_risk;
+                self.store(token_id, |entry| {
+                    entry.neg_risk = Some((neg_risk, Instant::now()))
+                });
+                neg_risk
+            }
+        };
+
+        Ok(CreateOrderOptions::default()
+            .tick_size(tick_size)
+            .neg_risk(neg_risk))
+    }
+
+    /// Fill in the market's fee rate when `extras` leaves it at 0
+    ///
+    /// The rate comes from the cache, or is fetched and cached if absent or
+    /// expired.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - Token the order is for
+    /// * `extras` - Extra order arguments supplied by the caller
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidOrder`] if `extras` sets a nonzero rate other
+    /// than the market's, which the exchange would reject, or the error of
+    /// the fee rate request if it fails.
+    pub async fn resolve_fee_rate(
+        &self,
+        token_id: &str,
+        extras: &ExtraOrderArgs,
+    ) -> Result<ExtraOrderArgs> {
+        let fee_rate_bps = match self.fee_rate_bps(token_id) {
+            Some(fee_rate_bps) => fee_rate_bps,
+            None => {
+                let fee_rate_bps = self
+                    .clob_client
+                    .get_fee_rate(&TokenId::new(token_id))
+                    .await?
+                    .base_fee;
+                self.store(token_id, |entry| {
+                    entry.fee_rate_bps = Some((fee_rate_bps, Instant::now()))
+                });
+                fee_rate_bps
+            }
+        };
+
+        with_fee_rate(extras, fee_rate_bps)
+    }
+
+    /// Fill in the cached fee rate when `extras` leaves it at 0
+    ///
+    /// Same as [`resolve_fee_rate`](Self::resolve_fee_rate), but never
+    /// fetches. When the rate is not cached or has expired, `extras` is used
+    /// as is, with a warning if it leaves the rate at 0; fetch the rate with
+    /// [`resolve_fee_rate`](Self::resolve_fee_rate) first to avoid that.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidOrder`] if `extras` sets a nonzero rate other
+    /// than the cached one.
+    pub fn resolve_cached_fee_rate(
+        &self,
+        token_id: &str,
+        extras: &ExtraOrderArgs,
+    ) -> Result<ExtraOrderArgs> {
+        match self.fee_rate_bps(token_id) {
+            Some(fee_rate_bps) => with_fee_rate(extras, fee_rate_bps),
+            None => {
+                if extras.fee_rate_bps == 0 {
+                    log::warn!(
+                        "Fee rate of token {} is not cached, signing with 0 bps",
+                        token_id
+                    );
+                }
+                Ok(extras.clone())
+            }
+        }
+    }
+
+    /// Cached tick size of a token, if present and not expired
+    pub fn tick_size(&self, token_id: &str) -> Option<Decimal> {
+        let (tick_size, at) = self.cached(token_id).tick_size?;
+        (at.elapsed() < self.ttl).then_some(tick_size)
+    }
+
+    /// Cached neg risk flag of a token, if present and not expired
+    pub fn neg_risk(&self, token_id: &str) -> Option<bool> {
+        let (neg_risk, at) = self.cached(token_id).neg_risk?;
+        (at.elapsed() < self.ttl).then_some(neg_risk)
+    }
+
+    /// Cached fee rate of a token in basis points, if present and not expired
+    pub fn fee_rate_bps(&self, token_id: &str) -> Option<u32> {
+        let (fee_rate_bps, at) = self.cached(token_id).fee_rate_bps?;
+        (at.elapsed() < self.ttl).then_some(fee_rate_bps)
+    }
+
+    /// Update the cache from a market event
+    ///
+    /// A [`WsEvent::TickSizeChange`] replaces the cached tick size of its
+    /// token. Returns `true` if the event changed the cache.
+    pub fn apply_event(&self, event: &WsEvent) -> bool {
+        match event {
+            WsEvent::TickSizeChange(change) => {
+                self.store(&change.asset_id, |entry| {
+                    entry.tick_size = Some((change.new_tick_size, Instant::now()))
+                });
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Feed market events from a stream until it ends
+    ///
+    /// Stream errors are logged and skipped.
+    pub async fn run_stream<S>(&self, stream: S)
+    where
+        S: futures_util::Stream<Item = Result<WsEvent>>,
+    {
+        use futures_util::StreamExt;
+
+        futures_util::pin_mut!(stream);
+        while let Some(event) = stream.next().await {
+            match event {
+                Ok(event) => {
+                    self.apply_event(&event);
+                }
+                Err(e) => log::warn!("Order options feed error: {}", e),
+            }
+        }
+    }
+
+    /// Drop the cached values of a token
+    pub fn invalidate(&self, token_id: &str) {
+        self.lock().remove(token_id);
+    }
+
+    /// Drop every cached value
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn cached(&self, token_id: &str) -> CachedOptions {
+        self.lock().get(token_id).copied().unwrap_or_default()
+    }
+
+    fn store(&self, token_id: &str, update: impl FnOnce(&mut CachedOptions)) {
+        update(self.lock().entry(token_id.to_string()).or_default());
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, CachedOptions>> {
+        // Entries stay consistent even if a holder panicked
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Set `fee_rate_bps` on `extras`, unless it already sets a different rate
+fn with_fee_rate(extras: &ExtraOrderArgs, fee_rate_bps: u32) -> Result<ExtraOrderArgs> {
+    if extras.fee_rate_bps != 0 && extras.fee_rate_bps != fee_rate_bps {
+        return Err(Error::InvalidOrder(format!(
+            "Fee rate {} bps does not match the market fee rate {} bps",
+            extras.fee_rate_bps, fee_rate_bps
+        )));
+    }
+    Ok(extras.clone().fee_rate_bps(fee_rate_bps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::server_client;
+    use crate::testing::TestServer;
+    use crate::types::{OrderArgs, OrderType, Side, TickSizeChangeEvent};
+    use crate::Error;
+    use rust_decimal_macros::dec;
+
+    fn tick_size_change(new_tick_size: Decimal) -> WsEvent {
+        WsEvent::TickSizeChange(TickSizeChangeEvent {
+            event_type: "tick_size_change".to_string(),
+            asset_id: "1".to_string(),
+            market: "m".to_string(),
+            old_tick_size: dec!(0.01),
+            new_tick_size,
+            timestamp: "1000".to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_resolve_caches_values() {
+        let server = TestServer::start(137).await.unwrap();
+        server.set_token_options("1", dec!(0.01), true);
+        let cache = OrderOptionsCache::new(ClobClient::new(server.url()));
+
+        let options = cache
+            .resolve("1", CreateOrderOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(options.tick_size, Some(dec!(0.01)));
+        assert_eq!(options.neg_risk, Some(true));
+
+        // Cached values are used until they expire
+        server.set_token_options("1", dec!(0.001), false);
+        let options = cache
+            .resolve("1", CreateOrderOptions::default().neg_risk(false))
+            .await
+            .unwrap();
+        assert_eq!(options.tick_size, Some(dec!(0.01)));
+        assert_eq!(options.neg_risk, Some(false));
+        assert_eq!(cache.neg_risk("1"), Some(true));
+
+        cache.invalidate("1");
+        assert_eq!(cache.tick_size("1"), None);
+        let options = cache
+            .resolve("1", CreateOrderOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(options.tick_size, Some(dec!(0.001)));
+
+        match cache.resolve("2", CreateOrderOptions::default()).await {
+            Err(Error::Api { status: 404, .. }) => {}
+            other => panic!("expected a 404, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tick_size_change_and_expiry() {
+        let server = TestServer::start(137).await.unwrap();
+        server.set_token_options("1", dec!(0.01), false);
+        let cache = OrderOptionsCache::new(ClobClient::new(server.url()));
+        cache
+            .resolve("1", CreateOrderOptions::default())
+            .await
+            .unwrap();
+
+        assert!(cache.apply_event(&tick_size_change(dec!(0.001))));
+        assert_eq!(cache.tick_size("1"), Some(dec!(0.001)));
+
+        let expiring = cache.clone().with_ttl(Duration::ZERO);
+        assert_eq!(expiring.tick_size("1"), None);
+        let options = expiring
+            .resolve("1", CreateOrderOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(options.tick_size, Some(dec!(0.01)));
+    }
+
+    #[tokio::test]
+    async fn test_trading_client_resolves_options() {
+        let server = TestServer::start(137).await.unwrap();
+        server.set_token_options("1", dec!(0.01), false);
+        let client = server_client(&server)
+            .with_order_options_cache(OrderOptionsCache::new(ClobClient::new(server.url())));
+
+        let args = OrderArgs::new("1", dec!(0.45), dec!(10), Side::Buy);
+        let response = client
+            .create_and_post_order(&args, None, None, Default::default(), OrderType::Gtc)
+            .await
+            .unwrap();
+        assert!(response.success);
+        assert_eq!(server.orders()[0].order.fee_rate_bps, "0");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_fee_rate() {
+        let server = TestServer::start(137).await.unwrap();
+        server.set_token_options("1", dec!(0.01), false);
+        server.set_fee_rate("1", 200);
+        let cache = OrderOptionsCache::new(ClobClient::new(server.url()));
+
+        let extras = cache
+            .resolve_fee_rate("1", &ExtraOrderArgs::default())
+            .await
+            .unwrap();
+        assert_eq!(extras.fee_rate_bps, 200);
+        assert_eq!(cache.fee_rate_bps("1"), Some(200));
+
+        let matching = ExtraOrderArgs::default().fee_rate_bps(200);
+        assert!(cache.resolve_fee_rate("1", &matching).await.is_ok());
+        let wrong = ExtraOrderArgs::default().fee_rate_bps(100);
+        assert!(matches!(
+            cache.resolve_fee_rate("1", &wrong).await,
+            Err(Error::InvalidOrder(_))
+        ));
+
+        let client = server_client(&server).with_order_options_cache(cache);
+        let args = OrderArgs::new("1", dec!(0.45), dec!(10), Side::Buy);
+        client
+            .create_and_post_order(&args, None, None, Default::default(), OrderType::Gtc)
+            .await
+            .unwrap();
+        assert_eq!(server.orders()[0].order.fee_rate_bps, "200");
+    }
+
+    #[tokio::test]
+    async fn test_create_methods_resolve_fee_rate() {
+        use crate::types::{MarketOrderArgs, OrderBookSummary, PriceLevel};
+
+        let server = TestServer::start(137).await.unwrap();
+        server.set_token_options("1", dec!(0.01), false);
+        server.set_token_options("2", dec!(0.01), false);
+        server.set_fee_rate("1", 200);
+        let client = server_client(&server)
+            .with_order_options_cache(OrderOptionsCache::new(ClobClient::new(server.url())));
+        let options = CreateOrderOptions::default()
+            .tick_size(dec!(0.01))
+            .neg_risk(false);
+        let book = OrderBookSummary {
+            market: "m".to_string(),
+            asset_id: "1".to_string(),
+            hash: String::new(),
+            timestamp: 0,
+            bids: vec![],
+            asks: vec![PriceLevel {
+                price: dec!(0.5),
+                size: dec!(100),
+            }],
+        };
+        let market_args = MarketOrderArgs::new("1", dec!(10), Side::Buy);
+
+        // Nothing cached yet, and the synchronous method can't fetch
+        let order = client
+            .create_market_order(&market_args, &book, None, options.clone())
+            .unwrap();
+        assert_eq!(order.fee_rate_bps, "0");
+        let extras = ExtraOrderArgs::default().fee_rate_bps(200);
+        let order = client
+            .create_market_order(&market_args, &book, Some(&extras), options.clone())
+            .unwrap();
+        assert_eq!(order.fee_rate_bps, "200");
+
+        let orders = [
+            (
+                OrderArgs::new("1", dec!(0.45), dec!(10), Side::Buy),
+                options.clone(),
+            ),
+            (
+                OrderArgs::new("2", dec!(0.45), dec!(10), Side::Buy),
+                options.clone(),
+            ),
+        ];
+        let signed = client.create_orders(&orders, None, None).await;
+        assert_eq!(signed[0].as_ref().unwrap().0.fee_rate_bps, "200");
+        assert_eq!(signed[1].as_ref().unwrap().0.fee_rate_bps, "0");
+
+        let order = client
+            .create_market_order(&market_args, &book, None, options.clone())
+            .unwrap();
+        assert_eq!(order.fee_rate_bps, "200");
+        let order = client
+            .create_market_order_async(&market_args, &book, None, options.clone())
+            .await
+            .unwrap();
+        assert_eq!(order.fee_rate_bps, "200");
+
+        // A rate that only matches one token fails the other token's orders
+        let extras = ExtraOrderArgs::default().fee_rate_bps(200);
+        let signed = client.create_orders(&orders, None, Some(&extras)).await;
+        assert!(signed[0].is_ok());
+        assert!(matches!(signed[1], Err(Error::InvalidOrder(_))));
+    }
+}
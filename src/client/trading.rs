@@ -1,23 +1,34 @@
-use crate::error::Result;
+use super::OrderOptionsCache;
+use crate::error::{Error, Result};
 use crate::http::{create_l2_headers, HttpClient};
-use crate::orders::{calculate_market_price, OrderBuilder};
-use crate::signing::EthSigner;
+use crate::orders::{
+    ladder_orders, market_order_price_cap, walk_market_order_protected, OrderBuilder,
+};
+use crate::paper::PaperTrading;
+use crate::signing::AsyncEthSigner;
 use crate::types::{
-    ApiCreds, CancelOrdersResponse, CreateOrderOptions, ExtraOrderArgs, MarketOrderArgs, OpenOrder,
-    OpenOrderParams, OpenOrdersResponse, OrderArgs, OrderBookSummary, OrderId, OrderType,
-    PostOrder, PostOrderArgs, PostOrderResponse, Side, SignedOrderRequest, TradeParams,
+    ApiCreds, CancelOrdersResponse, CreateOrderOptions, ExtraOrderArgs, LadderArgs, Market,
+    MarketOrderArgs, OpenOrder, OpenOrderParams, OpenOrdersResponse, OrderArgs, OrderBookSummary,
+    OrderId, OrderType, PostOrder, PostOrderArgs, PostOrderResponse, ReplaceMode, ReplaceOutcome,
+    Side, SignedOrderRequest, TradeParams,
 };
+use rust_decimal::Decimal;
 
 /// Client for trading operations
 ///
 /// This client handles order creation, cancellation, and trade queries.
 /// All operations require L2 authentication (API credentials).
+///
+/// With [`with_paper_trading`](Self::with_paper_trading) the same API is
+/// served by a [`PaperTrading`] backend instead of the exchange.
 pub struct TradingClient {
     http_client: HttpClient,
-    signer: Box<dyn EthSigner>,
+    signer: Box<dyn AsyncEthSigner>,
     chain_id: u64,
     api_creds: ApiCreds,
     order_builder: OrderBuilder,
+    paper: Option<PaperTrading>,
+    order_options: Option<OrderOptionsCache>,
 }
 
 impl TradingClient {
@@ -25,13 +36,13 @@ impl TradingClient {
     ///
     /// # Arguments
     /// * `host` - The base URL for the API
-    /// * `signer` - The Ethereum signer
+    /// * `signer` - The Ethereum signer, used for the address in L2 headers
     /// * `chain_id` - The chain ID (137 for Polygon, 80002 for Amoy testnet)
     /// * `api_creds` - API credentials for authentication
     /// * `order_builder` - OrderBuilder instance for creating orders
     pub fn new(
         host: impl Into<String>,
-        signer: impl EthSigner + 'static,
+        signer: impl AsyncEthSigner + 'static,
         chain_id: u64,
         api_creds: ApiCreds,
         order_builder: OrderBuilder,
@@ -42,9 +53,86 @@ impl TradingClient {
             chain_id,
             api_creds,
             order_builder,
+            paper: None,
+            order_options: None,
+        }
+    }
+
+    /// Resolve missing tick sizes, neg risk flags and fee rates through a cache
+    ///
+    /// With a cache attached, [`create_and_post_order`](Self::create_and_post_order)
+    /// and [`resolve_order_options`](Self::resolve_order_options) fill in the
+    /// fields of [`CreateOrderOptions`] the caller leaves unset. The market's
+    /// fee rate is set by [`resolve_fee_rate`](Self::resolve_fee_rate) and by
+    /// every method that creates orders from [`ExtraOrderArgs`], except
+    /// [`create_order`](Self::create_order) and
+    /// [`create_order_async`](Self::create_order_async), which sign exactly
+    /// what they are given. The synchronous
+    /// [`create_market_order`](Self::create_market_order) only uses a rate
+    /// that is already cached.
+    ///
+    /// # Arguments
+    /// * `cache` - The cache to resolve options through
+    pub fn with_order_options_cache(mut self, cache: OrderOptionsCache) -> Self {
+        self.order_options = Some(cache);
+        self
+    }
+
+    /// Fill in the tick size and neg risk flag `options` leaves unset
+    ///
+    /// Returns `options` unchanged if no [`OrderOptionsCache`] is attached.
+    ///
+    /// # Arguments
+    /// * `token_id` - The token the order is for
+    /// * `options` - Options supplied by the caller
+    pub async fn resolve_order_options(
+        &self,
+        token_id: &str,
+        options: CreateOrderOptions,
+    ) -> Result<CreateOrderOptions> {
+        match &self.order_options {
+            Some(cache) => cache.resolve(token_id, options).await,
+            None => Ok(options),
+        }
+    }
+
+    /// Set the market's fee rate on `extras` when it is left at 0
+    ///
+    /// Returns `extras` (or the defaults) unchanged if no
+    /// [`OrderOptionsCache`] is attached.
+    ///
+    /// # Arguments
+    /// * `token_id` - The token the order is for
+    /// * `extras` - Optional extra order parameters supplied by the caller
+    pub async fn resolve_fee_rate(
+        &self,
+        token_id: &str,
+        extras: Option<&ExtraOrderArgs>,
+    ) -> Result<ExtraOrderArgs> {
+        let extras = extras.cloned().unwrap_or_default();
+        match &self.order_options {
+            Some(cache) => cache.resolve_fee_rate(token_id, &extras).await,
+            None => Ok(extras),
         }
     }
 
+    /// Trade against a paper-trading backend instead of the exchange
+    ///
+    /// Orders are still created and signed as usual, but posting,
+    /// cancelling and querying orders and trades go to `paper`.
+    ///
+    /// # Arguments
+    /// * `paper` - The backend to route orders to
+    pub fn with_paper_trading(mut self, paper: PaperTrading) -> Self {
+        self.paper = Some(paper);
+        self
+    }
+
+    /// The paper-trading backend, if one is attached
+    pub fn paper_trading(&self) -> Option<&PaperTrading> {
+        self.paper.as_ref()
+    }
+
     /// Create a limit order (local operation, not posted)
     ///
     /// # Arguments
@@ -52,6 +140,11 @@ impl TradingClient {
     /// * `expiration` - Optional expiration timestamp (defaults to 0 = no expiration)
     /// * `extras` - Optional extra order parameters (defaults to ExtraOrderArgs::default())
     /// * `options` - Order options (tick_size, neg_risk must be provided)
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::Signing`] if the order builder was created with
+    /// [`OrderBuilder::new_async`]; use [`create_order_async`](Self::create_order_async).
     pub fn create_order(
         &self,
         order_args: &OrderArgs,
@@ -67,13 +160,151 @@ impl TradingClient {
             .create_order(self.chain_id, order_args, expiration, extras, options)
     }
 
+    /// Create a limit order, signing it asynchronously (local operation, not posted)
+    ///
+    /// Same as [`create_order`](Self::create_order), but also works with an
+    /// [`OrderBuilder`] created with [`OrderBuilder::new_async`], e.g. for a
+    /// [`RemoteSigner`](crate::signing::RemoteSigner).
+    ///
+    /// # Arguments
+    /// * `order_args` - Order arguments (token_id, price, size, side)
+    /// * `expiration` - Optional expiration timestamp (defaults to 0 = no expiration)
+    /// * `extras` - Optional extra order parameters (defaults to ExtraOrderArgs::default())
+    /// * `options` - Order options (tick_size, neg_risk must be provided)
+    pub async fn create_order_async(
+        &self,
+        order_args: &OrderArgs,
+        expiration: Option<u64>,
+        extras: Option<&ExtraOrderArgs>,
+        options: CreateOrderOptions,
+    ) -> Result<SignedOrderRequest> {
+        let expiration = expiration.unwrap_or(0);
+        let default_extras = ExtraOrderArgs::default();
+        let extras = extras.unwrap_or(&default_extras);
+
+        self.order_builder
+            .create_order_async(self.chain_id, order_args, expiration, extras, options)
+            .await
+    }
+
+    /// Create and sign many limit orders in parallel (local operation, not posted)
+    ///
+    /// See [`OrderBuilder::create_orders`]. Results are in input order, each
+    /// with its order hash, and failures are reported per order.
+    ///
+    /// # Arguments
+    /// * `orders` - Each order with its options (tick_size, neg_risk must be provided)
+    /// * `expiration` - Optional expiration timestamp (defaults to 0 = no expiration)
+    /// * `extras` - Optional extra order parameters (defaults to ExtraOrderArgs::default(),
+    ///   with each token's fee rate if an [`OrderOptionsCache`] is attached)
+    pub async fn create_orders(
+        &self,
+        orders: &[(OrderArgs, CreateOrderOptions)],
+        expiration: Option<u64>,
+        extras: Option<&ExtraOrderArgs>,
+    ) -> Vec<Result<(SignedOrderRequest, OrderId)>> {
+        let expiration = expiration.unwrap_or(0);
+        // Cached after the first order of each token
+        let mut resolved = Vec::with_capacity(orders.len());
+        for (args, _) in orders {
+            resolved.push(self.resolve_fee_rate(&args.token_id, extras).await);
+        }
+
+        self.order_builder
+            .create_orders_with_extras(self.chain_id, orders, expiration, resolved)
+            .await
+    }
+
+    /// Create and sign a ladder of limit orders (local operation, not posted)
+    ///
+    /// The levels come from [`ladder_orders`] and are signed in parallel with
+    /// [`create_orders`](Self::create_orders), ready for
+    /// [`post_orders`](Self::post_orders).
+    ///
+    /// # Arguments
+    /// * `args` - The ladder's prices, levels and sizing
+    /// * `market` - The market of `args.token_id`, for its tick, minimum size and neg risk flag
+    /// * `order_type` - The order type of every level (GTC or GTD)
+    /// * `expiration` - Optional expiration timestamp (defaults to 0 = no expiration)
+    /// * `extras` - Optional extra order parameters (defaults to ExtraOrderArgs::default(),
+    ///   with the market's fee rate if an [`OrderOptionsCache`] is attached)
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from [`ladder_orders`], or the first fee rate or
+    /// signing error.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use polymarket_rs::client::{ClobClient, TradingClient};
+    /// # use polymarket_rs::types::{LadderArgs, LadderSizing, OrderType, Side};
+    /// # use rust_decimal_macros::dec;
+    /// # async fn example(client: &TradingClient, clob: &ClobClient) -> polymarket_rs::Result<()> {
+    /// let market = clob.get_market(&"0xabc...".into()).await?;
+    /// let args = LadderArgs::new(
+    ///     &market.tokens[0].token_id,
+    ///     Side::Sell,
+    ///     dec!(0.55),
+    ///     dec!(0.60),
+    ///     5,
+    ///     LadderSizing::PerLevel(dec!(20)),
+    /// );
+    /// let orders = client.create_ladder(&args, &market, OrderType::Gtc, None, None).await?;
+    /// let responses = client.post_orders(&orders).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_ladder(
+        &self,
+        args: &LadderArgs,
+        market: &Market,
+        order_type: OrderType,
+        expiration: Option<u64>,
+        extras: Option<&ExtraOrderArgs>,
+    ) -> Result<Vec<PostOrderArgs>> {
+        let options = CreateOrderOptions::default()
+            .tick_size(market.minimum_tick_size)
+            .neg_risk(market.neg_risk);
+        let orders: Vec<(OrderArgs, CreateOrderOptions)> = ladder_orders(args, market)?
+            .into_iter()
+            .map(|order| (order, options.clone()))
+            .collect();
+
+        self.create_orders(&orders, expiration, extras)
+            .await
+            .into_iter()
+            .map(|signed| signed.map(|(order, _)| PostOrderArgs::new(order, order_type)))
+            .collect()
+    }
+
     /// Create a market order (local operation, not posted)
     ///
+    /// The book is walked for `order_args.amount`, which is USDC notional for
+    /// a BUY and shares for a SELL, and the order is signed at the worst price
+    /// the walk reaches, so it can take every level the walk counted on.
+    ///
+    /// If `order_args` sets `max_slippage` or `limit_price`, the order is
+    /// rejected before signing when it cannot be filled within that price, or
+    /// its amount is truncated when `on_slippage` is
+    /// [`SlippageAction::Truncate`](crate::types::SlippageAction::Truncate).
+    /// The order is then signed at the price cap instead, so the exchange
+    /// enforces it too.
+    ///
     /// # Arguments
     /// * `order_args` - Market order arguments (token_id, amount, side)
     /// * `order_book` - The order book to calculate price from
-    /// * `extras` - Optional extra order parameters (defaults to ExtraOrderArgs::default())
+    /// * `extras` - Optional extra order parameters (defaults to ExtraOrderArgs::default(),
+    ///   with the fee rate cached by an attached [`OrderOptionsCache`], if any)
     /// * `options` - Order options (tick_size, neg_risk must be provided)
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::Signing`] if the order builder was created with
+    /// [`OrderBuilder::new_async`]; use
+    /// [`create_market_order_async`](Self::create_market_order_async). If an
+    /// [`OrderOptionsCache`] is attached but the token's fee rate is not
+    /// cached, the rate in `extras` (0 by default) is signed with a warning;
+    /// call [`resolve_fee_rate`](Self::resolve_fee_rate) first to avoid that.
     pub fn create_market_order(
         &self,
         order_args: &MarketOrderArgs,
@@ -81,20 +312,85 @@ impl TradingClient {
         extras: Option<&ExtraOrderArgs>,
         options: CreateOrderOptions,
     ) -> Result<SignedOrderRequest> {
-        let default_extras = ExtraOrderArgs::default();
-        let extras = extras.unwrap_or(&default_extras);
+        let extras = extras.cloned().unwrap_or_default();
+        let extras = match &self.order_options {
+            Some(cache) => cache.resolve_cached_fee_rate(&order_args.token_id, &extras)?,
+            None => extras,
+        };
+        let (order_args, price) =
+            Self::walk_for_market_order(order_args, order_book, options.tick_size)?;
+
+        self.order_builder
+            .create_market_order(self.chain_id, &order_args, price, &extras, options)
+    }
+
+    /// Create a market order, signing it asynchronously (local operation, not posted)
+    ///
+    /// Same as [`create_market_order`](Self::create_market_order), but also
+    /// works with an [`OrderBuilder`] created with [`OrderBuilder::new_async`],
+    /// and fetches the fee rate if it is not cached.
+    ///
+    /// # Arguments
+    /// * `order_args` - Market order arguments (token_id, amount, side)
+    /// * `order_book` - The order book to calculate price from
+    /// * `extras` - Optional extra order parameters (defaults to ExtraOrderArgs::default(),
+    ///   with the market's fee rate if an [`OrderOptionsCache`] is attached)
+    /// * `options` - Order options (tick_size, neg_risk must be provided)
+    pub async fn create_market_order_async(
+        &self,
+        order_args: &MarketOrderArgs,
+        order_book: &OrderBookSummary,
+        extras: Option<&ExtraOrderArgs>,
+        options: CreateOrderOptions,
+    ) -> Result<SignedOrderRequest> {
+        let extras = self.resolve_fee_rate(&order_args.token_id, extras).await?;
+        let (order_args, price) =
+            Self::walk_for_market_order(order_args, order_book, options.tick_size)?;
 
+        self.order_builder
+            .create_market_order_async(self.chain_id, &order_args, price, &extras, options)
+            .await
+    }
+
+    /// Walk the book for a market order, returning the arguments and the
+    /// price to sign
+    ///
+    /// The price is the price cap if there is one, or else the worst level the
+    /// walk takes. A cap is snapped to the tick and kept one tick inside 0 and 1.
+    fn walk_for_market_order(
+        order_args: &MarketOrderArgs,
+        order_book: &OrderBookSummary,
+        tick_size: Option<Decimal>,
+    ) -> Result<(MarketOrderArgs, Decimal)> {
         // Use asks for BUY (taking from sellers), bids for SELL (taking from buyers)
         let book_side = match order_args.side {
             Side::Buy => &order_book.asks,
             Side::Sell => &order_book.bids,
         };
 
-        // Calculate market price from order book
-        let price = calculate_market_price(book_side, order_args.amount, order_args.side)?;
+        // Walk the book, enforcing any price cap
+        let walk = walk_market_order_protected(book_side, order_args)?;
+        let price = match (market_order_price_cap(book_side, order_args)?, tick_size) {
+            (Some(cap), Some(tick)) => {
+                // Snap toward the book so rounding never loosens the cap
+                let ticks = match order_args.side {
+                    Side::Buy => (cap / tick).floor(),
+                    Side::Sell => (cap / tick).ceil(),
+                };
+                (ticks * tick).clamp(tick, Decimal::ONE - tick)
+            }
+            _ => walk.worst_price,
+        };
 
-        self.order_builder
-            .create_market_order(self.chain_id, order_args, price, extras, options)
+        // A truncated walk fills less than requested, so sign for what it fills
+        let filled = match order_args.side {
+            Side::Buy => walk.cost,
+            Side::Sell => walk.size,
+        };
+        let mut order_args = order_args.clone();
+        order_args.amount = order_args.amount.min(filled);
+
+        Ok((order_args, price))
     }
 
     /// Post an order to the exchange
@@ -107,6 +403,10 @@ impl TradingClient {
         order: SignedOrderRequest,
         order_type: OrderType,
     ) -> Result<PostOrderResponse> {
+        if let Some(paper) = &self.paper {
+            return paper.post_order(&order, order_type, &self.api_creds.api_key, self.chain_id);
+        }
+
         let owner = self.api_creds.api_key.clone();
         let post_order = PostOrder::new(order, owner, order_type);
 
@@ -140,6 +440,10 @@ impl TradingClient {
     /// # }
     /// ```
     pub async fn post_orders(&self, orders: &[PostOrderArgs]) -> Result<Vec<PostOrderResponse>> {
+        if let Some(paper) = &self.paper {
+            return Ok(paper.post_orders(orders, &self.api_creds.api_key, self.chain_id));
+        }
+
         let owner = self.api_creds.api_key.clone();
 
         // Build array of PostOrder structs
@@ -168,8 +472,10 @@ impl TradingClient {
     /// # Arguments
     /// * `order_args` - Order arguments (token_id, price, size, side)
     /// * `expiration` - Optional expiration timestamp (defaults to 0 = no expiration)
-    /// * `extras` - Optional extra order parameters (defaults to ExtraOrderArgs::default())
-    /// * `options` - Order options (tick_size, neg_risk must be provided)
+    /// * `extras` - Optional extra order parameters (defaults to ExtraOrderArgs::default(),
+    ///   with the market's fee rate if an [`OrderOptionsCache`] is attached)
+    /// * `options` - Order options (tick_size, neg_risk must be provided unless an
+    ///   [`OrderOptionsCache`] is attached)
     /// * `order_type` - The order type (GTC, FOK, FAK, GTD)
     pub async fn create_and_post_order(
         &self,
@@ -179,7 +485,13 @@ impl TradingClient {
         options: CreateOrderOptions,
         order_type: OrderType,
     ) -> Result<PostOrderResponse> {
-        let order = self.create_order(order_args, expiration, extras, options)?;
+        let options = self
+            .resolve_order_options(&order_args.token_id, options)
+            .await?;
+        let extras = self.resolve_fee_rate(&order_args.token_id, extras).await?;
+        let order = self
+            .create_order_async(order_args, expiration, Some(&extras), options)
+            .await?;
         self.post_order(order, order_type).await
     }
 
@@ -188,6 +500,10 @@ impl TradingClient {
     /// # Arguments
     /// * `params` - Query parameters to filter orders
     pub async fn get_orders(&self, params: OpenOrderParams) -> Result<OpenOrdersResponse> {
+        if let Some(paper) = &self.paper {
+            return Ok(paper.get_orders(&params));
+        }
+
         // IMPORTANT: Sign the base path WITHOUT query parameters
         // Query parameters are added to the URL after signing
         let base_path = "/data/orders";
@@ -215,6 +531,10 @@ impl TradingClient {
 
     /// Get a specific order by ID
     pub async fn get_order(&self, order_id: &OrderId) -> Result<OpenOrder> {
+        if let Some(paper) = &self.paper {
+            return paper.get_order(order_id);
+        }
+
         let path = format!("/data/order/{}", order_id.as_str());
         let headers =
             create_l2_headers::<_, ()>(&self.signer, &self.api_creds, "GET", &path, None)?;
@@ -226,6 +546,10 @@ impl TradingClient {
     /// # Arguments
     /// * `order_id` - The ID of the order to cancel
     pub async fn cancel(&self, order_id: &OrderId) -> Result<CancelOrdersResponse> {
+        if let Some(paper) = &self.paper {
+            return Ok(paper.cancel_orders(std::slice::from_ref(order_id)));
+        }
+
         let body = serde_json::json!({ "orderID": order_id.as_str() });
         let headers = create_l2_headers(
             &self.signer,
@@ -244,6 +568,10 @@ impl TradingClient {
     /// # Arguments
     /// * `order_ids` - List of order IDs to cancel
     pub async fn cancel_orders(&self, order_ids: &[OrderId]) -> Result<CancelOrdersResponse> {
+        if let Some(paper) = &self.paper {
+            return Ok(paper.cancel_orders(order_ids));
+        }
+
         let ids: Vec<&str> = order_ids.iter().map(|id| id.as_str()).collect();
         let body = serde_json::json!(ids);
         let headers = create_l2_headers(
@@ -258,8 +586,98 @@ impl TradingClient {
             .await
     }
 
+    /// Replace an open order with a new signed order
+    ///
+    /// With [`ReplaceMode::CancelFirst`] the old order is cancelled and the new
+    /// one posted only if the cancel went through. With
+    /// [`ReplaceMode::PostFirst`] the new order is posted and the old one
+    /// cancelled only if the post was accepted. Either way the old order is
+    /// read back with [`get_order`](Self::get_order) after the cancel, so the
+    /// outcome carries how much of it filled.
+    ///
+    /// The two steps are separate requests, so the replace is not atomic: the
+    /// old order can fill before the cancel lands. Failures are not returned as
+    /// an error but recorded per step in the [`ReplaceOutcome`].
+    ///
+    /// # Arguments
+    /// * `old_order_id` - The order to replace
+    /// * `order` - The signed replacement order
+    /// * `order_type` - The replacement's order type (GTC, FOK, FAK, GTD)
+    /// * `mode` - Whether to cancel or post first
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use polymarket_rs::client::TradingClient;
+    /// # use polymarket_rs::types::{OrderId, OrderType, ReplaceMode, SignedOrderRequest};
+    /// # async fn example(client: &TradingClient, old: OrderId, order: SignedOrderRequest) {
+    /// let outcome = client
+    ///     .replace_order(&old, order, OrderType::Gtc, ReplaceMode::PostFirst)
+    ///     .await;
+    /// if !outcome.is_replaced() {
+    ///     eprintln!("replace incomplete: {:?}", outcome);
+    /// }
+    /// # }
+    /// ```
+    pub async fn replace_order(
+        &self,
+        old_order_id: &OrderId,
+        order: SignedOrderRequest,
+        order_type: OrderType,
+        mode: ReplaceMode,
+    ) -> ReplaceOutcome {
+        let (cancel, post) = match mode {
+            ReplaceMode::CancelFirst => {
+                let cancel = self.cancel_for_replace(old_order_id).await;
+                let post = match cancel {
+                    Ok(()) => Some(self.post_for_replace(order, order_type).await),
+                    Err(_) => None,
+                };
+                (Some(cancel), post)
+            }
+            ReplaceMode::PostFirst => {
+                let post = self.post_for_replace(order, order_type).await;
+                let cancel = match post {
+                    Ok(_) => Some(self.cancel_for_replace(old_order_id).await),
+                    Err(_) => None,
+                };
+                (cancel, Some(post))
+            }
+        };
+
+        ReplaceOutcome {
+            mode,
+            old_order_id: old_order_id.clone(),
+            cancel,
+            post,
+            old_order: self.get_order(old_order_id).await,
+        }
+    }
+
+    async fn cancel_for_replace(&self, order_id: &OrderId) -> Result<()> {
+        self.cancel(order_id).await?.result_for(order_id)
+    }
+
+    async fn post_for_replace(
+        &self,
+        order: SignedOrderRequest,
+        order_type: OrderType,
+    ) -> Result<PostOrderResponse> {
+        let response = self.post_order(order, order_type).await?;
+        if !response.success {
+            return Err(Error::InvalidOrder(format!(
+                "Order rejected: {}",
+                response.error_msg
+            )));
+        }
+        Ok(response)
+    }
+
     /// Cancel all orders
     pub async fn cancel_all(&self) -> Result<CancelOrdersResponse> {
+        if let Some(paper) = &self.paper {
+            return Ok(paper.cancel_market_orders(None, None));
+        }
+
         let body = serde_json::json!({});
         let headers = create_l2_headers(
             &self.signer,
@@ -283,6 +701,10 @@ impl TradingClient {
         market: Option<&str>,
         asset_id: Option<&str>,
     ) -> Result<CancelOrdersResponse> {
+        if let Some(paper) = &self.paper {
+            return Ok(paper.cancel_market_orders(market, asset_id));
+        }
+
         // Python SDK always sends both fields, defaulting to empty strings
         let body = serde_json::json!({
             "market": market.unwrap_or(""),
@@ -306,6 +728,10 @@ impl TradingClient {
     /// # Arguments
     /// * `params` - Query parameters to filter trades
     pub async fn get_trades(&self, params: TradeParams) -> Result<serde_json::Value> {
+        if let Some(paper) = &self.paper {
+            return Ok(paper.get_trades(&params));
+        }
+
         // IMPORTANT: Sign the base path WITHOUT query parameters
         let base_path = "/data/trades";
         let headers =
@@ -331,7 +757,13 @@ impl TradingClient {
     }
 
     /// Check if an order is scoring
+    ///
+    /// Paper orders never score.
     pub async fn is_order_scoring(&self, order_id: &OrderId) -> Result<serde_json::Value> {
+        if self.paper.is_some() {
+            return Ok(serde_json::json!({ "scoring": false }));
+        }
+
         // IMPORTANT: Sign the base path WITHOUT query parameters
         let base_path = "/order-scoring";
         let headers =
@@ -344,7 +776,17 @@ impl TradingClient {
     }
 
     /// Check if multiple orders are scoring
+    ///
+    /// Paper orders never score.
     pub async fn are_orders_scoring(&self, order_ids: &[OrderId]) -> Result<serde_json::Value> {
+        if self.paper.is_some() {
+            let scoring: serde_json::Map<_, _> = order_ids
+                .iter()
+                .map(|id| (id.to_string(), false.into()))
+                .collect();
+            return Ok(serde_json::Value::Object(scoring));
+        }
+
         let ids: Vec<&str> = order_ids.iter().map(|id| id.as_str()).collect();
         let body = serde_json::json!(ids);
         let headers = create_l2_headers(
@@ -359,3 +801,222 @@ impl TradingClient {
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::server_client;
+    use crate::testing::TestServer;
+    use crate::types::{PriceLevel, ReplaceMode};
+    use alloy_signer_local::PrivateKeySigner;
+    use rust_decimal_macros::dec;
+
+    fn client() -> TradingClient {
+        let signer = PrivateKeySigner::random();
+        let creds = ApiCreds::new("key".into(), "secret".into(), "pass".into());
+        let builder = OrderBuilder::new(signer.clone(), None, None);
+        TradingClient::new("http://localhost", signer, 137, creds, builder)
+    }
+
+    fn options() -> CreateOrderOptions {
+        CreateOrderOptions::default()
+            .tick_size(dec!(0.01))
+            .neg_risk(false)
+    }
+
+    fn book(bids: &[(Decimal, Decimal)], asks: &[(Decimal, Decimal)]) -> OrderBookSummary {
+        let levels = |levels: &[(Decimal, Decimal)]| {
+            levels
+                .iter()
+                .map(|&(price, size)| PriceLevel { price, size })
+                .collect()
+        };
+        OrderBookSummary {
+            market: "m".to_string(),
+            asset_id: "1".to_string(),
+            hash: String::new(),
+            timestamp: 0,
+            bids: levels(bids),
+            asks: levels(asks),
+        }
+    }
+
+    /// Price implied by the signed amounts
+    fn price(order: &SignedOrderRequest) -> Decimal {
+        let maker: Decimal = order.maker_amount.parse().unwrap();
+        let taker: Decimal = order.taker_amount.parse().unwrap();
+        match order.side.as_str() {
+            "BUY" => maker / taker,
+            _ => taker / maker,
+        }
+    }
+
+    #[test]
+    fn test_market_order_covers_worst_level() {
+        let client = client();
+        let book = book(
+            &[(dec!(0.50), dec!(10)), (dec!(0.45), dec!(10))],
+            &[(dec!(0.50), dec!(10)), (dec!(0.55), dec!(20))],
+        );
+
+        // 10.5 USDC takes 10 shares at 0.50 and 10 at 0.55 (average 0.525)
+        let args = MarketOrderArgs::new("1", dec!(10.5), Side::Buy);
+        let order = client
+            .create_market_order(&args, &book, None, options())
+            .unwrap();
+        assert_eq!(order.maker_amount, "10500000");
+        assert_eq!(order.taker_amount, "19090900");
+        assert!(price(&order) >= dec!(0.55));
+
+        // 15 shares take 10 at 0.50 and 5 at 0.45
+        let args = MarketOrderArgs::new("1", dec!(15), Side::Sell);
+        let order = client
+            .create_market_order(&args, &book, None, options())
+            .unwrap();
+        assert_eq!(order.maker_amount, "15000000");
+        assert_eq!(order.taker_amount, "6750000");
+        assert!(price(&order) <= dec!(0.45));
+    }
+
+    #[test]
+    fn test_market_order_is_signed_at_price_cap() {
+        let client = client();
+        let book = book(&[], &[(dec!(0.50), dec!(10)), (dec!(0.55), dec!(20))]);
+
+        // 3% above the best ask is 0.515, snapped down to 0.51
+        let args = MarketOrderArgs::new("1", dec!(4), Side::Buy).max_slippage(dec!(0.03));
+        let order = client
+            .create_market_order(&args, &book, None, options())
+            .unwrap();
+        assert_eq!(price(&order).round_dp(4), dec!(0.51));
+
+        let args = MarketOrderArgs::new("1", dec!(4), Side::Buy).limit_price(dec!(2));
+        let order = client
+            .create_market_order(&args, &book, None, options())
+            .unwrap();
+        assert_eq!(price(&order).round_dp(4), dec!(0.99));
+    }
+
+    #[tokio::test]
+    async fn test_replace_order() {
+        let server = TestServer::start(137).await.unwrap();
+        let client = server_client(&server);
+        let order = |price| {
+            let args = OrderArgs::new("1", price, dec!(10), Side::Buy);
+            client.create_order(&args, None, None, options()).unwrap()
+        };
+
+        let old = client.post_order(order(dec!(0.45)), OrderType::Gtc).await;
+        let old = old.unwrap().order_id;
+        let outcome = client
+            .replace_order(
+                &old,
+                order(dec!(0.46)),
+                OrderType::Gtc,
+                ReplaceMode::PostFirst,
+            )
+            .await;
+        assert!(outcome.is_replaced());
+        assert_eq!(outcome.old_size_matched(), Some(dec!(0)));
+        let new = outcome.new_order_id().unwrap().clone();
+        let statuses: Vec<String> = server.orders().into_iter().map(|o| o.status).collect();
+        assert_eq!(statuses, vec!["CANCELED", "LIVE"]);
+
+        // The old order is already gone, so the cancel fails and nothing is posted
+        let outcome = client
+            .replace_order(
+                &old,
+                order(dec!(0.47)),
+                OrderType::Gtc,
+                ReplaceMode::CancelFirst,
+            )
+            .await;
+        assert!(matches!(outcome.cancel, Some(Err(Error::InvalidOrder(_)))));
+        assert!(outcome.post.is_none());
+        assert_eq!(outcome.old_order.unwrap().status, "CANCELED");
+        assert_eq!(server.orders().len(), 2);
+
+        // A rejected post leaves the old order live
+        let mut tampered = order(dec!(0.46));
+        tampered.taker_amount = "20000000".to_string();
+        let outcome = client
+            .replace_order(&new, tampered, OrderType::Gtc, ReplaceMode::PostFirst)
+            .await;
+        assert!(matches!(outcome.post, Some(Err(_))));
+        assert!(outcome.cancel.is_none());
+        assert_eq!(outcome.old_order.unwrap().status, "LIVE");
+
+        let outcome = client
+            .replace_order(
+                &new,
+                order(dec!(0.47)),
+                OrderType::Gtc,
+                ReplaceMode::CancelFirst,
+            )
+            .await;
+        assert!(outcome.is_replaced());
+        assert_eq!(server.orders()[1].status, "CANCELED");
+    }
+
+    #[tokio::test]
+    async fn test_replace_order_cancel_first_with_rejected_post() {
+        let server = TestServer::start(137).await.unwrap();
+        let client = server_client(&server);
+        let args = OrderArgs::new("1", dec!(0.45), dec!(10), Side::Buy);
+        let old = client
+            .post_order(
+                client.create_order(&args, None, None, options()).unwrap(),
+                OrderType::Gtc,
+            )
+            .await
+            .unwrap()
+            .order_id;
+
+        // The cancel lands, then the exchange rejects the new order
+        let mut tampered = client.create_order(&args, None, None, options()).unwrap();
+        tampered.taker_amount = "20000000".to_string();
+        let outcome = client
+            .replace_order(&old, tampered, OrderType::Gtc, ReplaceMode::CancelFirst)
+            .await;
+        assert_eq!(outcome.mode, ReplaceMode::CancelFirst);
+        assert_eq!(outcome.old_order_id, old);
+        assert!(outcome.is_canceled());
+        assert!(matches!(outcome.post, Some(Err(_))));
+        assert!(!outcome.is_replaced());
+        assert!(outcome.new_order_id().is_none());
+        assert_eq!(outcome.old_order.unwrap().status, "CANCELED");
+
+        let statuses: Vec<String> = server.orders().into_iter().map(|o| o.status).collect();
+        assert_eq!(statuses, vec!["CANCELED"]);
+    }
+
+    #[tokio::test]
+    async fn test_create_ladder_rejects_foreign_token() {
+        use crate::types::{LadderArgs, LadderSizing};
+
+        let client = client();
+        let market = crate::fixtures::market();
+        let ladder = |token_id: &str| {
+            LadderArgs::new(
+                token_id,
+                Side::Buy,
+                dec!(0.45),
+                dec!(0.40),
+                3,
+                LadderSizing::PerLevel(dec!(10)),
+            )
+        };
+
+        let orders = client
+            .create_ladder(&ladder("2"), &market, OrderType::Gtc, None, None)
+            .await
+            .unwrap();
+        assert_eq!(orders.len(), 3);
+        assert!(matches!(
+            client
+                .create_ladder(&ladder("3"), &market, OrderType::Gtc, None, None)
+                .await,
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+}
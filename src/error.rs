@@ -47,6 +47,12 @@ pub enum Error {
         attempts: u32,
         last_error: String,
     },
+
+    /// File I/O failed
+    Io(std::io::Error),
+
+    /// Order breaks market or account rules
+    Validation(crate::orders::ValidationError),
 }
 
 impl fmt::Display for Error {
@@ -74,6 +80,8 @@ impl fmt::Display for Error {
                 "Reconnection failed after {} attempts: {}",
                 attempts, last_error
             ),
+            Error::Io(e) => write!(f, "I/O error: {}", e),
+            Error::Validation(e) => write!(f, "Order validation failed: {}", e),
         }
     }
 }
@@ -84,6 +92,8 @@ impl std::error::Error for Error {
             Error::Http(e) => Some(e),
             Error::Json(e) => Some(e),
             Error::Decimal(e) => Some(e),
+            Error::Io(e) => Some(e),
+            Error::Validation(e) => Some(e),
             _ => None,
         }
     }
@@ -107,6 +117,18 @@ impl From<rust_decimal::Error> for Error {
     }
 }
 
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<crate::orders::ValidationError> for Error {
+    fn from(err: crate::orders::ValidationError) -> Self {
+        Error::Validation(err)
+    }
+}
+
 impl From<alloy_signer::Error> for Error {
     fn from(err: alloy_signer::Error) -> Self {
         Error::Signing(err.to_string())
@@ -0,0 +1,100 @@
+use crate::backtest::MarketRules;
+use crate::client::TradingClient;
+use crate::orders::OrderBuilder;
+use crate::paper::PaperTrading;
+use crate::testing::TestServer;
+use crate::types::{BookEvent, LastTradePriceEvent, Market, PriceLevel, Side, WsEvent};
+use alloy_signer_local::PrivateKeySigner;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+/// Book of token "1" in market "m": 50 bid at 0.48 and 10 offered at 0.52
+pub(crate) fn book() -> WsEvent {
+    WsEvent::Book(BookEvent {
+        event_type: "book".to_string(),
+        market: "m".to_string(),
+        asset_id: "1".to_string(),
+        timestamp: "1000".to_string(),
+        hash: String::new(),
+        bids: vec![PriceLevel {
+            price: dec!(0.48),
+            size: dec!(50),
+        }],
+        asks: vec![PriceLevel {
+            price: dec!(0.52),
+            size: dec!(10),
+        }],
+        last_trade_price: None,
+    })
+}
+
+/// Trade of token "1" printed after [`book`]
+pub(crate) fn trade(side: Side, price: Decimal, size: Decimal) -> WsEvent {
+    WsEvent::LastTradePrice(LastTradePriceEvent {
+        event_type: "last_trade_price".to_string(),
+        market: "m".to_string(),
+        asset_id: "1".to_string(),
+        price,
+        size,
+        fee_rate_bps: Decimal::ZERO,
+        side,
+        timestamp: "3000".to_string(),
+        transaction_hash: "0x".to_string(),
+    })
+}
+
+/// Paper backend with 100 USDC, trading token "1" (0.01 tick, minimum size 5)
+/// against [`book`]
+pub(crate) fn paper() -> PaperTrading {
+    let paper = PaperTrading::new(dec!(100));
+    paper.add_token("1", MarketRules::new(dec!(0.01), dec!(5)));
+    paper.apply_event(&book());
+    paper
+}
+
+/// Binary market with tokens "1" and "2", a 0.01 tick and a minimum size of 5
+pub(crate) fn market() -> Market {
+    serde_json::from_value(serde_json::json!({
+        "condition_id": "0xabc",
+        "tokens": [
+            { "token_id": "1", "outcome": "Yes" },
+            { "token_id": "2", "outcome": "No" }
+        ],
+        "rewards": { "rates": null, "min_size": 0, "max_spread": 0 },
+        "min_incentive_size": null,
+        "max_incentive_spread": null,
+        "active": true,
+        "closed": false,
+        "enable_order_book": true,
+        "archived": false,
+        "accepting_orders": true,
+        "accepting_order_timestamp": null,
+        "question_id": "0xdef",
+        "question": "?",
+        "minimum_order_size": 5,
+        "minimum_tick_size": 0.01,
+        "description": "",
+        "category": null,
+        "end_date_iso": null,
+        "game_start_time": null,
+        "market_slug": "m",
+        "icon": "",
+        "fpmm": "",
+        "neg_risk": false,
+        "neg_risk_market_id": "",
+        "neg_risk_request_id": ""
+    }))
+    .unwrap()
+}
+
+/// Client of `server` for a new random wallet, with API credentials issued
+pub(crate) fn server_client(server: &TestServer) -> TradingClient {
+    server_client_for(server, PrivateKeySigner::random())
+}
+
+/// Client of `server` signing with `signer`, with API credentials issued
+pub(crate) fn server_client_for(server: &TestServer, signer: PrivateKeySigner) -> TradingClient {
+    let creds = server.create_api_key(signer.address());
+    let builder = OrderBuilder::new(signer.clone(), None, None);
+    TradingClient::new(server.url(), signer, server.chain_id(), creds, builder)
+}
@@ -0,0 +1,1008 @@
+use futures_util::{Stream, StreamExt};
+use rust_decimal::Decimal;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::sync::broadcast;
+
+use crate::client::TradingClient;
+use crate::error::{Error, Result};
+use crate::types::{
+    CreateOrderOptions, OrderArgs, OrderEvent, OrderId, OrderType, PostOrderArgs, Side,
+    SignedOrderRequest, TradeEvent, TradeStatus, UserWsEvent, WsEvent,
+};
+
+/// Capacity of the group event channel
+const EVENT_CHANNEL_CAPACITY: usize = 256;
+
+/// Identifier of a group within its [`GroupManager`]
+pub type GroupId = u64;
+
+/// Client-side stop of a bracket
+///
+/// Armed once the bracket's entry has filled. When a trade in the entry's
+/// token prints at or through `trigger_price` (at or below it when the entry
+/// was a BUY, at or above it when it was a SELL), the take-profit is cancelled
+/// and an order at `price` is posted for the part of the position it has not
+/// closed. Price the order to fill, typically as a FAK or FOK.
+#[derive(Debug, Clone)]
+pub struct StopLeg {
+    pub trigger_price: Decimal,
+    pub price: Decimal,
+    pub order_type: OrderType,
+}
+
+impl StopLeg {
+    pub fn new(trigger_price: Decimal, price: Decimal, order_type: OrderType) -> Self {
+        Self {
+            trigger_price,
+            price,
+            order_type,
+        }
+    }
+}
+
+/// State of an order group
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupStatus {
+    /// A bracket whose entry has not fully filled yet
+    AwaitingEntry,
+    /// Orders are live and the first to fill cancels the rest, or a
+    /// bracket's legs are placed and its stop is armed
+    Active,
+    /// This order filled and the rest of the group was cancelled. A
+    /// bracket's take-profit only counts once it has filled in full.
+    Filled(OrderId),
+    /// A bracket's stop triggered and its order was posted
+    Stopped,
+    /// Every order was cancelled before one filled
+    Canceled,
+}
+
+impl GroupStatus {
+    /// Whether the group is done and no longer acts on events
+    pub fn is_final(&self) -> bool {
+        matches!(self, Self::Filled(_) | Self::Stopped | Self::Canceled)
+    }
+}
+
+/// Action taken by a [`GroupManager`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupEvent {
+    /// An order filled; `canceled` are the siblings that were cancelled
+    Filled {
+        group: GroupId,
+        order_id: OrderId,
+        canceled: Vec<OrderId>,
+    },
+    /// A bracket's entry filled, in full or in part before it was cancelled,
+    /// and its take-profit was posted for the filled size
+    LegsPlaced {
+        group: GroupId,
+        take_profit: OrderId,
+    },
+    /// A bracket's stop triggered, its take-profit was cancelled and the
+    /// stop order posted
+    StopTriggered { group: GroupId, order_id: OrderId },
+    /// Cancelling or posting an order of the group failed
+    Failed { group: GroupId, message: String },
+}
+
+/// Client-side OCO and bracket order groups
+///
+/// Orders placed through the manager are tracked by group, and the user
+/// stream fed to [`run_stream`](Self::run_stream) decides what happens next:
+///
+/// - [`place_oco`](Self::place_oco): the first order with a fill, even a
+///   partial one, cancels the other orders of the group
+/// - [`place_bracket`](Self::place_bracket): once the entry has filled, the
+///   take-profit is posted and the [`StopLeg`] is armed. The stop stays armed
+///   until the take-profit has filled in full, and only closes what the
+///   take-profit has not; a stop trigger cancels the take-profit. Stops need
+///   last trade prices from [`run_market_stream`](Self::run_market_stream).
+///
+/// Everything the manager does is announced on [`events`](Self::events).
+///
+/// Cancels are requests, not guarantees: siblings can fill before the cancel
+/// lands, and both legs of a group can fill on the same match. Such failures
+/// are reported as [`GroupEvent::Failed`].
+///
+/// Groups are kept once they reach a final state, so their outcome can be
+/// read with [`status`](Self::status). Drop them with
+/// [`remove`](Self::remove) or [`prune`](Self::prune).
+///
+/// Subscribe to the user stream before placing orders, so that no fill is
+/// missed. The manager is cheap to clone; clones share the same groups.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::client::TradingClient;
+/// use polymarket_rs::groups::{GroupEvent, GroupManager};
+/// use polymarket_rs::types::{ApiCreds, PostOrderArgs};
+/// use polymarket_rs::websocket::UserWsClient;
+/// use std::sync::Arc;
+///
+/// # async fn run(
+/// #     client: TradingClient,
+/// #     creds: &ApiCreds,
+/// #     orders: Vec<PostOrderArgs>,
+/// # ) -> Result<(), Box<dyn std::error::Error>> {
+/// let groups = GroupManager::new(Arc::new(client));
+/// let mut events = groups.events();
+///
+/// let user_stream = UserWsClient::new().subscribe_with_creds(creds).await?;
+/// let driver = groups.clone();
+/// tokio::spawn(async move { driver.run_stream(user_stream).await });
+///
+/// groups.place_oco(&orders).await?;
+/// while let Ok(event) = events.recv().await {
+///     if let GroupEvent::Filled { order_id, canceled, .. } = event {
+///         println!("{} filled, cancelled {:?}", order_id, canceled);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct GroupManager {
+    client: Arc<TradingClient>,
+    state: Arc<Mutex<GroupState>>,
+    /// Held while placing a group and while handling an event, so fills of a
+    /// new group's orders are handled only once the group is registered
+    gate: Arc<tokio::sync::Mutex<()>>,
+    events_tx: broadcast::Sender<GroupEvent>,
+}
+
+impl GroupManager {
+    /// Create a manager that trades through `client`
+    pub fn new(client: Arc<TradingClient>) -> Self {
+        let (events_tx, _rx) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        Self {
+            client,
+            state: Arc::new(Mutex::new(GroupState::default())),
+            gate: Arc::new(tokio::sync::Mutex::new(())),
+            events_tx,
+        }
+    }
+
+    /// Receiver of the actions taken on groups
+    pub fn events(&self) -> broadcast::Receiver<GroupEvent> {
+        self.events_tx.subscribe()
+    }
+
+    /// Current state of a group
+    pub fn status(&self, group: GroupId) -> Option<GroupStatus> {
+        self.lock().groups.get(&group).map(|g| g.status.clone())
+    }
+
+    /// Stop tracking a group and return its last state
+    ///
+    /// The orders of a group that is still active stay live, but no longer
+    /// cancel each other.
+    pub fn remove(&self, group: GroupId) -> Option<GroupStatus> {
+        self.lock().groups.remove(&group).map(|g| g.status)
+    }
+
+    /// Stop tracking every group in a final state
+    ///
+    /// Returns the removed groups with their final states.
+    pub fn prune(&self) -> Vec<(GroupId, GroupStatus)> {
+        let mut state = self.lock();
+        let finished: Vec<GroupId> = state
+            .groups
+            .iter()
+            .filter(|(_, g)| g.status.is_final())
+            .map(|(&id, _)| id)
+            .collect();
+        finished
+            .into_iter()
+            .filter_map(|id| state.groups.remove(&id).map(|g| (id, g.status)))
+            .collect()
+    }
+
+    /// Post orders as a one-cancels-other group
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from posting, or [`Error::InvalidOrder`] if any order
+    /// was rejected. Orders that were accepted are cancelled again in that
+    /// case, so no partial group is left behind.
+    pub async fn place_oco(&self, orders: &[PostOrderArgs]) -> Result<GroupId> {
+        let _gate = self.gate.lock().await;
+        let responses = self.client.post_orders(orders).await?;
+
+        let accepted: Vec<OrderId> = responses
+            .iter()
+            .filter(|r| r.success)
+            .map(|r| r.order_id.clone())
+            .collect();
+        if let Some(rejected) = responses.iter().find(|r| !r.success) {
+            if !accepted.is_empty() {
+                self.client.cancel_orders(&accepted).await?;
+            }
+            return Err(Error::InvalidOrder(format!(
+                "OCO order rejected: {}",
+                rejected.error_msg
+            )));
+        }
+
+        Ok(self.lock().insert(GroupStatus::Active, accepted, None))
+    }
+
+    /// Group orders that are already live as one-cancels-other
+    pub fn track_oco(&self, order_ids: Vec<OrderId>) -> GroupId {
+        self.lock().insert(GroupStatus::Active, order_ids, None)
+    }
+
+    /// Post an entry order with a take-profit and a stop to follow its fill
+    ///
+    /// Only `entry` is posted now. Once the entry has filled in full, or is
+    /// cancelled or expires after a partial fill, a GTC take-profit at
+    /// `take_profit_price` is signed and posted for the filled size, on the
+    /// other side of the entry's token, and `stop` is armed. An entry that is
+    /// cancelled before any fill cancels the group.
+    ///
+    /// The legs are signed with `options` and the token's fee rate. Legs
+    /// below the market's minimum order size are rejected by the exchange and
+    /// reported as [`GroupEvent::Failed`].
+    ///
+    /// # Arguments
+    ///
+    /// * `entry` - The signed entry order
+    /// * `take_profit_price` - Limit price of the take-profit
+    /// * `stop` - Trigger and order of the stop
+    /// * `options` - Order options of the legs (tick_size, neg_risk must be
+    ///   provided unless an [`OrderOptionsCache`](crate::client::OrderOptionsCache)
+    ///   is attached to the client)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidOrder`] if the entry cannot be decoded or is
+    /// rejected, [`Error::MissingField`] if `options` are incomplete,
+    /// [`Error::InvalidParameter`] if a leg price is outside (0, 1), or the
+    /// error from posting the entry.
+    pub async fn place_bracket(
+        &self,
+        entry: PostOrderArgs,
+        take_profit_price: Decimal,
+        stop: StopLeg,
+        options: CreateOrderOptions,
+    ) -> Result<GroupId> {
+        let entry_size = entry.order.size()?;
+        let exit_side = entry.order.order_side()?.opposite();
+        let token_id = entry.order.token_id.clone();
+
+        let options = self
+            .client
+            .resolve_order_options(&token_id, options)
+            .await?;
+        if options.tick_size.is_none() {
+            return Err(Error::MissingField("tick_size".to_string()));
+        }
+        if options.neg_risk.is_none() {
+            return Err(Error::MissingField("neg_risk".to_string()));
+        }
+        for price in [take_profit_price, stop.price] {
+            if price <= Decimal::ZERO || price >= Decimal::ONE {
+                return Err(Error::InvalidParameter(format!(
+                    "Bracket leg price {} is outside (0, 1)",
+                    price
+                )));
+            }
+        }
+
+        let _gate = self.gate.lock().await;
+        let response = self
+            .client
+            .post_order(entry.order, entry.order_type)
+            .await?;
+        if !response.success {
+            return Err(Error::InvalidOrder(format!(
+                "Entry order rejected: {}",
+                response.error_msg
+            )));
+        }
+
+        let bracket = Bracket {
+            entry: response.order_id,
+            entry_size,
+            entry_fills: Fills::default(),
+            token_id,
+            exit_side,
+            options,
+            take_profit_price,
+            take_profit_fills: Fills::default(),
+            position: Decimal::ZERO,
+            stop,
+        };
+        Ok(self
+            .lock()
+            .insert(GroupStatus::AwaitingEntry, Vec::new(), Some(bracket)))
+    }
+
+    /// Act on an event from the user stream
+    pub async fn handle_user_event(&self, event: &UserWsEvent) {
+        let _gate = self.gate.lock().await;
+        let actions = self.lock().apply_user_event(event);
+        self.execute(actions).await;
+    }
+
+    /// Act on an event from the market stream, triggering armed stops
+    pub async fn handle_market_event(&self, event: &WsEvent) {
+        let WsEvent::LastTradePrice(trade) = event else {
+            return;
+        };
+        let _gate = self.gate.lock().await;
+        let actions = self.lock().apply_trade_price(&trade.asset_id, trade.price);
+        self.execute(actions).await;
+    }
+
+    /// Drive the groups from a user event stream until it ends
+    ///
+    /// Stream errors are logged and skipped, so a
+    /// [`ReconnectingStream`](crate::websocket::ReconnectingStream) keeps the
+    /// manager running across reconnects.
+    pub async fn run_stream<S>(&self, stream: S)
+    where
+        S: Stream<Item = Result<UserWsEvent>>,
+    {
+        futures_util::pin_mut!(stream);
+        while let Some(event) = stream.next().await {
+            match event {
+                Ok(event) => self.handle_user_event(&event).await,
+                Err(e) => log::warn!("Order group user stream error: {}", e),
+            }
+        }
+    }
+
+    /// Drive the bracket stops from a market event stream until it ends
+    ///
+    /// Subscribe the stream to the tokens of the bracket entries. Stream errors
+    /// are logged and skipped.
+    pub async fn run_market_stream<S>(&self, stream: S)
+    where
+        S: Stream<Item = Result<WsEvent>>,
+    {
+        futures_util::pin_mut!(stream);
+        while let Some(event) = stream.next().await {
+            match event {
+                Ok(event) => self.handle_market_event(&event).await,
+                Err(e) => log::warn!("Order group market stream error: {}", e),
+            }
+        }
+    }
+
+    async fn execute(&self, actions: Vec<Action>) {
+        for action in actions {
+            match action {
+                Action::CancelSiblings {
+                    group,
+                    filled,
+                    siblings,
+                } => {
+                    let canceled = self.cancel(group, &siblings).await;
+                    self.publish(GroupEvent::Filled {
+                        group,
+                        order_id: filled,
+                        canceled,
+                    });
+                }
+                Action::PlaceTakeProfit {
+                    group,
+                    order,
+                    options,
+                } => {
+                    let take_profit = self.post(group, &order, OrderType::Gtc, options).await;
+                    if let Some(take_profit) = take_profit {
+                        if let Some(g) = self.lock().groups.get_mut(&group) {
+                            g.members.push(take_profit.clone());
+                        }
+                        self.publish(GroupEvent::LegsPlaced { group, take_profit });
+                    }
+                }
+                Action::FireStop {
+                    group,
+                    cancel,
+                    order,
+                    order_type,
+                    options,
+                } => {
+                    self.cancel(group, &cancel).await;
+                    if let Some(order_id) = self.post(group, &order, order_type, options).await {
+                        self.publish(GroupEvent::StopTriggered { group, order_id });
+                    }
+                }
+            }
+        }
+    }
+
+    /// Cancel orders of a group and return the ones that were cancelled
+    async fn cancel(&self, group: GroupId, order_ids: &[OrderId]) -> Vec<OrderId> {
+        if order_ids.is_empty() {
+            return Vec::new();
+        }
+        match self.client.cancel_orders(order_ids).await {
+            Ok(response) => {
+                for id in order_ids {
+                    if let Err(e) = response.result_for(id) {
+                        self.fail(group, e);
+                    }
+                }
+                response.canceled
+            }
+            Err(e) => {
+                self.fail(group, e);
+                Vec::new()
+            }
+        }
+    }
+
+    /// Sign and post an order of a group and return its ID if it was accepted
+    async fn post(
+        &self,
+        group: GroupId,
+        order: &OrderArgs,
+        order_type: OrderType,
+        options: CreateOrderOptions,
+    ) -> Option<OrderId> {
+        let signed = match self.sign(order, options).await {
+            Ok(signed) => signed,
+            Err(e) => {
+                self.fail(group, e);
+                return None;
+            }
+        };
+        match self.client.post_order(signed, order_type).await {
+            Ok(response) if response.success => Some(response.order_id),
+            Ok(response) => {
+                let message = format!("Order rejected: {}", response.error_msg);
+                self.fail(group, Error::InvalidOrder(message));
+                None
+            }
+            Err(e) => {
+                self.fail(group, e);
+                None
+            }
+        }
+    }
+
+    /// Sign an order with the fee rate of its token
+    async fn sign(
+        &self,
+        order: &OrderArgs,
+        options: CreateOrderOptions,
+    ) -> Result<SignedOrderRequest> {
+        let extras = self.client.resolve_fee_rate(&order.token_id, None).await?;
+        self.client
+            .create_order_async(order, None, Some(&extras), options)
+            .await
+    }
+
+    fn fail(&self, group: GroupId, error: Error) {
+        log::warn!("Order group {}: {}", group, error);
+        self.publish(GroupEvent::Failed {
+            group,
+            message: error.to_string(),
+        });
+    }
+
+    fn publish(&self, event: GroupEvent) {
+        let _ = self.events_tx.send(event);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, GroupState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// What the manager has to do after an event
+enum Action {
+    CancelSiblings {
+        group: GroupId,
+        filled: OrderId,
+        siblings: Vec<OrderId>,
+    },
+    PlaceTakeProfit {
+        group: GroupId,
+        order: OrderArgs,
+        options: CreateOrderOptions,
+    },
+    FireStop {
+        group: GroupId,
+        cancel: Vec<OrderId>,
+        order: OrderArgs,
+        order_type: OrderType,
+        options: CreateOrderOptions,
+    },
+}
+
+#[derive(Default)]
+struct GroupState {
+    next_id: GroupId,
+    groups: HashMap<GroupId, Group>,
+}
+
+impl GroupState {
+    fn insert(
+        &mut self,
+        status: GroupStatus,
+        members: Vec<OrderId>,
+        bracket: Option<Bracket>,
+    ) -> GroupId {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.groups.insert(
+            id,
+            Group {
+                status,
+                members,
+                bracket,
+            },
+        );
+        id
+    }
+
+    fn apply_user_event(&mut self, event: &UserWsEvent) -> Vec<Action> {
+        self.groups
+            .iter_mut()
+            .filter_map(|(&id, group)| group.apply_user_event(id, event))
+            .collect()
+    }
+
+    fn apply_trade_price(&mut self, token_id: &str, price: Decimal) -> Vec<Action> {
+        let mut actions = Vec::new();
+        for (&id, group) in &mut self.groups {
+            let Some(bracket) = &group.bracket else {
+                continue;
+            };
+            if group.status != GroupStatus::Active || bracket.token_id != token_id {
+                continue;
+            }
+            let triggered = match bracket.exit_side {
+                Side::Sell => price <= bracket.stop.trigger_price,
+                Side::Buy => price >= bracket.stop.trigger_price,
+            };
+            if triggered {
+                group.status = GroupStatus::Stopped;
+                // Close only what the take-profit has not
+                let size = bracket.position - bracket.take_profit_fills.filled();
+                actions.push(Action::FireStop {
+                    group: id,
+                    cancel: std::mem::take(&mut group.members),
+                    order: bracket.exit_order(bracket.stop.price, size),
+                    order_type: bracket.stop.order_type,
+                    options: bracket.options.clone(),
+                });
+            }
+        }
+        actions
+    }
+}
+
+struct Group {
+    status: GroupStatus,
+    /// Live orders that cancel each other; a bracket's take-profit
+    members: Vec<OrderId>,
+    bracket: Option<Bracket>,
+}
+
+impl Group {
+    fn apply_user_event(&mut self, id: GroupId, event: &UserWsEvent) -> Option<Action> {
+        match self.status {
+            GroupStatus::AwaitingEntry => {
+                let bracket = self.bracket.as_mut()?;
+                match bracket.apply_entry_event(event) {
+                    EntryUpdate::Filled(size) => {
+                        self.status = GroupStatus::Active;
+                        bracket.position = size;
+                        Some(Action::PlaceTakeProfit {
+                            group: id,
+                            order: bracket.exit_order(bracket.take_profit_price, size),
+                            options: bracket.options.clone(),
+                        })
+                    }
+                    EntryUpdate::Canceled => {
+                        self.status = GroupStatus::Canceled;
+                        None
+                    }
+                    EntryUpdate::Pending => None,
+                }
+            }
+            GroupStatus::Active if self.bracket.is_some() => {
+                self.apply_take_profit_event(id, event)
+            }
+            GroupStatus::Active => {
+                if let Some(filled) = self.members.iter().find(|m| fills(event, m)).cloned() {
+                    let siblings = self.members.drain(..).filter(|m| *m != filled).collect();
+                    self.status = GroupStatus::Filled(filled.clone());
+                    return Some(Action::CancelSiblings {
+                        group: id,
+                        filled,
+                        siblings,
+                    });
+                }
+                if let UserWsEvent::Order(order) = event {
+                    if order.order_event_type == "CANCELLATION" {
+                        self.members.retain(|m| m.as_str() != order.id);
+                        if self.members.is_empty() {
+                            self.status = GroupStatus::Canceled;
+                        }
+                    }
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Track the fill of a bracket's take-profit, which disarms the stop once
+    /// it is complete
+    fn apply_take_profit_event(&mut self, id: GroupId, event: &UserWsEvent) -> Option<Action> {
+        let bracket = self.bracket.as_mut()?;
+        let take_profit = self.members.first()?.clone();
+        bracket.take_profit_fills.apply(event, &take_profit);
+
+        let mut filled = bracket.take_profit_fills.filled() >= bracket.position;
+        if let UserWsEvent::Order(order) = event {
+            if order.id == take_profit.as_str() {
+                if is_closed(order) && !filled {
+                    // The stop stays armed for what is left of the position
+                    self.members.clear();
+                    return None;
+                }
+                filled |= order.status == "MATCHED";
+            }
+        }
+        if !filled {
+            return None;
+        }
+
+        self.members.clear();
+        self.status = GroupStatus::Filled(take_profit.clone());
+        Some(Action::CancelSiblings {
+            group: id,
+            filled: take_profit,
+            siblings: Vec::new(),
+        })
+    }
+}
+
+struct Bracket {
+    entry: OrderId,
+    entry_size: Decimal,
+    entry_fills: Fills,
+    /// Token and side of the take-profit and stop orders
+    token_id: String,
+    exit_side: Side,
+    options: CreateOrderOptions,
+    take_profit_price: Decimal,
+    take_profit_fills: Fills,
+    /// Filled size of the entry, set when the legs are placed
+    position: Decimal,
+    stop: StopLeg,
+}
+
+enum EntryUpdate {
+    Pending,
+    /// The entry is done with this filled size
+    Filled(Decimal),
+    Canceled,
+}
+
+impl Bracket {
+    fn apply_entry_event(&mut self, event: &UserWsEvent) -> EntryUpdate {
+        self.entry_fills.apply(event, &self.entry);
+        let filled = self.entry_fills.filled();
+
+        if let UserWsEvent::Order(order) = event {
+            if order.id == self.entry.as_str() {
+                // A cancelled or expired entry still brackets what it filled
+                if is_closed(order) {
+                    return if filled > Decimal::ZERO {
+                        EntryUpdate::Filled(filled.min(self.entry_size))
+                    } else {
+                        EntryUpdate::Canceled
+                    };
+                }
+                if order.status == "MATCHED" {
+                    return EntryUpdate::Filled(self.entry_size);
+                }
+            }
+        }
+
+        if filled >= self.entry_size {
+            EntryUpdate::Filled(self.entry_size)
+        } else {
+            EntryUpdate::Pending
+        }
+    }
+
+    /// Order closing `size` of the position at `price`
+    fn exit_order(&self, price: Decimal, size: Decimal) -> OrderArgs {
+        OrderArgs::new(&self.token_id, price, size, self.exit_side)
+    }
+}
+
+/// Cumulative fill of one order
+///
+/// Order events carry the cumulative size and trades the increments, so both
+/// are tracked and whichever is ahead counts.
+#[derive(Default)]
+struct Fills {
+    matched: Decimal,
+    traded: Decimal,
+    /// Trades already counted in `traded`
+    trades: HashSet<String>,
+}
+
+impl Fills {
+    fn apply(&mut self, event: &UserWsEvent, order_id: &OrderId) {
+        match event {
+            UserWsEvent::Trade(trade) if trade.status != TradeStatus::Failed => {
+                let matched = matched_size(trade, order_id);
+                if matched > Decimal::ZERO && self.trades.insert(trade.id.clone()) {
+                    self.traded += matched;
+                }
+            }
+            UserWsEvent::Order(order) if order.id == order_id.as_str() => {
+                self.matched = self.matched.max(order.size_matched);
+            }
+            _ => {}
+        }
+    }
+
+    fn filled(&self) -> Decimal {
+        self.matched.max(self.traded)
+    }
+}
+
+/// Whether an order event reports that the rest of the order was cancelled,
+/// by request, on expiry or as the remainder of a FAK
+fn is_closed(order: &OrderEvent) -> bool {
+    order.order_event_type == "CANCELLATION" || order.status == "CANCELED"
+}
+
+/// Whether `event` reports a fill of `order_id`
+fn fills(event: &UserWsEvent, order_id: &OrderId) -> bool {
+    match event {
+        UserWsEvent::Trade(trade) => {
+            trade.status != TradeStatus::Failed && matched_size(trade, order_id) > Decimal::ZERO
+        }
+        UserWsEvent::Order(order) => {
+            order.id == order_id.as_str() && order.size_matched > Decimal::ZERO
+        }
+    }
+}
+
+/// Size of `order_id` matched in a trade, as taker or maker
+fn matched_size(trade: &TradeEvent, order_id: &OrderId) -> Decimal {
+    if trade.taker_order_id.as_deref() == Some(order_id.as_str()) {
+        return trade.size;
+    }
+    trade
+        .maker_orders
+        .iter()
+        .filter(|m| m.order_id.as_deref() == Some(order_id.as_str()))
+        .map(|m| m.matched_amount)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{paper, trade};
+    use crate::orders::OrderBuilder;
+    use crate::paper::PaperTrading;
+    use crate::types::{ApiCreds, CreateOrderOptions, OrderArgs, OrderType};
+    use alloy_signer_local::PrivateKeySigner;
+    use rust_decimal_macros::dec;
+
+    fn setup() -> (PaperTrading, Arc<TradingClient>) {
+        let paper = paper();
+        let signer = PrivateKeySigner::random();
+        let creds = ApiCreds::new("key".into(), "secret".into(), "pass".into());
+        let builder = OrderBuilder::new(signer.clone(), None, None);
+        let client = TradingClient::new("http://localhost", signer, 137, creds, builder)
+            .with_paper_trading(paper.clone());
+        (paper, Arc::new(client))
+    }
+
+    fn options() -> CreateOrderOptions {
+        CreateOrderOptions::default()
+            .tick_size(dec!(0.01))
+            .neg_risk(false)
+    }
+
+    fn order(
+        client: &TradingClient,
+        side: Side,
+        price: Decimal,
+        size: Decimal,
+        order_type: OrderType,
+    ) -> PostOrderArgs {
+        let args = OrderArgs::new("1", price, size, side);
+        let signed = client.create_order(&args, None, None, options()).unwrap();
+        PostOrderArgs::new(signed, order_type)
+    }
+
+    /// Feed the manager every pending user event, including those its own
+    /// orders produce
+    async fn drain(groups: &GroupManager, events: &mut broadcast::Receiver<UserWsEvent>) {
+        while let Ok(event) = events.try_recv() {
+            groups.handle_user_event(&event).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_oco_fill_cancels_siblings() {
+        let (paper, client) = setup();
+        let groups = GroupManager::new(client.clone());
+        let mut user_events = paper.user_events();
+        let mut group_events = groups.events();
+
+        let orders = [
+            order(&client, Side::Buy, dec!(0.50), dec!(10), OrderType::Gtc),
+            order(&client, Side::Buy, dec!(0.45), dec!(10), OrderType::Gtc),
+        ];
+        let group = groups.place_oco(&orders).await.unwrap();
+        drain(&groups, &mut user_events).await;
+        assert_eq!(groups.status(group), Some(GroupStatus::Active));
+
+        // A partial fill of the first order cancels the second
+        paper.apply_event(&trade(Side::Sell, dec!(0.50), dec!(4)));
+        drain(&groups, &mut user_events).await;
+
+        let GroupEvent::Filled {
+            order_id, canceled, ..
+        } = group_events.try_recv().unwrap()
+        else {
+            panic!("expected a fill");
+        };
+        assert_eq!(
+            groups.status(group),
+            Some(GroupStatus::Filled(order_id.clone()))
+        );
+        assert_eq!(canceled.len(), 1);
+        assert_ne!(canceled[0], order_id);
+        assert_eq!(
+            client.get_order(&canceled[0]).await.unwrap().status,
+            "CANCELED"
+        );
+        assert_eq!(client.get_order(&order_id).await.unwrap().status, "LIVE");
+    }
+
+    #[tokio::test]
+    async fn test_bracket_places_legs_and_stops_out() {
+        let (paper, client) = setup();
+        let groups = GroupManager::new(client.clone());
+        let mut user_events = paper.user_events();
+        let mut group_events = groups.events();
+
+        let entry = order(&client, Side::Buy, dec!(0.50), dec!(10), OrderType::Gtc);
+        let stop = StopLeg::new(dec!(0.45), dec!(0.44), OrderType::Fak);
+        let group = groups
+            .place_bracket(entry, dec!(0.60), stop, options())
+            .await
+            .unwrap();
+        drain(&groups, &mut user_events).await;
+        assert_eq!(groups.status(group), Some(GroupStatus::AwaitingEntry));
+
+        // Armed stops do not fire before the entry fills
+        groups
+            .handle_market_event(&trade(Side::Sell, dec!(0.40), dec!(1)))
+            .await;
+        paper.apply_event(&trade(Side::Sell, dec!(0.50), dec!(6)));
+        drain(&groups, &mut user_events).await;
+        assert_eq!(groups.status(group), Some(GroupStatus::AwaitingEntry));
+
+        paper.apply_event(&trade(Side::Sell, dec!(0.50), dec!(4)));
+        drain(&groups, &mut user_events).await;
+        let GroupEvent::LegsPlaced { take_profit, .. } = group_events.try_recv().unwrap() else {
+            panic!("expected the legs to be placed");
+        };
+        assert_eq!(groups.status(group), Some(GroupStatus::Active));
+        assert_eq!(paper.position("1"), dec!(10));
+
+        groups
+            .handle_market_event(&trade(Side::Sell, dec!(0.46), dec!(1)))
+            .await;
+        assert_eq!(groups.status(group), Some(GroupStatus::Active));
+        groups
+            .handle_market_event(&trade(Side::Sell, dec!(0.45), dec!(1)))
+            .await;
+        drain(&groups, &mut user_events).await;
+
+        assert!(matches!(
+            group_events.try_recv().unwrap(),
+            GroupEvent::StopTriggered { .. }
+        ));
+        assert_eq!(groups.status(group), Some(GroupStatus::Stopped));
+        assert_eq!(
+            client.get_order(&take_profit).await.unwrap().status,
+            "CANCELED"
+        );
+        assert_eq!(paper.position("1"), Decimal::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_partial_entry_gets_legs_for_filled_size() {
+        let (paper, client) = setup();
+        let groups = GroupManager::new(client.clone());
+        let mut user_events = paper.user_events();
+        let mut group_events = groups.events();
+
+        // Only 10 of the 15 are offered, and the FAK remainder is cancelled
+        let entry = order(&client, Side::Buy, dec!(0.52), dec!(15), OrderType::Fak);
+        let stop = StopLeg::new(dec!(0.45), dec!(0.44), OrderType::Fak);
+        let group = groups
+            .place_bracket(entry, dec!(0.60), stop, options())
+            .await
+            .unwrap();
+        drain(&groups, &mut user_events).await;
+
+        let GroupEvent::LegsPlaced { take_profit, .. } = group_events.try_recv().unwrap() else {
+            panic!("expected the legs to be placed");
+        };
+        assert_eq!(groups.status(group), Some(GroupStatus::Active));
+        let take_profit = client.get_order(&take_profit).await.unwrap();
+        assert_eq!(take_profit.side, Side::Sell);
+        assert_eq!(take_profit.original_size, dec!(10));
+
+        // An entry cancelled before any fill cancels the group
+        let entry = order(&client, Side::Buy, dec!(0.40), dec!(10), OrderType::Gtc);
+        let stop = StopLeg::new(dec!(0.35), dec!(0.34), OrderType::Fak);
+        let other = groups
+            .place_bracket(entry, dec!(0.60), stop, options())
+            .await
+            .unwrap();
+        drain(&groups, &mut user_events).await;
+        client.cancel_all().await.unwrap();
+        drain(&groups, &mut user_events).await;
+        assert_eq!(groups.status(other), Some(GroupStatus::Canceled));
+    }
+
+    #[tokio::test]
+    async fn test_partial_take_profit_keeps_stop_armed() {
+        let (paper, client) = setup();
+        let groups = GroupManager::new(client.clone());
+        let mut user_events = paper.user_events();
+        let mut group_events = groups.events();
+
+        let entry = order(&client, Side::Buy, dec!(0.50), dec!(10), OrderType::Gtc);
+        let stop = StopLeg::new(dec!(0.45), dec!(0.44), OrderType::Fak);
+        let group = groups
+            .place_bracket(entry, dec!(0.60), stop, options())
+            .await
+            .unwrap();
+        paper.apply_event(&trade(Side::Sell, dec!(0.50), dec!(10)));
+        drain(&groups, &mut user_events).await;
+        assert!(matches!(
+            group_events.try_recv().unwrap(),
+            GroupEvent::LegsPlaced { .. }
+        ));
+
+        paper.apply_event(&trade(Side::Buy, dec!(0.60), dec!(4)));
+        drain(&groups, &mut user_events).await;
+        assert_eq!(groups.status(group), Some(GroupStatus::Active));
+        assert_eq!(paper.position("1"), dec!(6));
+
+        // The stop closes what the take-profit has not
+        groups
+            .handle_market_event(&trade(Side::Sell, dec!(0.45), dec!(1)))
+            .await;
+        drain(&groups, &mut user_events).await;
+        let GroupEvent::StopTriggered { order_id, .. } = group_events.try_recv().unwrap() else {
+            panic!("expected the stop to trigger");
+        };
+        assert_eq!(
+            client.get_order(&order_id).await.unwrap().original_size,
+            dec!(6)
+        );
+        assert_eq!(groups.status(group), Some(GroupStatus::Stopped));
+        assert_eq!(paper.position("1"), Decimal::ZERO);
+
+        assert_eq!(groups.prune(), vec![(group, GroupStatus::Stopped)]);
+        assert_eq!(groups.status(group), None);
+        assert!(groups.prune().is_empty());
+    }
+}
@@ -0,0 +1,19 @@
+//! Client-side order groups driven by the user WebSocket stream.
+//!
+//! The CLOB has no native one-cancels-other or bracket orders, so
+//! [`GroupManager`] emulates them:
+//!
+//! - **OCO**: when any order of the group fills, the others are cancelled
+//!   with [`TradingClient::cancel_orders`](crate::client::TradingClient::cancel_orders)
+//! - **Bracket**: once an entry order has filled, or is cancelled after a
+//!   partial fill, a take-profit order is posted for the filled size and a
+//!   client-side stop is armed. The stop stays armed until the take-profit has
+//!   filled in full, and a stop trigger cancels the take-profit.
+//!
+//! Fills are detected from the [`TradeEvent`](crate::types::TradeEvent)s and
+//! [`OrderEvent`](crate::types::OrderEvent)s of the user stream. Stops watch
+//! last trade prices from the market stream.
+
+mod manager;
+
+pub use manager::{GroupEvent, GroupId, GroupManager, GroupStatus, StopLeg};
@@ -1,5 +1,5 @@
 use crate::error::Result;
-use crate::signing::{sign_clob_auth_message, EthSigner};
+use crate::signing::{sign_clob_auth_message_async, AsyncEthSigner};
 use crate::types::ApiCreds;
 use crate::utils::{build_hmac_signature, get_current_unix_time_secs};
 use alloy_primitives::hex::encode_prefixed;
@@ -19,15 +19,17 @@ pub type Headers = HashMap<&'static str, String>;
 /// Create L1 headers for authentication (EIP-712 based)
 ///
 /// These headers are used for operations that require wallet signature,
-/// such as creating API keys.
-pub fn create_l1_headers<S: EthSigner>(
+/// such as creating API keys. Signing goes through the async
+/// [`Signer`](alloy_signer::Signer) interface, so remote signers work too.
+pub async fn create_l1_headers<S: AsyncEthSigner + ?Sized>(
     signer: &S,
     chain_id: u64,
     nonce: Option<U256>,
 ) -> Result<Headers> {
     let timestamp = get_current_unix_time_secs()?.to_string();
     let nonce = nonce.unwrap_or(U256::ZERO);
-    let signature = sign_clob_auth_message(signer, timestamp.clone(), nonce, chain_id)?;
+    let signature =
+        sign_clob_auth_message_async(signer, timestamp.clone(), nonce, chain_id).await?;
     let address = encode_prefixed(signer.address().as_slice());
 
     Ok(HashMap::from([
@@ -42,7 +44,7 @@ pub fn create_l1_headers<S: EthSigner>(
 ///
 /// These headers are used for API operations that require API credentials,
 /// such as creating orders, querying private data, etc.
-pub fn create_l2_headers<S: EthSigner, T>(
+pub fn create_l2_headers<S: AsyncEthSigner + ?Sized, T>(
     signer: &S,
     api_creds: &ApiCreds,
     method: &str,
@@ -8,27 +8,46 @@
 //! - Account and balance operations (authenticated)
 //! - Position tracking
 //! - Real-time WebSocket streaming for market data and user events
+//! - Local order books maintained from the market stream
+//! - OHLCV candles from live trades and price history
+//! - Order book microstructure analytics
+//! - Recording and replay of WebSocket sessions
+//! - Backtesting strategies against recorded market data
+//! - Paper trading against live markets
+//! - Client-side OCO and bracket order groups
+//! - An in-process stand-in CLOB server for integration tests (`test-server` feature)
 //!
 //! ## Features
 //!
 //! - **Builder Pattern**: Fluent API for constructing clients and orders
 //! - **Type Safety**: Strong typing with newtypes for IDs (TokenId, OrderId, ConditionId)
 //! - **Proper Error Handling**: No panics, comprehensive error types
-//! - **EIP-712 Signing**: Full support for Ethereum wallet signatures
+//! - **EIP-712 Signing**: Full support for Ethereum wallet signatures, local or remote
 //! - **Decimal Precision**: Accurate decimal math for prices and amounts
 //!
 
 // Public modules
+pub mod analytics;
+pub mod backtest;
+pub mod book;
+pub mod candles;
 pub mod client;
 pub mod config;
 pub mod error;
+pub mod groups;
 pub mod orders;
+pub mod paper;
+pub mod recording;
 pub mod request;
 pub mod signing;
+#[cfg(any(test, feature = "test-server"))]
+pub mod testing;
 pub mod types;
 pub mod websocket;
 
 // Internal modules
+#[cfg(test)]
+pub(crate) mod fixtures;
 mod http;
 mod utils;
 
@@ -39,7 +58,7 @@ pub use alloy_signer_local::PrivateKeySigner;
 pub use error::{Error, Result};
 pub use types::{
     ApiCreds, AssetType, ConditionId, CreateOrderOptions, ExtraOrderArgs, MarketOrderArgs,
-    OrderArgs, OrderId, OrderType, PostOrderArgs, Side, SignatureType, TokenId,
+    OrderArgs, OrderId, OrderType, PostOrderArgs, Side, SignatureType, SlippageAction, TokenId,
 };
 
 // Re-export clients
@@ -48,11 +67,14 @@ pub use client::{AuthenticatedClient, ClobClient, DataClient, GammaClient, Tradi
 // Re-export websocket clients
 pub use websocket::{MarketWsClient, UserWsClient};
 
+// Re-export order book engine
+pub use book::{BookManager, LocalOrderBook};
+
 // Re-export order builder
 pub use orders::OrderBuilder;
 
 // Re-export signer trait
-pub use signing::EthSigner;
+pub use signing::{AsyncEthSigner, EthSigner};
 
 // Re-export stream extension traits
 pub use futures_util::StreamExt;
@@ -2,17 +2,28 @@ use super::rounding::{decimal_to_token_u64, fix_amount_rounding, ROUNDING_CONFIG
 use crate::config::get_contract_config;
 use crate::error::{Error, Result};
 use crate::orders::RoundConfig;
-use crate::signing::{sign_order_message, EthSigner, Order};
+use crate::signing::{
+    order_domain_separator, order_signing_hash, order_signing_hash_with_separator,
+    sign_order_message, sign_order_message_async, AsyncEthSigner, EthSigner, Order,
+};
 use crate::types::{
-    CreateOrderOptions, ExtraOrderArgs, MarketOrderArgs, OrderArgs, Side, SignatureType,
+    CreateOrderOptions, ExtraOrderArgs, MarketOrderArgs, OrderArgs, OrderId, Side, SignatureType,
     SignedOrderRequest,
 };
 use crate::utils::get_current_unix_time_secs;
-use alloy_primitives::{Address, U256};
+use alloy_primitives::hex::encode_prefixed;
+use alloy_primitives::{Address, B256, U256};
+use futures_util::stream::{self, StreamExt};
 use rand::{thread_rng, Rng};
 use rust_decimal::Decimal;
 use rust_decimal::RoundingStrategy::ToZero;
+use std::collections::HashMap;
 use std::str::FromStr;
+use std::sync::Arc;
+
+/// Default number of signatures [`OrderBuilder::create_orders`] requests at
+/// once from an asynchronous signer
+pub const DEFAULT_SIGNING_CONCURRENCY: usize = 16;
 
 /// Generate a random seed for order salt
 fn generate_seed() -> Result<u64> {
@@ -23,11 +34,117 @@ fn generate_seed() -> Result<u64> {
     Ok(a as u64)
 }
 
+/// Signer of an [`OrderBuilder`]
+enum OrderSigner {
+    Sync(Arc<dyn EthSigner>),
+    Async(Box<dyn AsyncEthSigner>),
+}
+
+impl OrderSigner {
+    fn address(&self) -> Address {
+        match self {
+            OrderSigner::Sync(signer) => signer.address(),
+            OrderSigner::Async(signer) => signer.address(),
+        }
+    }
+}
+
+/// An order that has been built but not signed yet
+struct UnsignedOrder {
+    order: Order,
+    /// The request to post, with an empty signature
+    request: SignedOrderRequest,
+    chain_id: u64,
+    exchange: Address,
+}
+
+impl UnsignedOrder {
+    fn hash(&self) -> OrderId {
+        OrderId::new(order_signing_hash(&self.order, self.chain_id, self.exchange).to_string())
+    }
+
+    /// Hash using the precomputed separator of the order's exchange
+    fn hash_with(&self, separators: &DomainSeparators) -> B256 {
+        let separator = separators
+            .get(&(self.chain_id, self.exchange))
+            .copied()
+            .unwrap_or_else(|| order_domain_separator(self.chain_id, self.exchange));
+        order_signing_hash_with_separator(&self.order, &separator)
+    }
+}
+
+/// EIP-712 domain separators by chain and exchange
+type DomainSeparators = HashMap<(u64, Address), B256>;
+
+/// Compute the domain separator of each exchange in a batch once
+fn domain_separators(orders: &[Result<UnsignedOrder>]) -> DomainSeparators {
+    let mut separators = DomainSeparators::new();
+    for order in orders.iter().flatten() {
+        separators
+            .entry((order.chain_id, order.exchange))
+            .or_insert_with(|| order_domain_separator(order.chain_id, order.exchange));
+    }
+    separators
+}
+
+/// Sign a batch on scoped threads, keeping the input order
+fn sign_batch(
+    signer: &dyn EthSigner,
+    orders: Vec<Result<UnsignedOrder>>,
+) -> Vec<Result<(SignedOrderRequest, OrderId)>> {
+    let separators = domain_separators(&orders);
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk_size = orders.len().div_ceil(threads).max(1);
+
+    let mut chunks = Vec::new();
+    let mut orders = orders.into_iter();
+    loop {
+        let chunk: Vec<_> = orders.by_ref().take(chunk_size).collect();
+        if chunk.is_empty() {
+            break;
+        }
+        chunks.push(chunk);
+    }
+
+    let separators = &separators;
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = chunks
+            .into_iter()
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .into_iter()
+                        .map(|order| {
+                            let order = order?;
+                            let hash = order.hash_with(separators);
+                            let signature = signer.sign_hash_sync(&hash).map_err(|e| {
+                                Error::Signing(format!("Failed to sign order: {}", e))
+                            })?;
+                            let mut request = order.request;
+                            request.signature = encode_prefixed(signature.as_bytes());
+                            Ok((request, OrderId::new(hash.to_string())))
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|e| std::panic::resume_unwind(e))
+            })
+            .collect()
+    })
+}
+
 /// Builder for creating and signing orders
 pub struct OrderBuilder {
-    signer: Box<dyn EthSigner>,
+    signer: OrderSigner,
     sig_type: SignatureType,
     funder: Address,
+    signing_concurrency: usize,
 }
 
 impl OrderBuilder {
@@ -47,12 +164,73 @@ impl OrderBuilder {
         let funder = funder.unwrap_or(signer_addr);
 
         Self {
-            signer: Box::new(signer),
+            signer: OrderSigner::Sync(Arc::new(signer)),
             sig_type,
             funder,
+            signing_concurrency: DEFAULT_SIGNING_CONCURRENCY,
         }
     }
 
+    /// Create an OrderBuilder for a signer that can only sign asynchronously
+    ///
+    /// Use this for remote signers such as
+    /// [`RemoteSigner`](crate::signing::RemoteSigner), so the private key never
+    /// has to be loaded into the trading process. Orders are then created with
+    /// [`create_order_async`](Self::create_order_async) and
+    /// [`create_market_order_async`](Self::create_market_order_async); the
+    /// synchronous methods return [`Error::Signing`].
+    ///
+    /// # Arguments
+    /// * `signer` - The signer to use for signing orders
+    /// * `sig_type` - The signature type (defaults to EOA if None)
+    /// * `funder` - The address funding the order (defaults to signer address if None)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polymarket_rs::orders::OrderBuilder;
+    /// use polymarket_rs::signing::RemoteSigner;
+    /// use polymarket_rs::types::{CreateOrderOptions, ExtraOrderArgs, OrderArgs, Side};
+    /// use polymarket_rs::Address;
+    /// use rust_decimal_macros::dec;
+    ///
+    /// # async fn example(address: Address) -> polymarket_rs::Result<()> {
+    /// let signer = RemoteSigner::new("http://127.0.0.1:9000", address);
+    /// let builder = OrderBuilder::new_async(signer, None, None);
+    ///
+    /// let args = OrderArgs::new("1234", dec!(0.45), dec!(10), Side::Buy);
+    /// let options = CreateOrderOptions::default().tick_size(dec!(0.01)).neg_risk(false);
+    /// let order = builder
+    ///     .create_order_async(137, &args, 0, &ExtraOrderArgs::default(), options)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_async(
+        signer: impl AsyncEthSigner + 'static,
+        sig_type: Option<SignatureType>,
+        funder: Option<Address>,
+    ) -> Self {
+        let sig_type = sig_type.unwrap_or(SignatureType::Eoa);
+        let funder = funder.unwrap_or(signer.address());
+
+        Self {
+            signer: OrderSigner::Async(Box::new(signer)),
+            sig_type,
+            funder,
+            signing_concurrency: DEFAULT_SIGNING_CONCURRENCY,
+        }
+    }
+
+    /// Set how many signatures [`create_orders`](Self::create_orders)
+    /// requests at once from an asynchronous signer
+    ///
+    /// Defaults to [`DEFAULT_SIGNING_CONCURRENCY`]; 0 is treated as 1.
+    pub fn with_signing_concurrency(mut self, concurrency: usize) -> Self {
+        self.signing_concurrency = concurrency.max(1);
+        self
+    }
+
     /// Get the signature type as u8
     pub fn get_sig_type(&self) -> u8 {
         self.sig_type.to_u8()
@@ -120,6 +298,12 @@ impl OrderBuilder {
     /// Create a market order
     ///
     /// Market orders are executed at the best available price by walking the order book.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Signing`] if the builder was created with
+    /// [`new_async`](Self::new_async); use
+    /// [`create_market_order_async`](Self::create_market_order_async) instead.
     pub fn create_market_order(
         &self,
         chain_id: u64,
@@ -128,6 +312,250 @@ impl OrderBuilder {
         extras: &ExtraOrderArgs,
         options: CreateOrderOptions,
     ) -> Result<SignedOrderRequest> {
+        self.create_market_order_with_hash(chain_id, order_args, price, extras, options)
+            .map(|(order, _)| order)
+    }
+
+    /// Create a market order along with its order hash
+    ///
+    /// Same as [`create_market_order`](Self::create_market_order), but also
+    /// returns the EIP-712 order hash, which is the ID the exchange will give
+    /// the order. With an asynchronous signer this fails like
+    /// [`create_market_order`](Self::create_market_order); use
+    /// [`create_market_order_with_hash_async`](Self::create_market_order_with_hash_async).
+    pub fn create_market_order_with_hash(
+        &self,
+        chain_id: u64,
+        order_args: &MarketOrderArgs,
+        price: Decimal,
+        extras: &ExtraOrderArgs,
+        options: CreateOrderOptions,
+    ) -> Result<(SignedOrderRequest, OrderId)> {
+        let order = self.build_market_order(chain_id, order_args, price, extras, options)?;
+        self.sign(order)
+    }
+
+    /// Create a market order, signing it asynchronously
+    ///
+    /// Same as [`create_market_order`](Self::create_market_order), but works
+    /// with signers that can only sign asynchronously.
+    pub async fn create_market_order_async(
+        &self,
+        chain_id: u64,
+        order_args: &MarketOrderArgs,
+        price: Decimal,
+        extras: &ExtraOrderArgs,
+        options: CreateOrderOptions,
+    ) -> Result<SignedOrderRequest> {
+        self.create_market_order_with_hash_async(chain_id, order_args, price, extras, options)
+            .await
+            .map(|(order, _)| order)
+    }
+
+    /// Create a market order along with its order hash, signing it asynchronously
+    ///
+    /// Same as [`create_market_order_with_hash`](Self::create_market_order_with_hash),
+    /// but works with signers that can only sign asynchronously.
+    pub async fn create_market_order_with_hash_async(
+        &self,
+        chain_id: u64,
+        order_args: &MarketOrderArgs,
+        price: Decimal,
+        extras: &ExtraOrderArgs,
+        options: CreateOrderOptions,
+    ) -> Result<(SignedOrderRequest, OrderId)> {
+        let order = self.build_market_order(chain_id, order_args, price, extras, options)?;
+        self.sign_async(order).await
+    }
+
+    /// Create a limit order
+    ///
+    /// Limit orders are executed at a specific price or better.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Signing`] if the builder was created with
+    /// [`new_async`](Self::new_async), whose signer cannot sign synchronously;
+    /// use [`create_order_async`](Self::create_order_async) instead.
+    pub fn create_order(
+        &self,
+        chain_id: u64,
+        order_args: &OrderArgs,
+        expiration: u64,
+        extras: &ExtraOrderArgs,
+        options: CreateOrderOptions,
+    ) -> Result<SignedOrderRequest> {
+        self.create_order_with_hash(chain_id, order_args, expiration, extras, options)
+            .map(|(order, _)| order)
+    }
+
+    /// Create a limit order along with its order hash
+    ///
+    /// Same as [`create_order`](Self::create_order), but also returns the
+    /// EIP-712 order hash, which is the ID the exchange will give the order.
+    /// Knowing it before posting lets a retried post be checked with
+    /// `get_order` instead of signing a duplicate. Needs a synchronous signer,
+    /// see [`create_order_with_hash_async`](Self::create_order_with_hash_async).
+    pub fn create_order_with_hash(
+        &self,
+        chain_id: u64,
+        order_args: &OrderArgs,
+        expiration: u64,
+        extras: &ExtraOrderArgs,
+        options: CreateOrderOptions,
+    ) -> Result<(SignedOrderRequest, OrderId)> {
+        let order = self.build_limit_order(chain_id, order_args, expiration, extras, options)?;
+        self.sign(order)
+    }
+
+    /// Create a limit order, signing it asynchronously
+    ///
+    /// Same as [`create_order`](Self::create_order), but works with signers
+    /// that can only sign asynchronously.
+    pub async fn create_order_async(
+        &self,
+        chain_id: u64,
+        order_args: &OrderArgs,
+        expiration: u64,
+        extras: &ExtraOrderArgs,
+        options: CreateOrderOptions,
+    ) -> Result<SignedOrderRequest> {
+        self.create_order_with_hash_async(chain_id, order_args, expiration, extras, options)
+            .await
+            .map(|(order, _)| order)
+    }
+
+    /// Create a limit order along with its order hash, signing it asynchronously
+    ///
+    /// Same as [`create_order_with_hash`](Self::create_order_with_hash), but
+    /// works with signers that can only sign asynchronously.
+    pub async fn create_order_with_hash_async(
+        &self,
+        chain_id: u64,
+        order_args: &OrderArgs,
+        expiration: u64,
+        extras: &ExtraOrderArgs,
+        options: CreateOrderOptions,
+    ) -> Result<(SignedOrderRequest, OrderId)> {
+        let order = self.build_limit_order(chain_id, order_args, expiration, extras, options)?;
+        self.sign_async(order).await
+    }
+
+    /// Create and sign many limit orders in parallel
+    ///
+    /// With a synchronous signer, orders are signed on a blocking thread
+    /// split across the available cores, so the async runtime is not held up
+    /// by a large quoting refresh. With an asynchronous signer, up to
+    /// [`with_signing_concurrency`](Self::with_signing_concurrency) signatures
+    /// are requested at once. Each exchange's EIP-712 domain separator is
+    /// computed once for the whole batch.
+    ///
+    /// Results are in the same order as `orders`, each with the order hash,
+    /// and an order that can't be built or signed doesn't affect the others.
+    ///
+    /// # Arguments
+    /// * `chain_id` - The chain ID
+    /// * `orders` - Each order with its options (tick_size, neg_risk must be provided)
+    /// * `expiration` - Expiration timestamp for every order (0 = no expiration)
+    /// * `extras` - Extra order parameters for every order
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polymarket_rs::orders::OrderBuilder;
+    /// use polymarket_rs::types::{CreateOrderOptions, ExtraOrderArgs, OrderArgs, Side};
+    /// use rust_decimal::Decimal;
+    /// use rust_decimal_macros::dec;
+    ///
+    /// # async fn example(builder: &OrderBuilder) {
+    /// let options = CreateOrderOptions::default().tick_size(dec!(0.01)).neg_risk(false);
+    /// let orders: Vec<_> = (1..=99)
+    ///     .map(|i| {
+    ///         let args = OrderArgs::new("1234", Decimal::new(i, 2), dec!(10), Side::Buy);
+    ///         (args, options.clone())
+    ///     })
+    ///     .collect();
+    ///
+    /// for result in builder.create_orders(137, &orders, 0, &ExtraOrderArgs::default()).await {
+    ///     match result {
+    ///         Ok((order, hash)) => println!("{} at {}", hash, order.maker_amount),
+    ///         Err(e) => eprintln!("{}", e),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn create_orders(
+        &self,
+        chain_id: u64,
+        orders: &[(OrderArgs, CreateOrderOptions)],
+        expiration: u64,
+        extras: &ExtraOrderArgs,
+    ) -> Vec<Result<(SignedOrderRequest, OrderId)>> {
+        let extras = orders.iter().map(|_| Ok(extras.clone())).collect();
+        self.create_orders_with_extras(chain_id, orders, expiration, extras)
+            .await
+    }
+
+    /// [`create_orders`](Self::create_orders) with extras per order
+    ///
+    /// `extras[i]` goes with `orders[i]`; an `Err` is returned as that
+    /// order's result without building it.
+    pub(crate) async fn create_orders_with_extras(
+        &self,
+        chain_id: u64,
+        orders: &[(OrderArgs, CreateOrderOptions)],
+        expiration: u64,
+        extras: Vec<Result<ExtraOrderArgs>>,
+    ) -> Vec<Result<(SignedOrderRequest, OrderId)>> {
+        let unsigned: Vec<Result<UnsignedOrder>> = orders
+            .iter()
+            .zip(extras)
+            .map(|((args, options), extras)| {
+                self.build_limit_order(chain_id, args, expiration, &extras?, options.clone())
+            })
+            .collect();
+
+        match &self.signer {
+            OrderSigner::Sync(signer) => {
+                let count = unsigned.len();
+                let signer = signer.clone();
+                tokio::task::spawn_blocking(move || sign_batch(signer.as_ref(), unsigned))
+                    .await
+                    .unwrap_or_else(|e| {
+                        (0..count)
+                            .map(|_| Err(Error::Signing(format!("Signing task failed: {}", e))))
+                            .collect()
+                    })
+            }
+            OrderSigner::Async(signer) => {
+                let separators = domain_separators(&unsigned);
+                stream::iter(unsigned.into_iter().map(|order| async {
+                    let order = order?;
+                    let hash = order.hash_with(&separators);
+                    let signature = signer
+                        .sign_hash(&hash)
+                        .await
+                        .map_err(|e| Error::Signing(format!("Failed to sign order: {}", e)))?;
+                    let mut request = order.request;
+                    request.signature = encode_prefixed(signature.as_bytes());
+                    Ok((request, OrderId::new(hash.to_string())))
+                }))
+                .buffered(self.signing_concurrency)
+                .collect()
+                .await
+            }
+        }
+    }
+
+    /// Build an unsigned market order
+    fn build_market_order(
+        &self,
+        chain_id: u64,
+        order_args: &MarketOrderArgs,
+        price: Decimal,
+        extras: &ExtraOrderArgs,
+        options: CreateOrderOptions,
+    ) -> Result<UnsignedOrder> {
         let tick_size = options
             .tick_size
             .ok_or_else(|| Error::MissingField("tick_size".to_string()))?;
@@ -148,7 +576,7 @@ impl OrderBuilder {
         let exchange_address = Address::from_str(&contract_config.exchange)
             .map_err(|e| Error::Config(format!("Invalid exchange address: {}", e)))?;
 
-        self.build_signed_order(
+        self.build_order(
             order_args.token_id.clone(),
             order_args.side,
             chain_id,
@@ -160,17 +588,15 @@ impl OrderBuilder {
         )
     }
 
-    /// Create a limit order
-    ///
-    /// Limit orders are executed at a specific price or better.
-    pub fn create_order(
+    /// Build an unsigned limit order
+    fn build_limit_order(
         &self,
         chain_id: u64,
         order_args: &OrderArgs,
         expiration: u64,
         extras: &ExtraOrderArgs,
         options: CreateOrderOptions,
-    ) -> Result<SignedOrderRequest> {
+    ) -> Result<UnsignedOrder> {
         let tick_size = options
             .tick_size
             .ok_or_else(|| Error::MissingField("tick_size".to_string()))?;
@@ -195,7 +621,7 @@ impl OrderBuilder {
         let exchange_address = Address::from_str(&contract_config.exchange)
             .map_err(|e| Error::Config(format!("Invalid exchange address: {}", e)))?;
 
-        self.build_signed_order(
+        self.build_order(
             order_args.token_id.clone(),
             order_args.side,
             chain_id,
@@ -207,9 +633,9 @@ impl OrderBuilder {
         )
     }
 
-    /// Build and sign an order
+    /// Build an unsigned order
     #[allow(clippy::too_many_arguments)]
-    fn build_signed_order(
+    fn build_order(
         &self,
         token_id: String,
         side: Side,
@@ -219,7 +645,7 @@ impl OrderBuilder {
         taker_amount: u64,
         expiration: u64,
         extras: &ExtraOrderArgs,
-    ) -> Result<SignedOrderRequest> {
+    ) -> Result<UnsignedOrder> {
         let seed = generate_seed()?;
         let taker_address = Address::from_str(&extras.taker)
             .map_err(|e| Error::InvalidParameter(format!("Invalid taker address: {}", e)))?;
@@ -242,9 +668,7 @@ impl OrderBuilder {
             signatureType: self.sig_type.to_u8(),
         };
 
-        let signature = sign_order_message(&self.signer, order, chain_id, exchange)?;
-
-        Ok(SignedOrderRequest {
+        let request = SignedOrderRequest {
             salt: seed,
             maker: self.funder.to_checksum(None),
             signer: self.signer.address().to_checksum(None),
@@ -257,15 +681,69 @@ impl OrderBuilder {
             fee_rate_bps: extras.fee_rate_bps.to_string(),
             side: side.as_str().to_string(),
             signature_type: self.sig_type.to_u8(),
-            signature,
+            signature: String::new(),
+        };
+
+        Ok(UnsignedOrder {
+            order,
+            request,
+            chain_id,
+            exchange,
         })
     }
+
+    /// Sign an order, returning it with its hash
+    fn sign(&self, unsigned: UnsignedOrder) -> Result<(SignedOrderRequest, OrderId)> {
+        let OrderSigner::Sync(signer) = &self.signer else {
+            return Err(Error::Signing(
+                "Signer can only sign asynchronously; use the async order methods".to_string(),
+            ));
+        };
+        let hash = unsigned.hash();
+        let mut request = unsigned.request;
+        request.signature = sign_order_message(
+            signer.as_ref(),
+            unsigned.order,
+            unsigned.chain_id,
+            unsigned.exchange,
+        )?;
+        Ok((request, hash))
+    }
+
+    /// Sign an order with either kind of signer, returning it with its hash
+    async fn sign_async(&self, unsigned: UnsignedOrder) -> Result<(SignedOrderRequest, OrderId)> {
+        let hash = unsigned.hash();
+        let mut request = unsigned.request;
+        request.signature = match &self.signer {
+            OrderSigner::Sync(signer) => {
+                sign_order_message_async(
+                    signer.as_ref(),
+                    unsigned.order,
+                    unsigned.chain_id,
+                    unsigned.exchange,
+                )
+                .await?
+            }
+            OrderSigner::Async(signer) => {
+                sign_order_message_async(
+                    signer.as_ref(),
+                    unsigned.order,
+                    unsigned.chain_id,
+                    unsigned.exchange,
+                )
+                .await?
+            }
+        };
+        Ok((request, hash))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use alloy_signer_local::PrivateKeySigner;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering::SeqCst;
 
     #[test]
     fn test_generate_seed() {
@@ -302,4 +780,161 @@ mod tests {
         assert_eq!(maker_amount, 30_000_000);
         assert_eq!(taker_amount, 27_000_000);
     }
+
+    #[test]
+    fn test_create_order_with_hash() {
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None);
+        let args = OrderArgs::new("1234", Decimal::new(45, 2), Decimal::from(10), Side::Buy);
+        let options = CreateOrderOptions::default()
+            .tick_size(Decimal::new(1, 2))
+            .neg_risk(true);
+
+        let (order, hash) = builder
+            .create_order_with_hash(137, &args, 0, &ExtraOrderArgs::default(), options)
+            .unwrap();
+        assert_eq!(order.order_hash(137, true).unwrap(), hash);
+    }
+
+    #[tokio::test]
+    async fn test_create_order_with_hash_async() {
+        let builder = OrderBuilder::new_async(PrivateKeySigner::random(), None, None);
+        let args = OrderArgs::new("1234", Decimal::new(45, 2), Decimal::from(10), Side::Buy);
+        let options = CreateOrderOptions::default()
+            .tick_size(Decimal::new(1, 2))
+            .neg_risk(false);
+
+        // The synchronous methods can't use an async-only signer
+        assert!(matches!(
+            builder.create_order_with_hash(
+                137,
+                &args,
+                0,
+                &ExtraOrderArgs::default(),
+                options.clone()
+            ),
+            Err(Error::Signing(_))
+        ));
+
+        let (order, hash) = builder
+            .create_order_with_hash_async(
+                137,
+                &args,
+                0,
+                &ExtraOrderArgs::default(),
+                options.clone(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(order.order_hash(137, false).unwrap(), hash);
+        crate::signing::verify_order_signature(&order, 137, false).unwrap();
+
+        let market = MarketOrderArgs::new("1234", Decimal::from(10), Side::Buy);
+        let (order, hash) = builder
+            .create_market_order_with_hash_async(
+                137,
+                &market,
+                Decimal::new(5, 1),
+                &ExtraOrderArgs::default(),
+                options,
+            )
+            .await
+            .unwrap();
+        assert_eq!(order.order_hash(137, false).unwrap(), hash);
+    }
+
+    #[tokio::test]
+    async fn test_create_orders_in_input_order() {
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None);
+        let standard = CreateOrderOptions::default()
+            .tick_size(Decimal::new(1, 2))
+            .neg_risk(false);
+        let orders: Vec<_> = (1..=40)
+            .map(|i| {
+                let args = OrderArgs::new("1234", Decimal::new(i, 2), Decimal::from(10), Side::Buy);
+                // Mix exchanges, and make one order invalid
+                let options = match i {
+                    7 => CreateOrderOptions::default(),
+                    _ => standard.clone().neg_risk(i % 2 == 0),
+                };
+                (args, options)
+            })
+            .collect();
+
+        let results = builder
+            .create_orders(137, &orders, 0, &ExtraOrderArgs::default())
+            .await;
+        assert_eq!(results.len(), orders.len());
+        for (i, result) in results.iter().enumerate() {
+            let price = Decimal::new(i as i64 + 1, 2);
+            if i + 1 == 7 {
+                assert!(matches!(result, Err(Error::MissingField(_))));
+                continue;
+            }
+            let (order, hash) = result.as_ref().unwrap();
+            assert_eq!(order.price().unwrap(), price);
+            let neg_risk = (i + 1) % 2 == 0;
+            assert_eq!(&order.order_hash(137, neg_risk).unwrap(), hash);
+            crate::signing::verify_order_signature(order, 137, neg_risk).unwrap();
+        }
+    }
+
+    /// Signer that records how many signatures it is asked for at once
+    struct CountingSigner {
+        inner: PrivateKeySigner,
+        in_flight: AtomicUsize,
+        max_in_flight: Arc<AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl alloy_signer::Signer for CountingSigner {
+        async fn sign_hash(
+            &self,
+            hash: &B256,
+        ) -> alloy_signer::Result<alloy_primitives::PrimitiveSignature> {
+            let in_flight = self.in_flight.fetch_add(1, SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+            self.in_flight.fetch_sub(1, SeqCst);
+            alloy_signer::SignerSync::sign_hash_sync(&self.inner, hash)
+        }
+
+        fn address(&self) -> Address {
+            self.inner.address()
+        }
+
+        fn chain_id(&self) -> Option<alloy_primitives::ChainId> {
+            None
+        }
+
+        fn set_chain_id(&mut self, _chain_id: Option<alloy_primitives::ChainId>) {}
+    }
+
+    #[tokio::test]
+    async fn test_create_orders_bounds_async_signing() {
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+        let signer = CountingSigner {
+            inner: PrivateKeySigner::random(),
+            in_flight: AtomicUsize::new(0),
+            max_in_flight: max_in_flight.clone(),
+        };
+        let builder = OrderBuilder::new_async(signer, None, None).with_signing_concurrency(4);
+        let options = CreateOrderOptions::default()
+            .tick_size(Decimal::new(1, 2))
+            .neg_risk(false);
+        let orders: Vec<_> = (1..=40)
+            .map(|i| {
+                let args = OrderArgs::new("1234", Decimal::new(i, 2), Decimal::from(10), Side::Buy);
+                (args, options.clone())
+            })
+            .collect();
+
+        let results = builder
+            .create_orders(137, &orders, 0, &ExtraOrderArgs::default())
+            .await;
+        assert_eq!(max_in_flight.load(SeqCst), 4);
+        for (i, result) in results.iter().enumerate() {
+            let (order, _) = result.as_ref().unwrap();
+            assert_eq!(order.price().unwrap(), Decimal::new(i as i64 + 1, 2));
+        }
+    }
 }
@@ -0,0 +1,146 @@
+use rust_decimal::Decimal;
+use rust_decimal::RoundingStrategy::ToZero;
+
+use crate::error::{Error, Result};
+use crate::types::Side;
+
+/// Basis points per unit
+const BPS: u32 = 10_000;
+
+/// Decimal places of USDC and outcome token amounts
+const AMOUNT_DECIMALS: u32 = 6;
+
+/// Taker fee and net result of a proposed order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TakerFee {
+    /// Fee in the asset the taker receives: shares for a BUY, USDC for a SELL
+    pub fee: Decimal,
+    /// Fee valued in USDC at the order price
+    pub fee_usdc: Decimal,
+    /// `price * size`: USDC paid for a BUY, USDC received before fees for a SELL
+    pub notional: Decimal,
+    /// What the taker ends up with: shares for a BUY, USDC for a SELL
+    pub net_proceeds: Decimal,
+    /// USDC per share after fees: the cost of each share kept for a BUY, or
+    /// the USDC kept per share sold for a SELL
+    pub effective_price: Decimal,
+}
+
+/// Calculate the Polymarket taker fee of a proposed order
+///
+/// The fee is `fee_rate_bps / 10000 * min(price, 1 - price) * size` in USDC,
+/// so it is largest at a price of 0.5 and vanishes toward 0 and 1. It is
+/// charged in the asset the taker receives: a BUY receives `fee_usdc / price`
+/// fewer shares, and a SELL receives `fee_usdc` less USDC. Fees are rounded
+/// down to the 6 decimals of the token amounts, like on the exchange.
+///
+/// # Arguments
+///
+/// * `side` - Side of the taker order
+/// * `price` - Price per share the order fills at
+/// * `size` - Size in shares
+/// * `fee_rate_bps` - The market's fee rate, from
+///   [`ClobClient::get_fee_rate`](crate::client::ClobClient::get_fee_rate)
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidParameter`] if the price is not inside (0, 1) or
+/// the size is negative.
+///
+/// # Example
+///
+/// ```
+/// use polymarket_rs::orders::taker_fee;
+/// use polymarket_rs::types::Side;
+/// use rust_decimal_macros::dec;
+///
+/// let fee = taker_fee(Side::Sell, dec!(0.8), dec!(100), 200).unwrap();
+/// assert_eq!(fee.fee, dec!(0.4));
+/// assert_eq!(fee.net_proceeds, dec!(79.6));
+/// ```
+pub fn taker_fee(side: Side, price: Decimal, size: Decimal, fee_rate_bps: u32) -> Result<TakerFee> {
+    if price <= Decimal::ZERO || price >= Decimal::ONE {
+        return Err(Error::InvalidParameter(format!(
+            "Price {} is outside (0, 1)",
+            price
+        )));
+    }
+    if size.is_sign_negative() {
+        return Err(Error::InvalidParameter(format!(
+            "Size {} is negative",
+            size
+        )));
+    }
+
+    let rate = Decimal::from(fee_rate_bps) / Decimal::from(BPS);
+    let notional = price * size;
+    let fee_usdc = rate * price.min(Decimal::ONE - price) * size;
+
+    let (fee, net_proceeds) = match side {
+        Side::Buy => {
+            let fee = (fee_usdc / price).round_dp_with_strategy(AMOUNT_DECIMALS, ToZero);
+            (fee, size - fee)
+        }
+        Side::Sell => {
+            let fee = fee_usdc.round_dp_with_strategy(AMOUNT_DECIMALS, ToZero);
+            (fee, notional - fee)
+        }
+    };
+    let effective_price = match side {
+        Side::Buy if net_proceeds.is_zero() => price,
+        Side::Buy => notional / net_proceeds,
+        Side::Sell if size.is_zero() => price,
+        Side::Sell => net_proceeds / size,
+    };
+
+    Ok(TakerFee {
+        fee,
+        fee_usdc: match side {
+            Side::Buy => fee * price,
+            Side::Sell => fee,
+        },
+        notional,
+        net_proceeds,
+        effective_price,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_buy_fee_is_charged_in_shares() {
+        // 2% of min(0.25, 0.75) * 100 = 0.5 USDC, or 2 shares at 0.25
+        let fee = taker_fee(Side::Buy, dec!(0.25), dec!(100), 200).unwrap();
+        assert_eq!(fee.fee, dec!(2));
+        assert_eq!(fee.fee_usdc, dec!(0.5));
+        assert_eq!(fee.notional, dec!(25));
+        assert_eq!(fee.net_proceeds, dec!(98));
+        assert_eq!(fee.effective_price.round_dp(6), dec!(0.255102));
+    }
+
+    #[test]
+    fn test_fee_is_symmetric_around_half() {
+        let low = taker_fee(Side::Sell, dec!(0.3), dec!(50), 100).unwrap();
+        let high = taker_fee(Side::Sell, dec!(0.7), dec!(50), 100).unwrap();
+        assert_eq!(low.fee, high.fee);
+        assert_eq!(low.fee, dec!(0.15));
+        assert_eq!(high.net_proceeds, dec!(34.85));
+
+        let peak = taker_fee(Side::Sell, dec!(0.5), dec!(50), 100).unwrap();
+        assert!(peak.fee > low.fee);
+    }
+
+    #[test]
+    fn test_zero_rate_and_invalid_input() {
+        let fee = taker_fee(Side::Buy, dec!(0.4), dec!(10), 0).unwrap();
+        assert_eq!(fee.fee, Decimal::ZERO);
+        assert_eq!(fee.net_proceeds, dec!(10));
+        assert_eq!(fee.effective_price, dec!(0.4));
+
+        assert!(taker_fee(Side::Buy, dec!(1), dec!(10), 100).is_err());
+        assert!(taker_fee(Side::Sell, dec!(0.5), dec!(-1), 100).is_err());
+    }
+}
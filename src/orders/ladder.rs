@@ -0,0 +1,307 @@
+use super::rounding::ROUNDING_CONFIG;
+use crate::error::{Error, Result};
+use crate::types::{LadderArgs, LadderSizing, Market, OrderArgs, Side, SizeDistribution};
+use rust_decimal::Decimal;
+use rust_decimal::RoundingStrategy::ToZero;
+
+/// Build the limit orders of a ladder
+///
+/// Prices are spaced evenly from `args.start_price` to `args.end_price` and
+/// snapped to `market.minimum_tick_size` away from the spread: down for a BUY,
+/// up for a SELL, so no level is more aggressive than requested. Levels that
+/// snap to the same price are merged. Sizes are rounded down to the size
+/// precision of the tick, and levels left below `market.minimum_order_size`
+/// are dropped, so a [`LadderSizing::TotalNotional`] ladder can come out
+/// slightly under its target.
+///
+/// Orders are returned from the start price to the end price. Sign them
+/// with [`TradingClient::create_ladder`](crate::client::TradingClient::create_ladder)
+/// to get [`PostOrderArgs`](crate::types::PostOrderArgs).
+///
+/// # Arguments
+///
+/// * `args` - The ladder to build
+/// * `market` - The market of `args.token_id`, for its tick and minimum size
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidParameter`] if `args.token_id` is not one of the
+/// market's tokens, there are no levels, a snapped end price is outside
+/// (0, 1), the size or a weight is not positive, a level's size overflows,
+/// or the market's tick size is not supported.
+///
+/// # Example
+///
+/// ```no_run
+/// # use polymarket_rs::client::ClobClient;
+/// # use polymarket_rs::orders::ladder_orders;
+/// # use polymarket_rs::types::{LadderArgs, LadderSizing, Side, SizeDistribution};
+/// # use rust_decimal_macros::dec;
+/// # async fn example(clob: ClobClient) -> polymarket_rs::Result<()> {
+/// let market = clob.get_market(&"0xabc...".into()).await?;
+/// // 500 USDC of bids from 0.45 down to 0.40, twice as heavy at the bottom
+/// let args = LadderArgs::new(
+///     &market.tokens[0].token_id,
+///     Side::Buy,
+///     dec!(0.45),
+///     dec!(0.40),
+///     6,
+///     LadderSizing::TotalNotional(dec!(500)),
+/// )
+/// .distribution(SizeDistribution::Linear { last_weight: dec!(2) });
+/// let orders = ladder_orders(&args, &market)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn ladder_orders(args: &LadderArgs, market: &Market) -> Result<Vec<OrderArgs>> {
+    if !market.tokens.iter().any(|t| t.token_id == args.token_id) {
+        return Err(Error::InvalidParameter(format!(
+            "Token {} is not in market {}",
+            args.token_id, market.condition_id
+        )));
+    }
+    let tick_size = market.minimum_tick_size;
+    let round_config = ROUNDING_CONFIG
+        .get(&tick_size)
+        .ok_or_else(|| Error::InvalidParameter(format!("Invalid tick_size: {}", tick_size)))?;
+    if args.levels == 0 {
+        return Err(Error::InvalidParameter(
+            "A ladder needs at least one level".to_string(),
+        ));
+    }
+
+    let snap = |price: Decimal| {
+        let ticks = price / tick_size;
+        let ticks = match args.side {
+            Side::Buy => ticks.floor(),
+            Side::Sell => ticks.ceil(),
+        };
+        ticks * tick_size
+    };
+    for price in [args.start_price, args.end_price] {
+        let snapped = snap(price);
+        if snapped <= Decimal::ZERO || snapped >= Decimal::ONE {
+            return Err(Error::InvalidParameter(format!(
+                "Ladder price {} snaps to {}, outside (0, 1)",
+                price, snapped
+            )));
+        }
+    }
+
+    let (LadderSizing::TotalNotional(size) | LadderSizing::PerLevel(size)) = args.sizing;
+    if size <= Decimal::ZERO {
+        return Err(Error::InvalidParameter(format!(
+            "Ladder size {} is not positive",
+            size
+        )));
+    }
+
+    let weights = weights(args.distribution, args.levels)?;
+    let steps = Decimal::from(args.levels.saturating_sub(1).max(1));
+    let step = (args.end_price - args.start_price) / steps;
+    let mut levels: Vec<(Decimal, Decimal)> = Vec::with_capacity(args.levels);
+    let overflow = || {
+        Error::InvalidParameter(format!(
+            "Ladder size {} overflows over {} levels",
+            size, args.levels
+        ))
+    };
+    let total_weight = weights
+        .iter()
+        .try_fold(Decimal::ZERO, |total, weight| total.checked_add(*weight))
+        .ok_or_else(overflow)?;
+
+    for (i, weight) in weights.iter().enumerate() {
+        let price = snap(args.start_price + step * Decimal::from(i));
+        let size = match args.sizing {
+            LadderSizing::TotalNotional(notional) => notional
+                .checked_mul(*weight)
+                .and_then(|notional| notional.checked_div(total_weight))
+                .and_then(|notional| notional.checked_div(price)),
+            LadderSizing::PerLevel(size) => size.checked_mul(*weight),
+        }
+        .ok_or_else(overflow)?;
+        match levels.last_mut() {
+            Some((last, merged)) if *last == price => {
+                *merged = merged.checked_add(size).ok_or_else(overflow)?
+            }
+            _ => levels.push((price, size)),
+        }
+    }
+
+    Ok(levels
+        .into_iter()
+        .map(|(price, size)| {
+            (
+                price,
+                size.round_dp_with_strategy(round_config.size, ToZero),
+            )
+        })
+        .filter(|(_, size)| *size > Decimal::ZERO && *size >= market.minimum_order_size)
+        .map(|(price, size)| OrderArgs::new(&args.token_id, price.normalize(), size, args.side))
+        .collect())
+}
+
+/// Weight of each level, from the start price
+fn weights(distribution: SizeDistribution, levels: usize) -> Result<Vec<Decimal>> {
+    let weights: Vec<Decimal> = match distribution {
+        SizeDistribution::Linear { last_weight } => {
+            let steps = Decimal::from(levels.saturating_sub(1).max(1));
+            (0..levels)
+                .map(|i| Decimal::ONE + (last_weight - Decimal::ONE) * Decimal::from(i) / steps)
+                .collect()
+        }
+        SizeDistribution::Geometric { ratio } => {
+            let mut weights = vec![Decimal::ONE];
+            while weights.len() < levels {
+                let weight = weights[weights.len() - 1]
+                    .checked_mul(ratio)
+                    .ok_or_else(|| {
+                        Error::InvalidParameter(format!(
+                            "Geometric ratio {} overflows over {} levels",
+                            ratio, levels
+                        ))
+                    })?;
+                weights.push(weight);
+            }
+            weights
+        }
+    };
+
+    match weights.iter().find(|w| **w <= Decimal::ZERO) {
+        Some(weight) => Err(Error::InvalidParameter(format!(
+            "Ladder weight {} is not positive",
+            weight
+        ))),
+        None => Ok(weights),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::market;
+    use rust_decimal_macros::dec;
+
+    fn levels(orders: &[OrderArgs]) -> Vec<(Decimal, Decimal)> {
+        orders.iter().map(|o| (o.price, o.size)).collect()
+    }
+
+    #[test]
+    fn test_total_notional_is_split_evenly() {
+        let args = LadderArgs::new(
+            "1",
+            Side::Buy,
+            dec!(0.45),
+            dec!(0.40),
+            6,
+            LadderSizing::TotalNotional(dec!(300)),
+        );
+        let orders = ladder_orders(&args, &market()).unwrap();
+        assert_eq!(
+            levels(&orders),
+            vec![
+                (dec!(0.45), dec!(111.11)),
+                (dec!(0.44), dec!(113.63)),
+                (dec!(0.43), dec!(116.27)),
+                (dec!(0.42), dec!(119.04)),
+                (dec!(0.41), dec!(121.95)),
+                (dec!(0.40), dec!(125)),
+            ]
+        );
+        assert!(orders
+            .iter()
+            .all(|o| o.side == Side::Buy && o.token_id == "1"));
+    }
+
+    #[test]
+    fn test_geometric_sell_snaps_up() {
+        let args = LadderArgs::new(
+            "1",
+            Side::Sell,
+            dec!(0.501),
+            dec!(0.55),
+            3,
+            LadderSizing::PerLevel(dec!(10)),
+        )
+        .distribution(SizeDistribution::Geometric { ratio: dec!(2) });
+        let orders = ladder_orders(&args, &market()).unwrap();
+        assert_eq!(
+            levels(&orders),
+            vec![
+                (dec!(0.51), dec!(10)),
+                (dec!(0.53), dec!(20)),
+                (dec!(0.55), dec!(40)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merges_and_drops_levels() {
+        // All three levels snap down to 0.40 and are merged
+        let args = LadderArgs::new(
+            "1",
+            Side::Buy,
+            dec!(0.405),
+            dec!(0.40),
+            3,
+            LadderSizing::PerLevel(dec!(2)),
+        );
+        let orders = ladder_orders(&args, &market()).unwrap();
+        assert_eq!(levels(&orders), vec![(dec!(0.40), dec!(6))]);
+
+        // Sizes 2 and 4 are below the minimum of 5
+        let args = LadderArgs::new(
+            "1",
+            Side::Buy,
+            dec!(0.50),
+            dec!(0.48),
+            3,
+            LadderSizing::PerLevel(dec!(2)),
+        )
+        .distribution(SizeDistribution::Linear {
+            last_weight: dec!(3),
+        });
+        let orders = ladder_orders(&args, &market()).unwrap();
+        assert_eq!(levels(&orders), vec![(dec!(0.48), dec!(6))]);
+    }
+
+    #[test]
+    fn test_rejects_invalid_ladders() {
+        let ladder = |end, levels, size| {
+            let sizing = LadderSizing::PerLevel(size);
+            let args = LadderArgs::new("1", Side::Buy, dec!(0.5), end, levels, sizing);
+            ladder_orders(&args, &market())
+        };
+        assert!(ladder(dec!(0.4), 3, dec!(10)).is_ok());
+        assert!(ladder(dec!(0.4), 0, dec!(10)).is_err());
+        assert!(ladder(dec!(0.005), 3, dec!(10)).is_err());
+        assert!(ladder(dec!(0.4), 3, dec!(-10)).is_err());
+
+        let args = LadderArgs::new(
+            "1",
+            Side::Buy,
+            dec!(0.5),
+            dec!(0.4),
+            3,
+            LadderSizing::PerLevel(dec!(10)),
+        )
+        .distribution(SizeDistribution::Linear {
+            last_weight: dec!(-1),
+        });
+        assert!(ladder_orders(&args, &market()).is_err());
+
+        // Weights that fit can still overflow once scaled by the size
+        for sizing in [
+            LadderSizing::PerLevel(dec!(1000)),
+            LadderSizing::TotalNotional(dec!(1000)),
+        ] {
+            let args = LadderArgs::new("1", Side::Buy, dec!(0.5), dec!(0.4), 27, sizing)
+                .distribution(SizeDistribution::Geometric { ratio: dec!(10) });
+            assert!(matches!(
+                ladder_orders(&args, &market()),
+                Err(Error::InvalidParameter(_))
+            ));
+        }
+    }
+}
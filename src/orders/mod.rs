@@ -1,7 +1,16 @@
 mod builder;
+mod fees;
+mod ladder;
 mod price;
 mod rounding;
+mod validation;
 
-pub use builder::OrderBuilder;
-pub use price::calculate_market_price;
+pub use builder::{OrderBuilder, DEFAULT_SIGNING_CONCURRENCY};
+pub use fees::{taker_fee, TakerFee};
+pub use ladder::ladder_orders;
+pub use price::{
+    calculate_market_price, market_order_price_cap, walk_book_notional, walk_book_shares,
+    walk_market_order, walk_market_order_protected, BookWalk, LevelFill,
+};
 pub use rounding::{decimal_to_token_u64, fix_amount_rounding, RoundConfig, ROUNDING_CONFIG};
+pub use validation::{validate_order, CollateralState, RuleViolation, ValidationError};
@@ -1,12 +1,245 @@
 use crate::error::{Error, Result};
-use crate::types::PriceLevel;
+use crate::types::{MarketOrderArgs, PriceLevel, SlippageAction};
 use crate::Side;
 use rust_decimal::Decimal;
 
+/// Liquidity taken from a single price level while walking the book
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelFill {
+    /// Price of the level
+    pub price: Decimal,
+    /// Shares taken from the level
+    pub size: Decimal,
+    /// Notional paid or received for those shares (`price * size`)
+    pub cost: Decimal,
+}
+
+/// Result of walking the book for a market order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BookWalk {
+    /// Per-level breakdown, best price first
+    pub fills: Vec<LevelFill>,
+    /// Total shares filled
+    pub size: Decimal,
+    /// Total notional paid or received
+    pub cost: Decimal,
+    /// Volume-weighted average price (`cost / size`)
+    pub average_price: Decimal,
+    /// Price of the last (worst) level touched
+    pub worst_price: Decimal,
+}
+
+impl BookWalk {
+    fn push(&mut self, price: Decimal, size: Decimal, cost: Decimal) {
+        self.fills.push(LevelFill { price, size, cost });
+        self.size += size;
+        self.cost += cost;
+        self.worst_price = price;
+    }
+
+    fn finish(mut self) -> Self {
+        if !self.size.is_zero() {
+            self.average_price = self.cost / self.size;
+        }
+        self
+    }
+}
+
+/// Sort levels in the order a taker on `side` consumes them
+///
+/// Buys walk the asks from lowest to highest, sells walk the bids from highest
+/// to lowest.
+fn sorted_levels(positions: &[PriceLevel], side: Side) -> Vec<PriceLevel> {
+    let mut levels = positions.to_vec();
+    match side {
+        Side::Buy => levels.sort_by_key(|l| l.price),
+        Side::Sell => levels.sort_by_key(|l| std::cmp::Reverse(l.price)),
+    }
+    levels
+}
+
+/// Walk the book until a number of shares has been filled
+///
+/// # Arguments
+/// * `positions` - The order book levels to take from (asks for a buy, bids for a sell)
+/// * `shares` - The number of shares to fill
+/// * `side` - The taker side
+///
+/// # Returns
+/// The per-level fill breakdown, or an error if there's insufficient liquidity
+pub fn walk_book_shares(positions: &[PriceLevel], shares: Decimal, side: Side) -> Result<BookWalk> {
+    let mut walk = BookWalk::default();
+    let mut remaining = shares;
+
+    for p in sorted_levels(positions, side) {
+        if remaining.is_zero() {
+            break;
+        }
+        let filled = remaining.min(p.size);
+        walk.push(p.price, filled, filled * p.price);
+        remaining -= filled;
+    }
+
+    if !remaining.is_zero() || shares <= Decimal::ZERO {
+        return Err(Error::InvalidOrder(format!(
+            "Not enough liquidity to create market order with amount {}",
+            shares
+        )));
+    }
+    Ok(walk.finish())
+}
+
+/// Walk the book until a notional (USDC) amount has been spent or received
+///
+/// The last level may be partially filled; its share size is the remaining
+/// notional divided by the level price.
+///
+/// # Arguments
+/// * `positions` - The order book levels to take from (asks for a buy, bids for a sell)
+/// * `notional` - The USDC amount to fill
+/// * `side` - The taker side
+///
+/// # Returns
+/// The per-level fill breakdown, or an error if there's insufficient liquidity
+pub fn walk_book_notional(
+    positions: &[PriceLevel],
+    notional: Decimal,
+    side: Side,
+) -> Result<BookWalk> {
+    let mut walk = BookWalk::default();
+    let mut remaining = notional;
+
+    for p in sorted_levels(positions, side) {
+        if remaining.is_zero() {
+            break;
+        }
+        if p.price.is_zero() {
+            continue;
+        }
+        let level_cost = p.size * p.price;
+        if level_cost <= remaining {
+            walk.push(p.price, p.size, level_cost);
+            remaining -= level_cost;
+        } else {
+            walk.push(p.price, remaining / p.price, remaining);
+            remaining = Decimal::ZERO;
+        }
+    }
+
+    if !remaining.is_zero() || notional <= Decimal::ZERO {
+        return Err(Error::InvalidOrder(format!(
+            "Not enough liquidity to create market order with amount {}",
+            notional
+        )));
+    }
+    Ok(walk.finish())
+}
+
+/// Walk the book for a market order amount
+///
+/// Follows the `MarketOrderArgs.amount` convention: a BUY amount is the USDC
+/// notional to spend, a SELL amount is the number of shares to sell.
+pub fn walk_market_order(
+    positions: &[PriceLevel],
+    amount: Decimal,
+    side: Side,
+) -> Result<BookWalk> {
+    match side {
+        Side::Buy => walk_book_notional(positions, amount, side),
+        Side::Sell => walk_book_shares(positions, amount, side),
+    }
+}
+
+/// Worst acceptable fill price for a market order, if it has any price protection
+///
+/// Combines `limit_price` with `max_slippage` measured from the best price on
+/// the side being taken, and returns the tighter of the two, clamped to the
+/// price range [0, 1].
+///
+/// # Errors
+/// Returns [`Error::InvalidParameter`] if `max_slippage` is outside [0, 1).
+pub fn market_order_price_cap(
+    positions: &[PriceLevel],
+    args: &MarketOrderArgs,
+) -> Result<Option<Decimal>> {
+    if let Some(slippage) = args.max_slippage {
+        if slippage.is_sign_negative() || slippage >= Decimal::ONE {
+            return Err(Error::InvalidParameter(format!(
+                "max_slippage {} is outside [0, 1)",
+                slippage
+            )));
+        }
+    }
+
+    let slippage_cap = args.max_slippage.and_then(|slippage| {
+        let best = sorted_levels(positions, args.side).first()?.price;
+        Some(match args.side {
+            Side::Buy => best * (Decimal::ONE + slippage),
+            Side::Sell => best * (Decimal::ONE - slippage),
+        })
+    });
+
+    let cap = match (slippage_cap, args.limit_price) {
+        (Some(a), Some(b)) => Some(match args.side {
+            Side::Buy => a.min(b),
+            Side::Sell => a.max(b),
+        }),
+        (a, b) => a.or(b),
+    };
+    Ok(cap.map(|cap| cap.clamp(Decimal::ZERO, Decimal::ONE)))
+}
+
+/// Walk the book for a market order, enforcing its price protection
+///
+/// Levels priced beyond the cap from [`market_order_price_cap`] are never
+/// taken. If the amount cannot be filled within the cap, the order is either
+/// rejected or truncated to the available liquidity, depending on
+/// `args.on_slippage`. A truncated walk fills less than `args.amount`; use
+/// `walk.cost` (BUY) or `walk.size` (SELL) as the new amount.
+///
+/// # Errors
+/// Returns [`Error::InvalidParameter`] if `args.max_slippage` is invalid, or
+/// [`Error::InvalidOrder`] if the book has no liquidity within the cap,
+/// or if the amount cannot be filled within the cap and the action is
+/// [`SlippageAction::Reject`].
+pub fn walk_market_order_protected(
+    positions: &[PriceLevel],
+    args: &MarketOrderArgs,
+) -> Result<BookWalk> {
+    let Some(cap) = market_order_price_cap(positions, args)? else {
+        return walk_market_order(positions, args.amount, args.side);
+    };
+
+    let within_cap: Vec<PriceLevel> = positions
+        .iter()
+        .filter(|l| match args.side {
+            Side::Buy => l.price <= cap,
+            Side::Sell => l.price >= cap,
+        })
+        .cloned()
+        .collect();
+
+    match walk_market_order(&within_cap, args.amount, args.side) {
+        Ok(walk) => Ok(walk),
+        Err(_) if args.on_slippage == SlippageAction::Truncate && !within_cap.is_empty() => {
+            let available = match args.side {
+                Side::Buy => within_cap.iter().map(|l| l.price * l.size).sum(),
+                Side::Sell => within_cap.iter().map(|l| l.size).sum(),
+            };
+            walk_market_order(&within_cap, available, args.side)
+        }
+        Err(_) => Err(Error::InvalidOrder(format!(
+            "Market order amount {} cannot be filled within price cap {}",
+            args.amount, cap
+        ))),
+    }
+}
+
 /// Calculate the weighted average price for a market order based on order book depth
 ///
 /// This walks the order book until enough liquidity is found to match
 /// the requested shares, calculating the volume-weighted average price.
+/// Use [`walk_market_order`] for the USDC-notional amount of a market BUY.
 ///
 /// # Arguments
 /// * `positions` - The order book positions to walk through
@@ -33,38 +266,7 @@ pub fn calculate_market_price(
     shares_to_match: Decimal,
     side: Side,
 ) -> Result<Decimal> {
-    let mut remaining = shares_to_match;
-    let mut total_cost = Decimal::ZERO;
-
-    // If buying, walk the asks (lowest to highest)
-    // If selling, walk the bids (highest to lowest)
-    let positions = match side {
-        Side::Buy => {
-            let mut asks = positions.to_vec();
-            asks.sort_by(|a, b| a.price.cmp(&b.price));
-            asks
-        }
-        Side::Sell => {
-            let mut bids = positions.to_vec();
-            bids.sort_by(|a, b| b.price.cmp(&a.price));
-            bids
-        }
-    };
-
-    for p in positions {
-        let filled = remaining.min(p.size);
-        total_cost += filled * p.price;
-        remaining -= filled;
-
-        if remaining.is_zero() {
-            return Ok(total_cost / shares_to_match); // weighted avg price
-        }
-    }
-
-    Err(Error::InvalidOrder(format!(
-        "Not enough liquidity to create market order with amount {}",
-        shares_to_match
-    )))
+    walk_book_shares(positions, shares_to_match, side).map(|walk| walk.average_price)
 }
 
 #[cfg(test)]
@@ -109,4 +311,115 @@ mod tests {
         let result = calculate_market_price(&positions, dec!(20), Side::Buy);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_walk_notional_buy() {
+        // 10 shares @ 0.50 (cost 5), 20 shares @ 0.55 (cost 11)
+        let positions = vec![order(dec!(0.55), dec!(20)), order(dec!(0.50), dec!(10))];
+
+        // Spend 10.5 USDC: 5 at 0.50 for 10 shares, 5.5 at 0.55 for 10 shares
+        let walk = walk_book_notional(&positions, dec!(10.5), Side::Buy).unwrap();
+        assert_eq!(walk.fills.len(), 2);
+        assert_eq!(
+            walk.fills[1],
+            LevelFill {
+                price: dec!(0.55),
+                size: dec!(10),
+                cost: dec!(5.5)
+            }
+        );
+        assert_eq!(walk.size, dec!(20));
+        assert_eq!(walk.cost, dec!(10.5));
+        assert_eq!(walk.average_price, dec!(0.525));
+        assert_eq!(walk.worst_price, dec!(0.55));
+    }
+
+    #[test]
+    fn test_walk_market_order_uses_notional_for_buys() {
+        let positions = vec![order(dec!(0.50), dec!(10)), order(dec!(0.55), dec!(20))];
+
+        // 10.5 USDC buys 20 shares, but 10.5 shares would only need the first level
+        let by_notional = walk_market_order(&positions, dec!(10.5), Side::Buy).unwrap();
+        let by_shares = walk_book_shares(&positions, dec!(10.5), Side::Buy).unwrap();
+        assert_eq!(by_notional.size, dec!(20));
+        assert_ne!(by_notional.average_price, by_shares.average_price);
+
+        let sell = walk_market_order(&positions, dec!(25), Side::Sell).unwrap();
+        assert_eq!(sell.size, dec!(25));
+        assert_eq!(sell.average_price, dec!(0.54));
+    }
+
+    #[test]
+    fn test_price_cap_uses_tighter_bound() {
+        let positions = vec![order(dec!(0.50), dec!(10)), order(dec!(0.60), dec!(10))];
+        let args = MarketOrderArgs::new("1", dec!(5), Side::Buy).max_slippage(dec!(0.1));
+        assert_eq!(
+            market_order_price_cap(&positions, &args).unwrap(),
+            Some(dec!(0.55))
+        );
+
+        let args = args.limit_price(dec!(0.52));
+        assert_eq!(
+            market_order_price_cap(&positions, &args).unwrap(),
+            Some(dec!(0.52))
+        );
+
+        let args = MarketOrderArgs::new("1", dec!(5), Side::Sell).max_slippage(dec!(0.1));
+        assert_eq!(
+            market_order_price_cap(&positions, &args).unwrap(),
+            Some(dec!(0.54))
+        );
+    }
+
+    #[test]
+    fn test_price_cap_validates_slippage_and_clamps() {
+        let positions = vec![order(dec!(0.95), dec!(10))];
+        let buy = MarketOrderArgs::new("1", dec!(5), Side::Buy);
+        assert!(market_order_price_cap(&positions, &buy.clone().max_slippage(dec!(-0.1))).is_err());
+        assert!(market_order_price_cap(&positions, &buy.clone().max_slippage(dec!(1))).is_err());
+
+        // 0.95 * 1.5 is clamped to 1
+        let args = buy.max_slippage(dec!(0.5));
+        assert_eq!(
+            market_order_price_cap(&positions, &args).unwrap(),
+            Some(Decimal::ONE)
+        );
+        let args = MarketOrderArgs::new("1", dec!(5), Side::Sell).max_slippage(dec!(0.99));
+        assert!(market_order_price_cap(&positions, &args).unwrap().unwrap() > Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_protected_walk_rejects_or_truncates() {
+        // 5 USDC at 0.50, then 6 USDC at 0.60
+        let positions = vec![order(dec!(0.50), dec!(10)), order(dec!(0.60), dec!(10))];
+
+        let args = MarketOrderArgs::new("1", dec!(8), Side::Buy).limit_price(dec!(0.55));
+        assert!(walk_market_order_protected(&positions, &args).is_err());
+
+        let args = args.on_slippage(SlippageAction::Truncate);
+        let walk = walk_market_order_protected(&positions, &args).unwrap();
+        assert_eq!(walk.cost, dec!(5));
+        assert_eq!(walk.worst_price, dec!(0.50));
+
+        // Within the cap the walk is unchanged
+        let args = MarketOrderArgs::new("1", dec!(4), Side::Buy).limit_price(dec!(0.55));
+        let walk = walk_market_order_protected(&positions, &args).unwrap();
+        assert_eq!(walk.cost, dec!(4));
+    }
+
+    #[test]
+    fn test_protected_walk_nothing_within_cap() {
+        let positions = vec![order(dec!(0.50), dec!(10))];
+        let args = MarketOrderArgs::new("1", dec!(5), Side::Sell)
+            .limit_price(dec!(0.60))
+            .on_slippage(SlippageAction::Truncate);
+        assert!(walk_market_order_protected(&positions, &args).is_err());
+    }
+
+    #[test]
+    fn test_walk_notional_insufficient_liquidity() {
+        let positions = vec![order(dec!(0.50), dec!(10))];
+        assert!(walk_book_notional(&positions, dec!(6), Side::Buy).is_err());
+        assert!(walk_book_notional(&positions, dec!(0), Side::Buy).is_err());
+    }
 }
@@ -0,0 +1,433 @@
+use alloy_primitives::U256;
+use rust_decimal::Decimal;
+use std::fmt;
+
+use crate::error::{Error, Result};
+use crate::types::{Market, OpenOrder, OrderArgs, Side};
+
+/// Scale of the fixed-point amounts in balance responses
+const COLLATERAL_DECIMALS: u32 = 6;
+
+/// A market or account rule an order breaks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleViolation {
+    /// The price is not strictly between 0 and 1
+    PriceOutOfRange { price: Decimal },
+    /// The price is not a multiple of the market's tick size
+    PriceOffTick { price: Decimal, tick_size: Decimal },
+    /// The size is below the market's minimum order size
+    SizeBelowMinimum { size: Decimal, minimum: Decimal },
+    /// The market is not accepting orders
+    NotAcceptingOrders,
+    /// The market is closed
+    MarketClosed,
+    /// The market has no order book
+    OrderBookDisabled,
+    /// A BUY costs more USDC than the balance not reserved by open orders
+    InsufficientBalance {
+        required: Decimal,
+        available: Decimal,
+    },
+    /// A BUY costs more USDC than the exchange is allowed to spend, less what
+    /// open orders reserve
+    InsufficientAllowance {
+        required: Decimal,
+        available: Decimal,
+    },
+}
+
+impl fmt::Display for RuleViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleViolation::PriceOutOfRange { price } => {
+                write!(f, "price {} is outside (0, 1)", price)
+            }
+            RuleViolation::PriceOffTick { price, tick_size } => {
+                write!(
+                    f,
+                    "price {} is not a multiple of tick size {}",
+                    price, tick_size
+                )
+            }
+            RuleViolation::SizeBelowMinimum { size, minimum } => {
+                write!(
+                    f,
+                    "size {} is below the minimum order size {}",
+                    size, minimum
+                )
+            }
+            RuleViolation::NotAcceptingOrders => write!(f, "market is not accepting orders"),
+            RuleViolation::MarketClosed => write!(f, "market is closed"),
+            RuleViolation::OrderBookDisabled => write!(f, "market order book is disabled"),
+            RuleViolation::InsufficientBalance {
+                required,
+                available,
+            } => write!(
+                f,
+                "order needs {} USDC but the available balance is {}",
+                required, available
+            ),
+            RuleViolation::InsufficientAllowance {
+                required,
+                available,
+            } => write!(
+                f,
+                "order needs {} USDC but the available allowance is {}",
+                required, available
+            ),
+        }
+    }
+}
+
+/// Every rule an order breaks
+///
+/// Returned by [`validate_order`] and, wrapped in
+/// [`Error::Validation`], by
+/// [`AuthenticatedClient::validate_order`](crate::client::AuthenticatedClient::validate_order).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// The violated rules, never empty
+    pub violations: Vec<RuleViolation>,
+}
+
+impl ValidationError {
+    /// Whether `rule` is among the violations
+    pub fn contains(&self, rule: &RuleViolation) -> bool {
+        self.violations.contains(rule)
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, violation) in self.violations.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", violation)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// USDC an account can spend on orders
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollateralState {
+    /// USDC balance
+    pub balance: Decimal,
+    /// USDC the exchange contract is allowed to spend
+    pub allowance: Decimal,
+    /// USDC reserved by open BUY orders
+    pub reserved: Decimal,
+}
+
+impl CollateralState {
+    /// Create a collateral state from USDC amounts, with nothing reserved
+    pub fn new(balance: Decimal, allowance: Decimal) -> Self {
+        Self {
+            balance,
+            allowance,
+            reserved: Decimal::ZERO,
+        }
+    }
+
+    /// Reserve the unfilled notional of the open BUY orders
+    ///
+    /// The exchange only accepts a BUY if the balance and allowance cover it
+    /// on top of the account's other open BUY orders, so pass every open
+    /// order of the account, e.g. from
+    /// [`TradingClient::get_orders`](crate::client::TradingClient::get_orders).
+    pub fn with_open_orders<'a>(mut self, orders: impl IntoIterator<Item = &'a OpenOrder>) -> Self {
+        self.reserved = orders
+            .into_iter()
+            .filter(|o| o.side == Side::Buy)
+            .map(|o| o.price * (o.original_size - o.size_matched).max(Decimal::ZERO))
+            .sum();
+        self
+    }
+
+    /// Balance left after open orders
+    pub fn available_balance(&self) -> Decimal {
+        self.balance.saturating_sub(self.reserved)
+    }
+
+    /// Allowance left after open orders
+    pub fn available_allowance(&self) -> Decimal {
+        self.allowance.saturating_sub(self.reserved)
+    }
+
+    /// Parse a collateral `get_balance_allowance` response
+    ///
+    /// Amounts in the response are in 1e6 fixed point. When the response lists
+    /// allowances per spender, the one for `exchange` is used, and a missing
+    /// entry counts as no allowance.
+    ///
+    /// # Arguments
+    ///
+    /// * `response` - Body returned by
+    ///   [`AuthenticatedClient::get_balance_allowance`](crate::client::AuthenticatedClient::get_balance_allowance)
+    /// * `exchange` - Address of the exchange contract orders go to
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if the balance or allowance is
+    /// missing or not a number.
+    pub fn from_response(response: &serde_json::Value, exchange: &str) -> Result<Self> {
+        let amount = |value: &serde_json::Value, field: &str| -> Result<Decimal> {
+            let units = match value {
+                serde_json::Value::String(s) => s.parse::<U256>().ok(),
+                serde_json::Value::Number(n) => n.as_u64().map(U256::from),
+                _ => None,
+            }
+            .ok_or_else(|| {
+                Error::InvalidParameter(format!("Invalid {} in balance response", field))
+            })?;
+            // Unlimited approvals (max uint256) exceed Decimal's range
+            Ok(i128::try_from(units)
+                .ok()
+                .and_then(|units| {
+                    Decimal::try_from_i128_with_scale(units, COLLATERAL_DECIMALS).ok()
+                })
+                .unwrap_or(Decimal::MAX))
+        };
+
+        let balance = amount(&response["balance"], "balance")?;
+        let allowance = match response.get("allowances").and_then(|a| a.as_object()) {
+            Some(allowances) => allowances
+                .iter()
+                .find(|(spender, _)| spender.eq_ignore_ascii_case(exchange))
+                .map(|(_, value)| amount(value, "allowance"))
+                .transpose()?
+                .unwrap_or(Decimal::ZERO),
+            None => amount(&response["allowance"], "allowance")?,
+        };
+
+        Ok(Self::new(balance, allowance))
+    }
+}
+
+/// Check a limit order against a market's rules and the account's collateral
+///
+/// The rules checked are:
+///
+/// - the price is inside (0, 1) and a multiple of `market.minimum_tick_size`
+/// - the size is at least `market.minimum_order_size`
+/// - the market is accepting orders, not closed, and has its order book enabled
+/// - for a BUY with `collateral` given, `price * size` is covered by both the
+///   balance and the allowance left after
+///   [`reserved`](CollateralState::reserved) open-order notional
+///
+/// Taker fees are not included in the cost, so a marketable BUY that just
+/// fits can still be rejected by the exchange. SELL orders are not checked
+/// against the conditional token balance.
+///
+/// # Arguments
+///
+/// * `args` - The order to check
+/// * `market` - The market the order's token belongs to
+/// * `collateral` - The account's collateral, or `None` to skip those checks
+///
+/// # Errors
+///
+/// Returns a [`ValidationError`] listing every violated rule.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::orders::validate_order;
+/// use polymarket_rs::types::{Market, OrderArgs, Side};
+/// use rust_decimal_macros::dec;
+///
+/// # fn run(market: &Market) {
+/// let args = OrderArgs::new(&market.tokens[0].token_id, dec!(0.455), dec!(1), Side::Buy);
+/// if let Err(e) = validate_order(&args, market, None) {
+///     for violation in &e.violations {
+///         println!("{}", violation);
+///     }
+/// }
+/// # }
+/// ```
+pub fn validate_order(
+    args: &OrderArgs,
+    market: &Market,
+    collateral: Option<&CollateralState>,
+) -> std::result::Result<(), ValidationError> {
+    let mut violations = Vec::new();
+
+    if args.price <= Decimal::ZERO || args.price >= Decimal::ONE {
+        violations.push(RuleViolation::PriceOutOfRange { price: args.price });
+    }
+    let tick_size = market.minimum_tick_size;
+    if !tick_size.is_zero() && !(args.price % tick_size).is_zero() {
+        violations.push(RuleViolation::PriceOffTick {
+            price: args.price,
+            tick_size,
+        });
+    }
+    if args.size < market.minimum_order_size {
+        violations.push(RuleViolation::SizeBelowMinimum {
+            size: args.size,
+            minimum: market.minimum_order_size,
+        });
+    }
+
+    if !market.accepting_orders {
+        violations.push(RuleViolation::NotAcceptingOrders);
+    }
+    if market.closed {
+        violations.push(RuleViolation::MarketClosed);
+    }
+    if !market.enable_order_book {
+        violations.push(RuleViolation::OrderBookDisabled);
+    }
+
+    if let (Side::Buy, Some(collateral)) = (args.side, collateral) {
+        let required = args.price * args.size;
+        let balance = collateral.available_balance();
+        if balance < required {
+            violations.push(RuleViolation::InsufficientBalance {
+                required,
+                available: balance,
+            });
+        }
+        let allowance = collateral.available_allowance();
+        if allowance < required {
+            violations.push(RuleViolation::InsufficientAllowance {
+                required,
+                available: allowance,
+            });
+        }
+    }
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError { violations })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::market;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_valid_order() {
+        let args = OrderArgs::new("1", dec!(0.45), dec!(10), Side::Buy);
+        let collateral = CollateralState::new(dec!(4.5), dec!(100));
+        assert_eq!(validate_order(&args, &market(), Some(&collateral)), Ok(()));
+    }
+
+    #[test]
+    fn test_lists_every_violation() {
+        let mut market = market();
+        market.closed = true;
+        market.accepting_orders = false;
+        let args = OrderArgs::new("1", dec!(1.005), dec!(2), Side::Buy);
+        let collateral = CollateralState::new(dec!(1), dec!(0));
+
+        let err = validate_order(&args, &market, Some(&collateral)).unwrap_err();
+        assert_eq!(
+            err.violations,
+            vec![
+                RuleViolation::PriceOutOfRange { price: dec!(1.005) },
+                RuleViolation::PriceOffTick {
+                    price: dec!(1.005),
+                    tick_size: dec!(0.01)
+                },
+                RuleViolation::SizeBelowMinimum {
+                    size: dec!(2),
+                    minimum: dec!(5)
+                },
+                RuleViolation::NotAcceptingOrders,
+                RuleViolation::MarketClosed,
+                RuleViolation::InsufficientBalance {
+                    required: dec!(2.010),
+                    available: dec!(1)
+                },
+                RuleViolation::InsufficientAllowance {
+                    required: dec!(2.010),
+                    available: dec!(0)
+                },
+            ]
+        );
+        assert!(err.to_string().contains("market is closed"));
+    }
+
+    #[test]
+    fn test_open_orders_reserve_collateral() {
+        let open_order = |side: &str, price: &str, size: &str, matched: &str| -> OpenOrder {
+            serde_json::from_value(serde_json::json!({
+                "id": "0x1",
+                "associate_trades": [],
+                "status": "LIVE",
+                "market": "0xabc",
+                "original_size": size,
+                "outcome": "Yes",
+                "maker_address": "0x0",
+                "owner": "key",
+                "price": price,
+                "side": side,
+                "size_matched": matched,
+                "asset_id": "1",
+                "expiration": "0",
+                "order_type": "GTC",
+                "created_at": "0"
+            }))
+            .unwrap()
+        };
+        let orders = [
+            // 0.4 * (10 - 5)
+            open_order("BUY", "0.4", "10", "5"),
+            open_order("SELL", "0.6", "10", "0"),
+        ];
+        let collateral = CollateralState::new(dec!(6.5), dec!(100)).with_open_orders(&orders);
+        assert_eq!(collateral.reserved, dec!(2));
+
+        let args = OrderArgs::new("1", dec!(0.45), dec!(10), Side::Buy);
+        assert_eq!(validate_order(&args, &market(), Some(&collateral)), Ok(()));
+        let args = OrderArgs::new("1", dec!(0.46), dec!(10), Side::Buy);
+        let err = validate_order(&args, &market(), Some(&collateral)).unwrap_err();
+        assert_eq!(
+            err.violations,
+            vec![RuleViolation::InsufficientBalance {
+                required: dec!(4.60),
+                available: dec!(4.5)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_sell_skips_collateral() {
+        let args = OrderArgs::new("1", dec!(0.45), dec!(10), Side::Sell);
+        let collateral = CollateralState::new(Decimal::ZERO, Decimal::ZERO);
+        assert_eq!(validate_order(&args, &market(), Some(&collateral)), Ok(()));
+    }
+
+    #[test]
+    fn test_collateral_from_response() {
+        let exchange = "0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E";
+        let response = serde_json::json!({
+            "balance": "12500000",
+            "allowances": {
+                "0x4bfb41d5b3570defd03c39a9a4d8de6bd8b8982e": "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+                "0xC5d563A36AE78145C45a50134d48A1215220f80a": "0"
+            }
+        });
+        let collateral = CollateralState::from_response(&response, exchange).unwrap();
+        assert_eq!(collateral.balance, dec!(12.5));
+        assert_eq!(collateral.allowance, Decimal::MAX);
+
+        let legacy = serde_json::json!({ "balance": "1000000", "allowance": "500000" });
+        let collateral = CollateralState::from_response(&legacy, exchange).unwrap();
+        assert_eq!(collateral, CollateralState::new(dec!(1), dec!(0.5)));
+
+        let missing = serde_json::json!({ "balance": "1000000", "allowances": {} });
+        let collateral = CollateralState::from_response(&missing, exchange).unwrap();
+        assert_eq!(collateral.allowance, Decimal::ZERO);
+
+        assert!(CollateralState::from_response(&serde_json::json!({}), exchange).is_err());
+    }
+}
@@ -0,0 +1,833 @@
+use futures_util::{stream, Stream, StreamExt};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::sync::broadcast;
+
+use crate::backtest::{BacktestReport, Fill, Liquidity, MarketRules, SimExchange};
+use crate::error::{Error, Result};
+use crate::types::{
+    CancelOrdersResponse, MakerOrder, Market, OpenOrder, OpenOrderParams, OpenOrdersResponse,
+    OrderArgs, OrderEvent, OrderId, OrderStatus, OrderType, PostOrderArgs, PostOrderResponse, Side,
+    SignedOrderRequest, TradeEvent, TradeParams, TradeStatus, UserWsEvent, WsEvent,
+};
+
+/// Capacity of the synthetic user event channel
+const USER_EVENT_CHANNEL_CAPACITY: usize = 1024;
+
+/// Cursor the API returns on the last page
+const END_CURSOR: &str = "LTE=";
+
+#[derive(Debug, Clone)]
+struct PaperOrder {
+    id: OrderId,
+    sim_id: u64,
+    market: String,
+    asset_id: String,
+    outcome: String,
+    side: Side,
+    price: Decimal,
+    original_size: Decimal,
+    size_matched: Decimal,
+    maker_address: String,
+    owner: String,
+    order_type: OrderType,
+    expiration: u64,
+    /// Unix seconds
+    created_at: u64,
+    status: OrderStatus,
+    trades: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+struct TokenInfo {
+    market: String,
+    outcome: String,
+    neg_risk: bool,
+}
+
+struct PaperState {
+    exchange: SimExchange,
+    tokens: HashMap<String, TokenInfo>,
+    orders: HashMap<String, PaperOrder>,
+    /// Simulated order ID to paper order ID
+    sim_ids: HashMap<u64, String>,
+    /// Every synthetic trade, oldest first
+    trades: Vec<TradeEvent>,
+}
+
+/// Paper-trading backend for [`TradingClient`](crate::client::TradingClient)
+///
+/// Attach it with [`TradingClient::with_paper_trading`](crate::client::TradingClient::with_paper_trading)
+/// and the client keeps its API, but posting, cancelling and querying orders
+/// and trades is served from a [`SimExchange`] instead of the CLOB:
+///
+/// - Signed orders are decoded back into price and size and matched against
+///   the local books with the exchange's price-time priority and
+///   time-in-force rules. GTD expirations are not simulated.
+/// - Cash and positions are virtual and checked like the exchange does.
+/// - Order placements, fills and cancellations are published as synthetic
+///   [`UserWsEvent`]s on [`user_stream`](Self::user_stream), which has the
+///   same type as [`UserWsClient::subscribe`](crate::websocket::UserWsClient::subscribe).
+///
+/// The books must be fed with live market events through
+/// [`apply_event`](Self::apply_event) or [`run_stream`](Self::run_stream). The
+/// backend is cheap to clone; clones share the same state.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::client::TradingClient;
+/// use polymarket_rs::paper::PaperTrading;
+/// use polymarket_rs::types::Market;
+/// use polymarket_rs::websocket::MarketWsClient;
+/// use rust_decimal_macros::dec;
+///
+/// # async fn run(client: TradingClient, market: Market) -> Result<(), Box<dyn std::error::Error>> {
+/// let paper = PaperTrading::new(dec!(1000)).with_market(&market);
+///
+/// let token_ids = market.tokens.iter().map(|t| t.token_id.clone()).collect();
+/// let feed = MarketWsClient::new().subscribe(token_ids).await?;
+/// let driver = paper.clone();
+/// tokio::spawn(async move { driver.run_stream(feed).await });
+///
+/// let client = client.with_paper_trading(paper.clone());
+/// // ... trade with `client` as usual, then:
+/// println!("PnL: {}", paper.report().total_pnl);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct PaperTrading {
+    state: Arc<Mutex<PaperState>>,
+    events_tx: broadcast::Sender<UserWsEvent>,
+}
+
+impl PaperTrading {
+    /// Create a backend with a starting cash balance
+    pub fn new(initial_cash: Decimal) -> Self {
+        let (events_tx, _rx) = broadcast::channel(USER_EVENT_CHANNEL_CAPACITY);
+        Self {
+            state: Arc::new(Mutex::new(PaperState {
+                exchange: SimExchange::new(initial_cash),
+                tokens: HashMap::new(),
+                orders: HashMap::new(),
+                sim_ids: HashMap::new(),
+                trades: Vec::new(),
+            })),
+            events_tx,
+        }
+    }
+
+    /// Allow trading both tokens of a market
+    pub fn with_market(self, market: &Market) -> Self {
+        self.add_market(market);
+        self
+    }
+
+    /// Allow trading both tokens of a market
+    pub fn add_market(&self, market: &Market) {
+        let mut state = self.lock();
+        state.exchange.add_market(market);
+        for token in &market.tokens {
+            state.tokens.insert(
+                token.token_id.clone(),
+                TokenInfo {
+                    market: market.condition_id.clone(),
+                    outcome: token.outcome.clone(),
+                    neg_risk: market.neg_risk,
+                },
+            );
+        }
+    }
+
+    /// Allow trading a single token with the given rules
+    ///
+    /// The token is treated as a standard (not neg risk) market when orders
+    /// are hashed into their IDs.
+    pub fn add_token(&self, token_id: impl Into<String>, rules: MarketRules) {
+        self.lock().exchange.add_token(token_id, rules);
+    }
+
+    /// Receiver of the synthetic user events
+    pub fn user_events(&self) -> broadcast::Receiver<UserWsEvent> {
+        self.events_tx.subscribe()
+    }
+
+    /// Stream of the synthetic user events
+    ///
+    /// This has the same type as the stream returned by
+    /// [`UserWsClient::subscribe`](crate::websocket::UserWsClient::subscribe). If the
+    /// consumer falls behind, the oldest events are skipped.
+    pub fn user_stream(&self) -> Pin<Box<dyn Stream<Item = Result<UserWsEvent>> + Send>> {
+        let rx = self.events_tx.subscribe();
+        Box::pin(stream::unfold(rx, |mut rx| async move {
+            loop {
+                match rx.recv().await {
+                    Ok(event) => return Some((Ok(event), rx)),
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        log::warn!("Paper user stream skipped {} events", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        }))
+    }
+
+    /// Update the books with a market event and fill orders it reaches
+    pub fn apply_event(&self, event: &WsEvent) {
+        let mut state = self.lock();
+        state.exchange.apply_event(event);
+        let events = state.drain_fills();
+        drop(state);
+        self.publish(events);
+    }
+
+    /// Feed market events from a stream until it ends
+    ///
+    /// Stream errors are logged and skipped, so a
+    /// [`ReconnectingStream`](crate::websocket::ReconnectingStream) keeps the
+    /// backend running across reconnects.
+    pub async fn run_stream<S>(&self, stream: S)
+    where
+        S: Stream<Item = Result<WsEvent>>,
+    {
+        futures_util::pin_mut!(stream);
+        while let Some(event) = stream.next().await {
+            match event {
+                Ok(event) => self.apply_event(&event),
+                Err(e) => log::warn!("Paper trading feed error: {}", e),
+            }
+        }
+    }
+
+    /// Virtual cash balance
+    pub fn cash(&self) -> Decimal {
+        self.lock().exchange.cash()
+    }
+
+    /// Virtual position in a token
+    pub fn position(&self, token_id: &str) -> Decimal {
+        self.lock().exchange.position(token_id)
+    }
+
+    /// PnL, fills and inventory so far
+    pub fn report(&self) -> BacktestReport {
+        self.lock().exchange.report()
+    }
+
+    pub(crate) fn post_order(
+        &self,
+        order: &SignedOrderRequest,
+        order_type: OrderType,
+        owner: &str,
+        chain_id: u64,
+    ) -> Result<PostOrderResponse> {
+        let mut state = self.lock();
+        let id = match state.submit(order, order_type, owner, chain_id) {
+            Ok(id) => id,
+            Err(e) => {
+                return Err(Error::Api {
+                    status: 400,
+                    message: e.to_string(),
+                })
+            }
+        };
+
+        let mut events: Vec<UserWsEvent> =
+            state.order_event(&id, "PLACEMENT").into_iter().collect();
+        events.extend(state.drain_fills());
+        // FAK and FOK remainders never rest
+        let order = state.placed(&id)?;
+        if state.exchange.order(order.sim_id).is_none() && order.status == OrderStatus::Live {
+            state.cancel(&id);
+            events.extend(state.order_event(&id, "CANCELLATION"));
+        }
+
+        let order = state.placed(&id)?;
+        let status = if order.size_matched > Decimal::ZERO {
+            "matched"
+        } else if order.status == OrderStatus::Live {
+            "live"
+        } else {
+            "unmatched"
+        };
+        drop(state);
+
+        self.publish(events);
+        Ok(PostOrderResponse {
+            error_msg: String::new(),
+            order_id: id,
+            status: status.to_string(),
+            success: true,
+        })
+    }
+
+    pub(crate) fn post_orders(
+        &self,
+        orders: &[PostOrderArgs],
+        owner: &str,
+        chain_id: u64,
+    ) -> Vec<PostOrderResponse> {
+        orders
+            .iter()
+            .map(|arg| {
+                self.post_order(&arg.order, arg.order_type, owner, chain_id)
+                    .unwrap_or_else(|e| PostOrderResponse {
+                        error_msg: e.to_string(),
+                        order_id: OrderId::new(""),
+                        status: String::new(),
+                        success: false,
+                    })
+            })
+            .collect()
+    }
+
+    // `map_or` rather than `is_none_or`, which needs Rust 1.82
+    #[allow(clippy::unnecessary_map_or)]
+    pub(crate) fn get_orders(&self, params: &OpenOrderParams) -> OpenOrdersResponse {
+        let state = self.lock();
+        let mut data: Vec<OpenOrder> = state
+            .orders
+            .values()
+            .filter(|o| o.status == OrderStatus::Live)
+            .filter(|o| params.id.as_ref().map_or(true, |id| o.id.as_str() == id))
+            .filter(|o| params.asset_id.as_ref().map_or(true, |a| &o.asset_id == a))
+            .filter(|o| params.market.as_ref().map_or(true, |m| &o.market == m))
+            .map(PaperOrder::to_open_order)
+            .collect();
+        data.sort_by(|a, b| {
+            a.created_at
+                .cmp(&b.created_at)
+                .then(a.id.as_str().cmp(b.id.as_str()))
+        });
+
+        OpenOrdersResponse {
+            limit: data.len() as u64,
+            count: data.len() as u64,
+            next_cursor: Some(END_CURSOR.to_string()),
+            data,
+        }
+    }
+
+    pub(crate) fn get_order(&self, order_id: &OrderId) -> Result<OpenOrder> {
+        self.lock()
+            .orders
+            .get(order_id.as_str())
+            .map(PaperOrder::to_open_order)
+            .ok_or_else(|| Error::Api {
+                status: 404,
+                message: format!("order {} not found", order_id),
+            })
+    }
+
+    pub(crate) fn cancel_orders(&self, order_ids: &[OrderId]) -> CancelOrdersResponse {
+        self.cancel_where(|o| order_ids.contains(&o.id), order_ids)
+    }
+
+    #[allow(clippy::unnecessary_map_or)]
+    pub(crate) fn cancel_market_orders(
+        &self,
+        market: Option<&str>,
+        asset_id: Option<&str>,
+    ) -> CancelOrdersResponse {
+        self.cancel_where(
+            |o| {
+                market.map_or(true, |m| m.is_empty() || o.market == m)
+                    && asset_id.map_or(true, |a| a.is_empty() || o.asset_id == a)
+            },
+            &[],
+        )
+    }
+
+    #[allow(clippy::unnecessary_map_or)]
+    pub(crate) fn get_trades(&self, params: &TradeParams) -> serde_json::Value {
+        let state = self.lock();
+        let trades: Vec<&TradeEvent> = state
+            .trades
+            .iter()
+            .filter(|t| params.id.as_ref().map_or(true, |id| &t.id == id))
+            .filter(|t| params.market.as_ref().map_or(true, |m| &t.market == m))
+            .filter(|t| params.asset_id.as_ref().map_or(true, |a| &t.asset_id == a))
+            .collect();
+        serde_json::to_value(trades).unwrap_or_default()
+    }
+
+    /// Cancel live orders matching `filter`; `requested` IDs that are not
+    /// live are reported as not cancelled
+    fn cancel_where(
+        &self,
+        filter: impl Fn(&PaperOrder) -> bool,
+        requested: &[OrderId],
+    ) -> CancelOrdersResponse {
+        let mut state = self.lock();
+        let ids: Vec<OrderId> = state
+            .orders
+            .values()
+            .filter(|o| o.status == OrderStatus::Live && filter(o))
+            .map(|o| o.id.clone())
+            .collect();
+
+        let mut events = Vec::with_capacity(ids.len());
+        for id in &ids {
+            state.cancel(id);
+            events.extend(state.order_event(id, "CANCELLATION"));
+        }
+
+        let not_canceled: serde_json::Map<String, serde_json::Value> = requested
+            .iter()
+            .filter(|id| !ids.contains(id))
+            .map(|id| {
+                let reason = match state.orders.get(id.as_str()) {
+                    Some(_) => "order can't be found - already canceled or matched",
+                    None => "order not found",
+                };
+                (id.to_string(), reason.into())
+            })
+            .collect();
+        drop(state);
+
+        self.publish(events);
+        CancelOrdersResponse {
+            canceled: ids,
+            not_canceled: serde_json::Value::Object(not_canceled),
+        }
+    }
+
+    fn publish(&self, events: Vec<UserWsEvent>) {
+        for event in events {
+            // No receivers is fine
+            let _ = self.events_tx.send(event);
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, PaperState> {
+        // The state stays consistent even if a holder panicked
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl PaperState {
+    /// Decode, place and match a signed order; returns its ID
+    ///
+    /// Like on the exchange, the ID is the order's EIP-712 hash and an order
+    /// that was already posted is rejected.
+    fn submit(
+        &mut self,
+        order: &SignedOrderRequest,
+        order_type: OrderType,
+        owner: &str,
+        chain_id: u64,
+    ) -> Result<OrderId> {
+        let info = self.token_info(&order.token_id);
+        let id = order.order_hash(chain_id, info.neg_risk)?;
+        if self.orders.contains_key(id.as_str()) {
+            return Err(Error::InvalidOrder("order already exists".to_string()));
+        }
+
+        let args = self.decode(order)?;
+        let sim_id = self.exchange.submit_order_with_type(&args, order_type)?;
+        self.orders.insert(
+            id.to_string(),
+            PaperOrder {
+                id: id.clone(),
+                sim_id,
+                market: info.market,
+                asset_id: args.token_id.clone(),
+                outcome: info.outcome,
+                side: args.side,
+                price: args.price,
+                original_size: args.size,
+                size_matched: Decimal::ZERO,
+                maker_address: order.maker.clone(),
+                owner: owner.to_string(),
+                order_type,
+                expiration: order.expiration.parse().unwrap_or_default(),
+                created_at: self.exchange.now_millis() / 1000,
+                status: OrderStatus::Live,
+                trades: Vec::new(),
+            },
+        );
+        self.sim_ids.insert(sim_id, id.to_string());
+        Ok(id)
+    }
+
+    /// Recover price and size from the signed amounts
+    fn decode(&self, order: &SignedOrderRequest) -> Result<OrderArgs> {
+        let side = order.order_side()?;
+        let size = order.size()?;
+
+        // Amounts are rounded when signing, so snap the price back onto the grid
+        let mut price = order.price()?;
+        if let Some(tick) = self.exchange.rules(&order.token_id).map(|r| r.tick_size) {
+            if !tick.is_zero() {
+                price = (price / tick).round() * tick;
+            }
+        }
+        Ok(OrderArgs::new(
+            order.token_id.clone(),
+            price.normalize(),
+            size,
+            side,
+        ))
+    }
+
+    fn token_info(&self, token_id: &str) -> TokenInfo {
+        self.tokens
+            .get(token_id)
+            .cloned()
+            .unwrap_or_else(|| TokenInfo {
+                market: self
+                    .exchange
+                    .book(token_id)
+                    .map(|b| b.market().to_string())
+                    .unwrap_or_default(),
+                outcome: String::new(),
+                neg_risk: false,
+            })
+    }
+
+    /// An order that was just submitted
+    fn placed(&self, id: &OrderId) -> Result<&PaperOrder> {
+        self.orders
+            .get(id.as_str())
+            .ok_or_else(|| Error::InvalidOrder(format!("order {} was not placed", id)))
+    }
+
+    fn cancel(&mut self, id: &OrderId) {
+        if let Some(order) = self.orders.get_mut(id.as_str()) {
+            self.exchange.cancel(order.sim_id);
+            order.status = OrderStatus::Canceled;
+        }
+    }
+
+    /// Record new fills on their orders and build the user events for them
+    fn drain_fills(&mut self) -> Vec<UserWsEvent> {
+        let mut events = Vec::new();
+        for fill in self.exchange.take_new_fills() {
+            let Some(id) = self.sim_ids.get(&fill.order_id).cloned() else {
+                continue;
+            };
+            let Some(trade) = self.trade_event(&id, &fill) else {
+                continue;
+            };
+            let Some(order) = self.orders.get_mut(&id) else {
+                continue;
+            };
+            order.size_matched += fill.size;
+            order.trades.push(trade.id.clone());
+            if order.size_matched >= order.original_size {
+                order.status = OrderStatus::Matched;
+            }
+
+            events.push(UserWsEvent::Trade(trade.clone()));
+            events.extend(self.order_event(&OrderId::new(id), "UPDATE"));
+            self.trades.push(trade);
+        }
+        events
+    }
+
+    fn trade_event(&self, id: &str, fill: &Fill) -> Option<TradeEvent> {
+        let order = self.orders.get(id)?;
+        let (side, maker_orders, taker_order_id) = match fill.liquidity {
+            Liquidity::Taker => (fill.side, Vec::new(), Some(id.to_string())),
+            Liquidity::Maker => (
+                fill.side.opposite(),
+                vec![MakerOrder {
+                    maker_address: order.maker_address.clone(),
+                    matched_amount: fill.size,
+                    price: fill.price,
+                    outcome: order.outcome.clone(),
+                    order_id: Some(id.to_string()),
+                    asset_id: Some(fill.token_id.clone()),
+                }],
+                None,
+            ),
+        };
+
+        Some(TradeEvent {
+            event_type: "trade".to_string(),
+            id: format!("paper-trade-{}", self.trades.len() + 1),
+            market: order.market.clone(),
+            asset_id: fill.token_id.clone(),
+            side,
+            outcome: order.outcome.clone(),
+            price: fill.price,
+            size: fill.size,
+            status: TradeStatus::Matched,
+            maker_orders,
+            taker_order_id,
+        })
+    }
+
+    fn order_event(&self, id: &OrderId, event_type: &str) -> Option<UserWsEvent> {
+        let order = self.orders.get(id.as_str())?;
+        Some(UserWsEvent::Order(OrderEvent {
+            event_type: "order".to_string(),
+            id: order.id.to_string(),
+            owner: Some(order.owner.clone()),
+            market: order.market.clone(),
+            asset_id: order.asset_id.clone(),
+            side: order.side,
+            order_owner: Some(order.owner.clone()),
+            original_size: order.original_size,
+            size_matched: order.size_matched,
+            price: order.price,
+            associate_trades: None,
+            outcome: order.outcome.clone(),
+            order_event_type: event_type.to_string(),
+            created_at: Some(order.created_at.to_string()),
+            expiration: Some(order.expiration.to_string()),
+            order_type: order.order_type.as_str().to_string(),
+            status: order.status.as_str().to_string(),
+            maker_address: order.maker_address.clone(),
+            timestamp: Some(self.exchange.now_millis().to_string()),
+        }))
+    }
+}
+
+impl PaperOrder {
+    fn to_open_order(&self) -> OpenOrder {
+        OpenOrder {
+            id: self.id.clone(),
+            associate_trades: self.trades.clone(),
+            status: self.status.as_str().to_string(),
+            market: self.market.clone(),
+            original_size: self.original_size,
+            outcome: self.outcome.clone(),
+            maker_address: self.maker_address.clone(),
+            owner: self.owner.clone(),
+            price: self.price,
+            side: self.side,
+            size_matched: self.size_matched,
+            asset_id: self.asset_id.clone(),
+            expiration: self.expiration,
+            order_type: self.order_type,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{paper, trade};
+    use crate::orders::OrderBuilder;
+    use crate::types::{CreateOrderOptions, ExtraOrderArgs};
+    use alloy_signer_local::PrivateKeySigner;
+    use rust_decimal_macros::dec;
+
+    const CHAIN_ID: u64 = 137;
+
+    fn signed(side: Side, price: Decimal, size: Decimal) -> SignedOrderRequest {
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None);
+        builder
+            .create_order(
+                CHAIN_ID,
+                &OrderArgs::new("1", price, size, side),
+                0,
+                &ExtraOrderArgs::default(),
+                CreateOrderOptions::default()
+                    .tick_size(dec!(0.01))
+                    .neg_risk(false),
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_marketable_order_fills_against_book() {
+        let paper = paper();
+        let mut events = paper.user_events();
+
+        let response = paper
+            .post_order(
+                &signed(Side::Buy, dec!(0.52), dec!(10)),
+                OrderType::Gtc,
+                "key",
+                CHAIN_ID,
+            )
+            .unwrap();
+        assert!(response.success);
+        assert_eq!(response.status, "matched");
+        assert_eq!(paper.position("1"), dec!(10));
+        assert_eq!(paper.cash(), dec!(94.8));
+
+        let UserWsEvent::Order(placement) = events.try_recv().unwrap() else {
+            panic!("expected an order event");
+        };
+        assert_eq!(placement.order_event_type, "PLACEMENT");
+        let UserWsEvent::Trade(fill) = events.try_recv().unwrap() else {
+            panic!("expected a trade event");
+        };
+        assert_eq!(
+            fill.taker_order_id.as_deref(),
+            Some(response.order_id.as_str())
+        );
+        assert_eq!(fill.price, dec!(0.52));
+        let UserWsEvent::Order(update) = events.try_recv().unwrap() else {
+            panic!("expected an order event");
+        };
+        assert_eq!(update.status, "MATCHED");
+        assert_eq!(update.size_matched, dec!(10));
+
+        let order = paper.get_order(&response.order_id).unwrap();
+        assert_eq!(order.status, "MATCHED");
+        assert_eq!(order.associate_trades, vec![fill.id.clone()]);
+        assert!(paper
+            .get_orders(&OpenOrderParams::default())
+            .data
+            .is_empty());
+        assert_eq!(paper.get_trades(&TradeParams::default())[0]["id"], fill.id);
+    }
+
+    #[test]
+    fn test_resting_order_fills_from_trades() {
+        let paper = paper();
+        let response = paper
+            .post_order(
+                &signed(Side::Buy, dec!(0.5), dec!(10)),
+                OrderType::Gtc,
+                "key",
+                CHAIN_ID,
+            )
+            .unwrap();
+        assert_eq!(response.status, "live");
+        let open = paper.get_orders(&OpenOrderParams::default());
+        assert_eq!(open.data.len(), 1);
+        assert_eq!(open.data[0].price, dec!(0.5));
+        assert_eq!(open.data[0].original_size, dec!(10));
+
+        let mut events = paper.user_events();
+        paper.apply_event(&trade(Side::Sell, dec!(0.5), dec!(4)));
+
+        let UserWsEvent::Trade(fill) = events.try_recv().unwrap() else {
+            panic!("expected a trade event");
+        };
+        assert_eq!(fill.side, Side::Sell);
+        assert_eq!(fill.maker_orders.len(), 1);
+        assert_eq!(fill.maker_orders[0].matched_amount, dec!(4));
+        let UserWsEvent::Order(update) = events.try_recv().unwrap() else {
+            panic!("expected an order event");
+        };
+        assert_eq!(update.order_event_type, "UPDATE");
+        assert_eq!(update.status, "LIVE");
+        assert_eq!(
+            paper.get_order(&response.order_id).unwrap().size_matched,
+            dec!(4)
+        );
+    }
+
+    #[test]
+    fn test_rejected_orders() {
+        let paper = paper();
+        let fok = signed(Side::Buy, dec!(0.52), dec!(20));
+        match paper.post_order(&fok, OrderType::Fok, "key", CHAIN_ID) {
+            Err(Error::Api { status: 400, .. }) => {}
+            other => panic!("expected a rejection, got {:?}", other),
+        }
+
+        let responses = paper.post_orders(
+            &[
+                PostOrderArgs::new(fok, OrderType::Fok),
+                PostOrderArgs::new(signed(Side::Buy, dec!(0.4), dec!(10)), OrderType::Gtc),
+            ],
+            "key",
+            CHAIN_ID,
+        );
+        assert!(!responses[0].success);
+        assert!(!responses[0].error_msg.is_empty());
+        assert!(responses[1].success);
+        assert_eq!(paper.position("1"), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_order_id_is_hash_and_duplicates_are_rejected() {
+        let paper = paper();
+        let order = signed(Side::Buy, dec!(0.4), dec!(10));
+        let response = paper
+            .post_order(&order, OrderType::Gtc, "key", CHAIN_ID)
+            .unwrap();
+        assert_eq!(
+            response.order_id,
+            order.order_hash(CHAIN_ID, false).unwrap()
+        );
+
+        match paper.post_order(&order, OrderType::Gtc, "key", CHAIN_ID) {
+            Err(Error::Api {
+                status: 400,
+                message,
+            }) => {
+                assert!(message.contains("order already exists"))
+            }
+            other => panic!("expected a rejection, got {:?}", other),
+        }
+        assert_eq!(paper.get_orders(&OpenOrderParams::default()).data.len(), 1);
+    }
+
+    #[test]
+    fn test_fak_remainder_is_cancelled() {
+        let paper = paper();
+        let mut events = paper.user_events();
+        let response = paper
+            .post_order(
+                &signed(Side::Buy, dec!(0.52), dec!(15)),
+                OrderType::Fak,
+                "key",
+                CHAIN_ID,
+            )
+            .unwrap();
+        assert_eq!(response.status, "matched");
+        let order = paper.get_order(&response.order_id).unwrap();
+        assert_eq!(order.size_matched, dec!(10));
+        assert_eq!(order.status, "CANCELED");
+        assert_eq!(paper.position("1"), dec!(10));
+
+        let mut last = None;
+        while let Ok(event) = events.try_recv() {
+            last = Some(event);
+        }
+        let Some(UserWsEvent::Order(cancel)) = last else {
+            panic!("expected an order event");
+        };
+        assert_eq!(cancel.order_event_type, "CANCELLATION");
+        assert_eq!(cancel.size_matched, dec!(10));
+    }
+
+    #[test]
+    fn test_cancel_orders() {
+        let paper = paper();
+        let first = paper
+            .post_order(
+                &signed(Side::Buy, dec!(0.4), dec!(10)),
+                OrderType::Gtc,
+                "key",
+                CHAIN_ID,
+            )
+            .unwrap();
+        let second = paper.post_order(
+            &signed(Side::Sell, dec!(0.6), dec!(5)),
+            OrderType::Gtc,
+            "key",
+            CHAIN_ID,
+        );
+        // No position to sell yet
+        assert!(second.is_err());
+
+        let mut events = paper.user_events();
+        let unknown = OrderId::new("0xdead");
+        let response = paper.cancel_orders(&[first.order_id.clone(), unknown.clone()]);
+        assert_eq!(response.canceled, vec![first.order_id.clone()]);
+        assert!(response.not_canceled.get(unknown.as_str()).is_some());
+
+        let UserWsEvent::Order(cancel) = events.try_recv().unwrap() else {
+            panic!("expected an order event");
+        };
+        assert_eq!(cancel.order_event_type, "CANCELLATION");
+        assert!(paper
+            .get_orders(&OpenOrderParams::default())
+            .data
+            .is_empty());
+        assert!(paper.cancel_market_orders(None, None).canceled.is_empty());
+    }
+}
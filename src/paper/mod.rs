@@ -0,0 +1,11 @@
+//! Paper trading against live markets.
+//!
+//! [`PaperTrading`] is a backend for [`TradingClient`](crate::client::TradingClient):
+//! orders are still created and signed with the [`OrderBuilder`](crate::orders::OrderBuilder),
+//! but instead of being posted they are matched against local books fed by the
+//! market WebSocket, with virtual balances and positions. Order and trade
+//! updates are published as synthetic [`UserWsEvent`](crate::types::UserWsEvent)s.
+
+mod backend;
+
+pub use backend::PaperTrading;
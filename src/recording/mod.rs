@@ -0,0 +1,21 @@
+//! Recording and replay of WebSocket sessions.
+//!
+//! - [`Recorder`]: Writes raw messages with their local receive time to
+//!   gzip-compressed JSONL files, rotated by size or by hour
+//! - [`Replay`]: Re-emits recordings as the same event streams the WebSocket
+//!   clients return, paced in real time, accelerated or as fast as possible
+//! - [`RecordingReader`]: Iterates over the messages of recordings
+//!
+//! Only the streams from
+//! [`MarketWsClient::subscribe_raw`](crate::websocket::MarketWsClient::subscribe_raw) and
+//! [`UserWsClient::subscribe_raw`](crate::websocket::UserWsClient::subscribe_raw) can be
+//! recorded; they keep every message as received, with its receive time. The
+//! parsed event streams of `subscribe` cannot.
+
+mod reader;
+mod recorder;
+mod replay;
+
+pub use reader::RecordingReader;
+pub use recorder::{Recorder, Rotation};
+pub use replay::{Pacing, Replay, SimulatedClock};
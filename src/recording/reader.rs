@@ -0,0 +1,128 @@
+use chrono::NaiveDateTime;
+use flate2::read::MultiGzDecoder;
+use std::collections::VecDeque;
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, Lines};
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+use crate::websocket::RawMessage;
+
+/// Reads messages back from files written by a [`Recorder`](super::Recorder)
+///
+/// Files are read in the order given, line by line. A line that cannot be
+/// decoded yields an error and reading continues with the next line; a file
+/// that cannot be opened or is truncated yields an error and reading
+/// continues with the next file.
+pub struct RecordingReader {
+    files: VecDeque<PathBuf>,
+    current: Option<Lines<BufReader<MultiGzDecoder<File>>>>,
+}
+
+impl RecordingReader {
+    /// Read the given files in order
+    pub fn new(files: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+        Self {
+            files: files.into_iter().map(Into::into).collect(),
+            current: None,
+        }
+    }
+
+    /// Read every recording with the given prefix in `dir`, oldest first
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be listed.
+    pub fn from_dir(dir: impl AsRef<Path>, prefix: &str) -> Result<Self> {
+        Ok(Self::new(recording_files(dir.as_ref(), prefix)?))
+    }
+}
+
+impl Iterator for RecordingReader {
+    type Item = Result<RawMessage>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let lines = match self.current.as_mut() {
+                Some(lines) => lines,
+                None => {
+                    let path = self.files.pop_front()?;
+                    match File::open(&path) {
+                        Ok(file) => {
+                            self.current = Some(BufReader::new(MultiGzDecoder::new(file)).lines());
+                            continue;
+                        }
+                        Err(e) => return Some(Err(e.into())),
+                    }
+                }
+            };
+
+            match lines.next() {
+                Some(Ok(line)) if line.trim().is_empty() => continue,
+                Some(Ok(line)) => return Some(serde_json::from_str(&line).map_err(Error::Json)),
+                Some(Err(e)) => {
+                    self.current = None;
+                    return Some(Err(e.into()));
+                }
+                None => self.current = None,
+            }
+        }
+    }
+}
+
+/// Recording files with the given prefix in `dir`, sorted by name
+pub(crate) fn recording_files(dir: &Path, prefix: &str) -> Result<Vec<PathBuf>> {
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| is_recording_file(name, prefix))
+        })
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// Whether `name` is `{prefix}-<YYYYMMDDTHHMMSSZ>-<seq>.jsonl.gz`
+///
+/// Matching the whole name keeps `market` from picking up the files of a
+/// `market-eu` recorder in the same directory.
+fn is_recording_file(name: &str, prefix: &str) -> bool {
+    let Some(rest) = name
+        .strip_prefix(prefix)
+        .and_then(|rest| rest.strip_prefix('-'))
+        .and_then(|rest| rest.strip_suffix(".jsonl.gz"))
+    else {
+        return false;
+    };
+    let Some((started, seq)) = rest.split_once('-') else {
+        return false;
+    };
+    NaiveDateTime::parse_from_str(started, "%Y%m%dT%H%M%SZ").is_ok()
+        && !seq.is_empty()
+        && seq.bytes().all(|b| b.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_only_the_recorder_file_pattern() {
+        let cases = [
+            ("book-20240101T000000Z-0000.jsonl.gz", "book", true),
+            ("book-20240101T235959Z-12345.jsonl.gz", "book", true),
+            ("book-eu-20240101T000000Z-0000.jsonl.gz", "book-eu", true),
+            ("book-eu-20240101T000000Z-0000.jsonl.gz", "book", false),
+            ("books-20240101T000000Z-0000.jsonl.gz", "book", false),
+            ("book-20240101T000000Z.jsonl.gz", "book", false),
+            ("book-20240101T000000Z-.jsonl.gz", "book", false),
+            ("book-2024010T000000Z-0000.jsonl.gz", "book", false),
+            ("book-20240101T000000Z-0000.jsonl", "book", false),
+        ];
+        for (name, prefix, expected) in cases {
+            assert_eq!(is_recording_file(name, prefix), expected, "{}", name);
+        }
+    }
+}
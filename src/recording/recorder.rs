@@ -0,0 +1,389 @@
+use chrono::DateTime;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use futures_util::{Stream, StreamExt};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufWriter, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::error::Result;
+use crate::websocket::RawMessage;
+
+const MILLIS_PER_HOUR: u64 = 60 * 60 * 1000;
+
+/// Most stream items written per blocking task
+const WRITE_BATCH: usize = 256;
+
+/// When a [`Recorder`] starts a new file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    /// Start a new file when the UTC hour of the receive time changes
+    Hourly,
+    /// Start a new file once this many uncompressed bytes have been written
+    Size(u64),
+}
+
+struct OpenFile {
+    encoder: GzEncoder<BufWriter<File>>,
+    /// Uncompressed bytes written so far
+    bytes: u64,
+    /// UTC hour (since the epoch) of the first message in the file
+    hour: u64,
+}
+
+/// Writes WebSocket messages to rotating, gzip-compressed JSONL files
+///
+/// Every line is a JSON-encoded [`RawMessage`]: the message text as received
+/// plus its local receive time in Unix milliseconds. Files are named
+/// `{prefix}-{YYYYMMDDTHHMMSSZ}-{seq}.jsonl.gz` after the receive time of
+/// their first message, so sorting the names orders the files in time.
+/// Existing files are never overwritten.
+///
+/// Only [`RawMessage`]s are recorded, so record the streams of
+/// [`MarketWsClient::subscribe_raw`](crate::websocket::MarketWsClient::subscribe_raw)
+/// and [`UserWsClient::subscribe_raw`](crate::websocket::UserWsClient::subscribe_raw)
+/// instead of the parsed `WsEvent` and `UserWsEvent` streams of `subscribe`.
+/// Re-encoding parsed events would lose the message text and its receive time.
+///
+/// Files are finished when they are rotated, on [`finish`](Self::finish) and
+/// when the recorder is dropped. Call [`flush`](Self::flush) to make the
+/// messages written so far readable while the file is still open.
+///
+/// [`record`](Self::record), [`flush`](Self::flush) and
+/// [`finish`](Self::finish) write to disk on the calling thread.
+/// [`record_stream`](Self::record_stream) does its writes on Tokio's blocking
+/// thread pool, so it can run on the async runtime directly.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::recording::{Recorder, Rotation};
+/// use polymarket_rs::websocket::MarketWsClient;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let stream = MarketWsClient::new()
+///         .subscribe_raw(vec!["token_id".to_string()])
+///         .await?;
+///
+///     let mut recorder = Recorder::new("recordings", "market").with_rotation(Rotation::Hourly);
+///     recorder.record_stream(stream).await?;
+///     Ok(())
+/// }
+/// ```
+pub struct Recorder {
+    dir: PathBuf,
+    writer: Arc<Mutex<Writer>>,
+}
+
+impl Recorder {
+    /// Create a recorder writing to `dir`, rotating hourly
+    ///
+    /// The directory is created when the first message is written.
+    pub fn new(dir: impl Into<PathBuf>, prefix: impl Into<String>) -> Self {
+        let dir = dir.into();
+        Self {
+            writer: Arc::new(Mutex::new(Writer {
+                dir: dir.clone(),
+                prefix: prefix.into(),
+                rotation: Rotation::Hourly,
+                current: None,
+                seq: 0,
+                files: Vec::new(),
+            })),
+            dir,
+        }
+    }
+
+    pub fn with_rotation(self, rotation: Rotation) -> Self {
+        self.lock().rotation = rotation;
+        self
+    }
+
+    /// Directory the files are written to
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Files written by this recorder, oldest first
+    pub fn files(&self) -> Vec<PathBuf> {
+        self.lock().files.clone()
+    }
+
+    /// Write one message
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message cannot be serialized or written.
+    pub fn record(&mut self, message: &RawMessage) -> Result<()> {
+        self.lock().write(message)
+    }
+
+    /// Write every item of a stream until it ends
+    ///
+    /// Items that are ready together are written as one batch on the blocking
+    /// thread pool. The current file is flushed when the stream ends, and the
+    /// first error produced by the stream is returned after flushing.
+    pub async fn record_stream<S>(&mut self, stream: S) -> Result<()>
+    where
+        S: Stream<Item = Result<RawMessage>>,
+    {
+        let stream = stream.ready_chunks(WRITE_BATCH);
+        futures_util::pin_mut!(stream);
+        while let Some(batch) = stream.next().await {
+            let mut messages = Vec::with_capacity(batch.len());
+            let mut error = None;
+            for item in batch {
+                match item {
+                    Ok(message) => messages.push(message),
+                    Err(e) => {
+                        error = Some(e);
+                        break;
+                    }
+                }
+            }
+
+            self.write_blocking(move |writer| {
+                messages
+                    .iter()
+                    .try_for_each(|message| writer.write(message))
+            })
+            .await?;
+            if let Some(e) = error {
+                self.write_blocking(Writer::flush).await?;
+                return Err(e);
+            }
+        }
+        self.write_blocking(Writer::flush).await
+    }
+
+    /// Flush buffered messages so they are readable before the file is finished
+    pub fn flush(&mut self) -> Result<()> {
+        self.lock().flush()
+    }
+
+    /// Finish the current file; the next message starts a new one
+    pub fn finish(&mut self) -> Result<()> {
+        self.lock().finish()
+    }
+
+    async fn write_blocking<F>(&self, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut Writer) -> Result<()> + Send + 'static,
+    {
+        let writer = Arc::clone(&self.writer);
+        tokio::task::spawn_blocking(move || {
+            f(&mut writer.lock().unwrap_or_else(|e| e.into_inner()))
+        })
+        .await
+        .map_err(std::io::Error::from)?
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Writer> {
+        self.writer.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// File state of a [`Recorder`], shared with its blocking writes
+struct Writer {
+    dir: PathBuf,
+    prefix: String,
+    rotation: Rotation,
+    current: Option<OpenFile>,
+    seq: u32,
+    files: Vec<PathBuf>,
+}
+
+impl Writer {
+    fn write(&mut self, message: &RawMessage) -> Result<()> {
+        let mut line = serde_json::to_vec(message)?;
+        line.push(b'\n');
+
+        let hour = message.received_at / MILLIS_PER_HOUR;
+        let rotate = match (&self.current, self.rotation) {
+            (None, _) => true,
+            (Some(file), Rotation::Hourly) => file.hour != hour,
+            (Some(file), Rotation::Size(max)) => file.bytes >= max,
+        };
+        if rotate {
+            self.finish()?;
+            self.open(message.received_at)?;
+        }
+
+        if let Some(file) = self.current.as_mut() {
+            file.encoder.write_all(&line)?;
+            file.bytes += line.len() as u64;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        if let Some(file) = self.current.as_mut() {
+            file.encoder.flush()?;
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        if let Some(file) = self.current.take() {
+            file.encoder.finish()?.flush()?;
+        }
+        Ok(())
+    }
+
+    fn open(&mut self, received_at: u64) -> Result<()> {
+        fs::create_dir_all(&self.dir)?;
+
+        let started = DateTime::from_timestamp_millis(received_at as i64)
+            .unwrap_or_default()
+            .format("%Y%m%dT%H%M%SZ");
+
+        // Never overwrite a file, e.g. one left by an earlier run
+        let (path, file) = loop {
+            let path = self.dir.join(format!(
+                "{}-{}-{:04}.jsonl.gz",
+                self.prefix, started, self.seq
+            ));
+            self.seq += 1;
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(file) => break (path, file),
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e.into()),
+            }
+        };
+
+        log::debug!("Recording to {}", path.display());
+        self.current = Some(OpenFile {
+            encoder: GzEncoder::new(BufWriter::new(file), Compression::default()),
+            bytes: 0,
+            hour: received_at / MILLIS_PER_HOUR,
+        });
+        self.files.push(path);
+        Ok(())
+    }
+}
+
+// Finished when the last handle goes, which may be a blocking write that
+// outlives a dropped `record_stream`
+impl Drop for Writer {
+    fn drop(&mut self) {
+        if let Err(e) = self.finish() {
+            log::warn!("Failed to finish recording file: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::GzDecoder;
+    use std::io::{BufRead, BufReader};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "polymarket-rs-recorder-{}-{}",
+            std::process::id(),
+            name
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn read_lines(path: &Path) -> Vec<RawMessage> {
+        let reader = BufReader::new(GzDecoder::new(File::open(path).unwrap()));
+        reader
+            .lines()
+            .map(|line| serde_json::from_str(&line.unwrap()).unwrap())
+            .collect()
+    }
+
+    fn read_lines_partial(path: &Path) -> Vec<String> {
+        let mut text = String::new();
+        let _ = std::io::Read::read_to_string(
+            &mut GzDecoder::new(File::open(path).unwrap()),
+            &mut text,
+        );
+        text.lines()
+            .map(|line| serde_json::from_str::<RawMessage>(line).unwrap().text)
+            .collect()
+    }
+
+    #[test]
+    fn test_rotates_by_size() {
+        let dir = temp_dir("size");
+        let mut recorder = Recorder::new(&dir, "market").with_rotation(Rotation::Size(1));
+
+        for i in 0..3 {
+            recorder
+                .record(&RawMessage::new(i, format!("{{\"n\":{}}}", i)))
+                .unwrap();
+        }
+        recorder.finish().unwrap();
+
+        let files = recorder.files().to_vec();
+        assert_eq!(files.len(), 3);
+        assert_eq!(read_lines(&files[2]), vec![RawMessage::new(2, "{\"n\":2}")]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_rotates_hourly_and_never_overwrites() {
+        let dir = temp_dir("hourly");
+        let mut recorder = Recorder::new(&dir, "market");
+        recorder.record(&RawMessage::new(1_000, "a")).unwrap();
+        recorder.record(&RawMessage::new(2_000, "b")).unwrap();
+        recorder
+            .record(&RawMessage::new(MILLIS_PER_HOUR, "c"))
+            .unwrap();
+        drop(recorder);
+
+        // A second run in the same second picks a fresh name
+        let mut again = Recorder::new(&dir, "market");
+        again.record(&RawMessage::new(1_000, "d")).unwrap();
+        drop(again);
+
+        let mut files: Vec<_> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|e| e.unwrap().path())
+            .collect();
+        files.sort();
+        assert_eq!(files.len(), 3);
+
+        let first: Vec<_> = read_lines(&files[0]).into_iter().map(|m| m.text).collect();
+        assert_eq!(first, vec!["a", "b"]);
+        assert!(files[0]
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .starts_with("market-19700101T000001Z-"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_record_stream_flushes_before_error() {
+        let dir = temp_dir("stream");
+        let mut recorder = Recorder::new(&dir, "market");
+
+        let items = vec![
+            Ok(RawMessage::new(1_000, "a")),
+            Ok(RawMessage::new(2_000, "b")),
+            Err(crate::error::Error::WebSocket("closed".to_string())),
+            Ok(RawMessage::new(3_000, "c")),
+        ];
+        let result = recorder
+            .record_stream(futures_util::stream::iter(items))
+            .await;
+        assert!(result.is_err());
+
+        // Readable before the file is finished
+        let files = recorder.files();
+        let lines: Vec<_> = read_lines_partial(&files[0]);
+        assert_eq!(lines, vec!["a", "b"]);
+
+        drop(recorder);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,319 @@
+use futures_util::{stream, Stream, StreamExt};
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::{sleep_until, Instant};
+
+use super::reader::{recording_files, RecordingReader};
+use crate::error::{Error, Result};
+use crate::types::{UserWsEvent, WsEvent};
+use crate::websocket::RawMessage;
+
+/// Number of messages read ahead of the replay position
+const READ_AHEAD: usize = 1024;
+
+/// How fast a [`Replay`] emits messages
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Pacing {
+    /// Keep the original gaps between messages
+    RealTime,
+    /// Divide the original gaps by a factor (e.g. `10.0` for ten times faster)
+    ///
+    /// The factor must be positive; [`Replay::with_pacing`] rejects zero,
+    /// negative and NaN factors.
+    Accelerated(f64),
+    /// Emit messages without waiting
+    AsFastAsPossible,
+}
+
+/// Clock that follows the receive time of replayed messages
+///
+/// The clock reads the receive time of the most recently emitted message, or
+/// zero before the first one. Clones share the same time.
+#[derive(Debug, Clone, Default)]
+pub struct SimulatedClock {
+    now: Arc<AtomicU64>,
+}
+
+impl SimulatedClock {
+    /// Current simulated time, Unix milliseconds
+    pub fn now_millis(&self) -> u64 {
+        self.now.load(Ordering::Acquire)
+    }
+
+    /// Current simulated time, Unix seconds
+    pub fn now_secs(&self) -> u64 {
+        self.now_millis() / 1000
+    }
+
+    fn advance_to(&self, millis: u64) {
+        self.now.fetch_max(millis, Ordering::AcqRel);
+    }
+}
+
+/// Re-emits recorded WebSocket sessions as streams
+///
+/// [`stream`](Self::stream) returns the same stream type as
+/// [`MarketWsClient::subscribe`](crate::websocket::MarketWsClient::subscribe), so book
+/// engines, candle aggregators and strategies can run unchanged against a
+/// recording. The [`SimulatedClock`] from [`clock`](Self::clock) advances to the
+/// receive time of each message as it is emitted.
+///
+/// Files are read on a blocking thread, so the streams must be polled inside a
+/// Tokio runtime.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::book::BookManager;
+/// use polymarket_rs::recording::{Pacing, Replay};
+/// use polymarket_rs::websocket::MarketWsClient;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let replay = Replay::from_dir("recordings", "market")?.with_pacing(Pacing::Accelerated(60.0))?;
+///     let clock = replay.clock();
+///
+///     let manager = BookManager::new(MarketWsClient::new(), vec!["token_id".to_string()]);
+///     manager.run_stream(replay.stream()).await?;
+///     println!("replayed until {}", clock.now_millis());
+///     Ok(())
+/// }
+/// ```
+pub struct Replay {
+    files: Vec<PathBuf>,
+    pacing: Pacing,
+    clock: SimulatedClock,
+}
+
+impl Replay {
+    /// Replay the given files in order
+    pub fn new(files: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+        Self {
+            files: files.into_iter().map(Into::into).collect(),
+            pacing: Pacing::AsFastAsPossible,
+            clock: SimulatedClock::default(),
+        }
+    }
+
+    /// Replay every recording with the given prefix in `dir`, oldest first
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be listed.
+    pub fn from_dir(dir: impl AsRef<Path>, prefix: &str) -> Result<Self> {
+        Ok(Self::new(recording_files(dir.as_ref(), prefix)?))
+    }
+
+    /// Set the pacing (defaults to [`Pacing::AsFastAsPossible`])
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if the factor of
+    /// [`Pacing::Accelerated`] is not positive.
+    pub fn with_pacing(mut self, pacing: Pacing) -> Result<Self> {
+        if let Pacing::Accelerated(factor) = pacing {
+            if factor.is_nan() || factor <= 0.0 {
+                return Err(Error::InvalidParameter(format!(
+                    "Replay speed-up factor {} is not positive",
+                    factor
+                )));
+            }
+        }
+        self.pacing = pacing;
+        Ok(self)
+    }
+
+    /// Clock that follows the replay
+    pub fn clock(&self) -> SimulatedClock {
+        self.clock.clone()
+    }
+
+    /// Replay a market channel recording as [`WsEvent`]s
+    pub fn stream(self) -> Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>> {
+        Box::pin(self.raw_stream().flat_map(|msg| {
+            stream::iter(match msg {
+                Ok(msg) => msg.market_events(),
+                Err(e) => vec![Err(e)],
+            })
+        }))
+    }
+
+    /// Replay a user channel recording as [`UserWsEvent`]s
+    pub fn user_stream(self) -> Pin<Box<dyn Stream<Item = Result<UserWsEvent>> + Send>> {
+        Box::pin(self.raw_stream().flat_map(|msg| {
+            stream::iter(match msg {
+                Ok(msg) => msg.user_events(),
+                Err(e) => vec![Err(e)],
+            })
+        }))
+    }
+
+    /// Replay the recorded messages without parsing them
+    pub fn raw_stream(self) -> Pin<Box<dyn Stream<Item = Result<RawMessage>> + Send>> {
+        let (tx, rx) = mpsc::channel(READ_AHEAD);
+        let files = self.files;
+        let mut reader = Some(move || {
+            for msg in RecordingReader::new(files) {
+                if tx.blocking_send(msg).is_err() {
+                    // Replay dropped
+                    break;
+                }
+            }
+        });
+
+        let pacer = Pacer::new(self.pacing);
+        let clock = self.clock;
+
+        Box::pin(stream::unfold(
+            (rx, pacer, clock),
+            move |(mut rx, mut pacer, clock)| {
+                // Start reading on first poll, when a runtime is guaranteed
+                if let Some(read) = reader.take() {
+                    tokio::task::spawn_blocking(read);
+                }
+                async move {
+                    let msg = rx.recv().await?;
+                    if let Ok(msg) = &msg {
+                        pacer.wait_for(msg.received_at).await;
+                        clock.advance_to(msg.received_at);
+                    }
+                    Some((msg, (rx, pacer, clock)))
+                }
+            },
+        ))
+    }
+}
+
+/// Maps recorded receive times onto the local clock
+struct Pacer {
+    speed: Option<f64>,
+    origin: Option<(u64, Instant)>,
+}
+
+impl Pacer {
+    fn new(pacing: Pacing) -> Self {
+        let speed = match pacing {
+            Pacing::RealTime => Some(1.0),
+            Pacing::Accelerated(factor) => Some(factor),
+            Pacing::AsFastAsPossible => None,
+        };
+        Self {
+            speed,
+            origin: None,
+        }
+    }
+
+    async fn wait_for(&mut self, received_at: u64) {
+        let Some(speed) = self.speed else {
+            return;
+        };
+        let (start_ts, start) = *self
+            .origin
+            .get_or_insert_with(|| (received_at, Instant::now()));
+
+        let gap = Duration::from_millis(received_at.saturating_sub(start_ts));
+        sleep_until(start + gap.div_f64(speed)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recording::Recorder;
+    use std::fs;
+
+    fn book(asset_id: &str) -> String {
+        format!(
+            r#"{{"event_type":"book","market":"m","asset_id":"{}","timestamp":"1","hash":"h","bids":[],"asks":[]}}"#,
+            asset_id
+        )
+    }
+
+    fn record(name: &str, messages: &[RawMessage]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "polymarket-rs-replay-{}-{}",
+            std::process::id(),
+            name
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        let mut recorder =
+            Recorder::new(&dir, "market").with_rotation(crate::recording::Rotation::Size(1));
+        for msg in messages {
+            recorder.record(msg).unwrap();
+        }
+        dir
+    }
+
+    #[tokio::test]
+    async fn test_replay_as_fast_as_possible() {
+        let dir = record(
+            "fast",
+            &[
+                RawMessage::new(1_000, book("1")),
+                RawMessage::new(2_000, format!("[{},{}]", book("2"), book("3"))),
+            ],
+        );
+
+        let replay = Replay::from_dir(&dir, "market").unwrap();
+        let clock = replay.clock();
+        let events: Vec<_> = replay.stream().collect().await;
+
+        let ids: Vec<_> = events
+            .iter()
+            .map(|e| match e {
+                Ok(WsEvent::Book(b)) => b.asset_id.clone(),
+                other => panic!("unexpected {:?}", other),
+            })
+            .collect();
+        assert_eq!(ids, vec!["1", "2", "3"]);
+        assert_eq!(clock.now_millis(), 2_000);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_replay_accelerated_pacing() {
+        let dir = record(
+            "paced",
+            &[
+                RawMessage::new(10_000, book("1")),
+                RawMessage::new(30_000, book("2")),
+            ],
+        );
+
+        let replay = Replay::from_dir(&dir, "market")
+            .unwrap()
+            .with_pacing(Pacing::Accelerated(10.0))
+            .unwrap();
+        let clock = replay.clock();
+        let mut stream = replay.stream();
+
+        stream.next().await.unwrap().unwrap();
+        let start = Instant::now();
+        assert_eq!(clock.now_secs(), 10);
+
+        stream.next().await.unwrap().unwrap();
+        assert!(start.elapsed() >= Duration::from_secs(2));
+        assert_eq!(clock.now_secs(), 30);
+        assert!(stream.next().await.is_none());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_rejects_non_positive_speed_up() {
+        for factor in [0.0, -2.0, f64::NAN] {
+            assert!(matches!(
+                Replay::new(Vec::<PathBuf>::new()).with_pacing(Pacing::Accelerated(factor)),
+                Err(Error::InvalidParameter(_))
+            ));
+        }
+        assert!(Replay::new(Vec::<PathBuf>::new())
+            .with_pacing(Pacing::RealTime)
+            .is_ok());
+    }
+}
@@ -1,7 +1,9 @@
 mod data_params;
 mod gamma_params;
 mod pagination;
+mod price_history_params;
 
 pub use data_params::{ActivityQueryParams, ActivitySortBy, SortDirection, TradeQueryParams};
 pub use gamma_params::GammaMarketParams;
 pub use pagination::{PaginationParams, END_CURSOR, INITIAL_CURSOR};
+pub use price_history_params::{PriceHistoryInterval, PriceHistoryParams};
@@ -0,0 +1,226 @@
+use std::time::Duration;
+
+use crate::error::{Error, Result};
+
+/// Time span of a price history query, relative to now
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriceHistoryInterval {
+    OneHour,
+    SixHours,
+    OneDay,
+    OneWeek,
+    /// `1m`, which the server reads as one month
+    OneMonth,
+    Max,
+}
+
+impl PriceHistoryInterval {
+    pub fn as_str(&self) -> &str {
+        match self {
+            PriceHistoryInterval::OneHour => "1h",
+            PriceHistoryInterval::SixHours => "6h",
+            PriceHistoryInterval::OneDay => "1d",
+            PriceHistoryInterval::OneWeek => "1w",
+            PriceHistoryInterval::OneMonth => "1m",
+            PriceHistoryInterval::Max => "max",
+        }
+    }
+
+    /// Length of the interval, or `None` for [`PriceHistoryInterval::Max`]
+    ///
+    /// A month is taken as 30 days.
+    pub fn duration(&self) -> Option<Duration> {
+        let secs = match self {
+            PriceHistoryInterval::OneHour => 60 * 60,
+            PriceHistoryInterval::SixHours => 6 * 60 * 60,
+            PriceHistoryInterval::OneDay => 24 * 60 * 60,
+            PriceHistoryInterval::OneWeek => 7 * 24 * 60 * 60,
+            PriceHistoryInterval::OneMonth => 30 * 24 * 60 * 60,
+            PriceHistoryInterval::Max => return None,
+        };
+        Some(Duration::from_secs(secs))
+    }
+}
+
+/// Query parameters for the price history endpoint
+///
+/// A query covers either an `interval` ending now or an explicit
+/// `startTs`/`endTs` range; the server rejects queries that combine the two.
+/// `fidelity` is the resolution of the returned points in minutes and is left
+/// to the server default when unset.
+///
+/// # Example
+///
+/// ```
+/// use polymarket_rs::request::{PriceHistoryInterval, PriceHistoryParams};
+///
+/// let last_day = PriceHistoryParams::new()
+///     .with_interval(PriceHistoryInterval::OneDay)
+///     .with_fidelity(5);
+/// assert!(last_day.validate().is_ok());
+///
+/// let range = PriceHistoryParams::new().with_range(1_700_000_000, 1_700_086_400);
+/// assert!(range.validate().is_ok());
+///
+/// let both = range.with_interval(PriceHistoryInterval::OneDay);
+/// assert!(both.validate().is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PriceHistoryParams {
+    pub interval: Option<PriceHistoryInterval>,
+    pub start_ts: Option<u64>,
+    pub end_ts: Option<u64>,
+    pub fidelity: Option<u32>,
+}
+
+impl PriceHistoryParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_interval(mut self, interval: PriceHistoryInterval) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Start of the range, Unix seconds
+    pub fn with_start_ts(mut self, start_ts: u64) -> Self {
+        self.start_ts = Some(start_ts);
+        self
+    }
+
+    /// End of the range, Unix seconds
+    pub fn with_end_ts(mut self, end_ts: u64) -> Self {
+        self.end_ts = Some(end_ts);
+        self
+    }
+
+    /// Set both ends of the range, Unix seconds
+    pub fn with_range(self, start_ts: u64, end_ts: u64) -> Self {
+        self.with_start_ts(start_ts).with_end_ts(end_ts)
+    }
+
+    /// Resolution of the returned points, in minutes
+    pub fn with_fidelity(mut self, fidelity: u32) -> Self {
+        self.fidelity = Some(fidelity);
+        self
+    }
+
+    /// Check the parameters against the combinations the server accepts
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if:
+    /// - `interval` is combined with `startTs` or `endTs`
+    /// - neither `interval` nor `startTs` is set
+    /// - `startTs` is not before `endTs`
+    /// - `fidelity` is zero
+    pub fn validate(&self) -> Result<()> {
+        if self.interval.is_some() && (self.start_ts.is_some() || self.end_ts.is_some()) {
+            return Err(Error::InvalidParameter(
+                "interval cannot be combined with startTs/endTs".to_string(),
+            ));
+        }
+        if self.interval.is_none() && self.start_ts.is_none() {
+            return Err(Error::InvalidParameter(
+                "price history requires an interval or a startTs".to_string(),
+            ));
+        }
+        if let (Some(start), Some(end)) = (self.start_ts, self.end_ts) {
+            if start >= end {
+                return Err(Error::InvalidParameter(format!(
+                    "startTs {} must be before endTs {}",
+                    start, end
+                )));
+            }
+        }
+        if self.fidelity == Some(0) {
+            return Err(Error::InvalidParameter(
+                "fidelity must be at least one minute".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn to_query_string(&self) -> String {
+        let mut params = Vec::new();
+
+        if let Some(interval) = self.interval {
+            params.push(format!("interval={}", interval.as_str()));
+        }
+        if let Some(start_ts) = self.start_ts {
+            params.push(format!("startTs={}", start_ts));
+        }
+        if let Some(end_ts) = self.end_ts {
+            params.push(format!("endTs={}", end_ts));
+        }
+        if let Some(fidelity) = self.fidelity {
+            params.push(format!("fidelity={}", fidelity));
+        }
+
+        if params.is_empty() {
+            String::new()
+        } else {
+            format!("&{}", params.join("&"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interval_values() {
+        let hour = 60 * 60;
+        let cases = [
+            (PriceHistoryInterval::OneHour, "1h", Some(hour)),
+            (PriceHistoryInterval::SixHours, "6h", Some(6 * hour)),
+            (PriceHistoryInterval::OneDay, "1d", Some(24 * hour)),
+            (PriceHistoryInterval::OneWeek, "1w", Some(7 * 24 * hour)),
+            (PriceHistoryInterval::OneMonth, "1m", Some(30 * 24 * hour)),
+            (PriceHistoryInterval::Max, "max", None),
+        ];
+        for (interval, value, secs) in cases {
+            assert_eq!(interval.as_str(), value);
+            assert_eq!(interval.duration(), secs.map(Duration::from_secs));
+        }
+    }
+
+    #[test]
+    fn test_query_string_omits_unset_fidelity() {
+        let params = PriceHistoryParams::new().with_interval(PriceHistoryInterval::OneWeek);
+        assert_eq!(params.to_query_string(), "&interval=1w");
+
+        let params = PriceHistoryParams::new()
+            .with_range(100, 200)
+            .with_fidelity(60);
+        assert_eq!(
+            params.to_query_string(),
+            "&startTs=100&endTs=200&fidelity=60"
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_combinations() {
+        assert!(PriceHistoryParams::new().validate().is_err());
+        assert!(PriceHistoryParams::new()
+            .with_start_ts(100)
+            .validate()
+            .is_ok());
+        assert!(PriceHistoryParams::new()
+            .with_range(200, 100)
+            .validate()
+            .is_err());
+        assert!(PriceHistoryParams::new()
+            .with_interval(PriceHistoryInterval::Max)
+            .with_end_ts(100)
+            .validate()
+            .is_err());
+        assert!(PriceHistoryParams::new()
+            .with_interval(PriceHistoryInterval::Max)
+            .with_fidelity(0)
+            .validate()
+            .is_err());
+    }
+}
@@ -1,6 +1,9 @@
-use crate::error::Result;
-use alloy_primitives::{hex::encode_prefixed, Address, U256};
+use crate::config::get_contract_config;
+use crate::error::{Error, Result};
+use crate::types::{SignatureType, SignedOrderRequest};
+use alloy_primitives::{hex::encode_prefixed, keccak256, Address, PrimitiveSignature, B256, U256};
 use alloy_sol_types::{eip712_domain, sol, SolStruct};
+use std::str::FromStr;
 
 // EIP-712 struct for CLOB authentication
 sol! {
@@ -41,24 +44,9 @@ pub fn sign_clob_auth_message<T>(
     chain_id: u64,
 ) -> Result<String>
 where
-    T: alloy_signer::Signer + alloy_signer::SignerSync,
+    T: alloy_signer::Signer + alloy_signer::SignerSync + ?Sized,
 {
-    let message = "This message attests that I control the given wallet".to_owned();
-
-    let auth_struct = ClobAuth {
-        address: signer.address(),
-        timestamp,
-        nonce,
-        message,
-    };
-
-    let domain = eip712_domain!(
-        name: "ClobAuthDomain",
-        version: "1",
-        chain_id: chain_id,
-    );
-
-    let hash = auth_struct.eip712_signing_hash(&domain);
+    let hash = clob_auth_signing_hash(signer.address(), timestamp, nonce, chain_id);
     let signature = signer
         .sign_hash_sync(&hash)
         .map_err(|e| crate::error::Error::Signing(format!("Failed to sign auth message: {}", e)))?;
@@ -77,8 +65,201 @@ pub fn sign_order_message<T>(
     verifying_contract: Address,
 ) -> Result<String>
 where
-    T: alloy_signer::Signer + alloy_signer::SignerSync,
+    T: alloy_signer::Signer + alloy_signer::SignerSync + ?Sized,
+{
+    let hash = order_signing_hash(&order, chain_id, verifying_contract);
+    let signature = signer
+        .sign_hash_sync(&hash)
+        .map_err(|e| crate::error::Error::Signing(format!("Failed to sign order: {}", e)))?;
+
+    Ok(encode_prefixed(signature.as_bytes()))
+}
+
+/// Signs a CLOB authentication message using EIP-712, asynchronously
+///
+/// Same as [`sign_clob_auth_message`], for signers that only implement
+/// [`Signer`](alloy_signer::Signer), such as [`RemoteSigner`](super::RemoteSigner).
+pub async fn sign_clob_auth_message_async<T>(
+    signer: &T,
+    timestamp: String,
+    nonce: U256,
+    chain_id: u64,
+) -> Result<String>
+where
+    T: alloy_signer::Signer + Send + Sync + ?Sized,
+{
+    let hash = clob_auth_signing_hash(signer.address(), timestamp, nonce, chain_id);
+    let signature = signer
+        .sign_hash(&hash)
+        .await
+        .map_err(|e| crate::error::Error::Signing(format!("Failed to sign auth message: {}", e)))?;
+
+    Ok(encode_prefixed(signature.as_bytes()))
+}
+
+/// Signs an order using EIP-712, asynchronously
+///
+/// Same as [`sign_order_message`], for signers that only implement
+/// [`Signer`](alloy_signer::Signer), such as [`RemoteSigner`](super::RemoteSigner).
+pub async fn sign_order_message_async<T>(
+    signer: &T,
+    order: Order,
+    chain_id: u64,
+    verifying_contract: Address,
+) -> Result<String>
+where
+    T: alloy_signer::Signer + Send + Sync + ?Sized,
 {
+    let hash = order_signing_hash(&order, chain_id, verifying_contract);
+    let signature = signer
+        .sign_hash(&hash)
+        .await
+        .map_err(|e| crate::error::Error::Signing(format!("Failed to sign order: {}", e)))?;
+
+    Ok(encode_prefixed(signature.as_bytes()))
+}
+
+/// Verifies the signature of a signed order offline
+///
+/// Rebuilds the EIP-712 order and domain for the exchange selected by
+/// `chain_id` and `neg_risk`, recovers the address that signed it and checks
+/// it against the order's `signer`. Also checks that `maker` fits the
+/// signature type: an EOA order is made by its signer, while proxy and Gnosis
+/// Safe orders are made by a wallet other than the signer.
+///
+/// # Arguments
+///
+/// * `order` - The signed order, as sent to `POST /order`
+/// * `chain_id` - Chain the order was signed for
+/// * `neg_risk` - Whether the order targets the neg risk exchange
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidOrder`] if a field can't be decoded or `maker`
+/// doesn't fit the signature type, and [`Error::Signing`] if the signature is
+/// malformed or wasn't made by `signer` over this order.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::signing::verify_order_signature;
+/// use polymarket_rs::types::SignedOrderRequest;
+///
+/// # fn run(order: &SignedOrderRequest) -> polymarket_rs::Result<()> {
+/// let signer = verify_order_signature(order, 137, false)?;
+/// println!("order signed by {}", signer);
+/// # Ok(())
+/// # }
+/// ```
+pub fn verify_order_signature(
+    order: &SignedOrderRequest,
+    chain_id: u64,
+    neg_risk: bool,
+) -> Result<Address> {
+    let message = order_from_request(order)?;
+
+    let signature_type = SignatureType::from_u8(message.signatureType).ok_or_else(|| {
+        Error::InvalidOrder(format!("Invalid signature type {}", message.signatureType))
+    })?;
+    match signature_type {
+        SignatureType::Eoa if message.maker != message.signer => {
+            return Err(Error::InvalidOrder(format!(
+                "EOA order maker {} is not the signer {}",
+                message.maker, message.signer
+            )));
+        }
+        SignatureType::PolyProxy | SignatureType::PolyGnosisSafe
+            if message.maker == message.signer =>
+        {
+            return Err(Error::InvalidOrder(format!(
+                "{:?} order maker {} is the signer instead of its wallet",
+                signature_type, message.maker
+            )));
+        }
+        _ => {}
+    }
+
+    let hash = order_hash(&message, chain_id, neg_risk)?;
+    let recovered = recover_signer(&order.signature, &hash)?;
+    if recovered != message.signer {
+        return Err(Error::Signing(format!(
+            "Order signed by {} instead of signer {}",
+            recovered, message.signer
+        )));
+    }
+
+    Ok(recovered)
+}
+
+/// Computes the EIP-712 hash of an order
+///
+/// This is the hash the CTF Exchange signs over and uses as the order ID, so
+/// it matches `PostOrderResponse.order_id`, `OrderEvent.id` and the
+/// `orderHash` of on-chain `OrderFilled` logs.
+///
+/// # Arguments
+///
+/// * `order` - The EIP-712 order struct
+/// * `chain_id` - Chain the order is signed for
+/// * `neg_risk` - Whether the order targets the neg risk exchange
+///
+/// # Errors
+///
+/// Returns an error if there is no exchange for `chain_id`.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::signing::{order_hash, Order};
+///
+/// # fn run(order: &Order) -> polymarket_rs::Result<()> {
+/// let hash = order_hash(order, 137, false)?;
+/// println!("order id {}", hash);
+/// # Ok(())
+/// # }
+/// ```
+pub fn order_hash(order: &Order, chain_id: u64, neg_risk: bool) -> Result<B256> {
+    let exchange = Address::from_str(&get_contract_config(chain_id, neg_risk)?.exchange)
+        .map_err(|e| Error::Config(format!("Invalid exchange address: {}", e)))?;
+    Ok(order_signing_hash(order, chain_id, exchange))
+}
+
+/// EIP-712 hash of the CLOB authentication message for `address`
+pub(crate) fn clob_auth_signing_hash(
+    address: Address,
+    timestamp: String,
+    nonce: U256,
+    chain_id: u64,
+) -> B256 {
+    let message = "This message attests that I control the given wallet".to_owned();
+
+    let auth_struct = ClobAuth {
+        address,
+        timestamp,
+        nonce,
+        message,
+    };
+
+    let domain = eip712_domain!(
+        name: "ClobAuthDomain",
+        version: "1",
+        chain_id: chain_id,
+    );
+
+    auth_struct.eip712_signing_hash(&domain)
+}
+
+/// EIP-712 hash of an order for the exchange at `verifying_contract`
+pub(crate) fn order_signing_hash(
+    order: &Order,
+    chain_id: u64,
+    verifying_contract: Address,
+) -> B256 {
+    order_signing_hash_with_separator(order, &order_domain_separator(chain_id, verifying_contract))
+}
+
+/// EIP-712 domain separator of the exchange at `verifying_contract`
+pub(crate) fn order_domain_separator(chain_id: u64, verifying_contract: Address) -> B256 {
     let domain = eip712_domain!(
         name: "Polymarket CTF Exchange",
         version: "1",
@@ -86,10 +267,205 @@ where
         verifying_contract: verifying_contract,
     );
 
-    let hash = order.eip712_signing_hash(&domain);
-    let signature = signer
-        .sign_hash_sync(&hash)
-        .map_err(|e| crate::error::Error::Signing(format!("Failed to sign order: {}", e)))?;
+    domain.separator()
+}
 
-    Ok(encode_prefixed(signature.as_bytes()))
+/// EIP-712 hash of an order, given its exchange's domain separator
+///
+/// Lets a batch of orders for the same exchange share one separator.
+pub(crate) fn order_signing_hash_with_separator(order: &Order, domain_separator: &B256) -> B256 {
+    let mut digest = [0u8; 66];
+    digest[..2].copy_from_slice(&[0x19, 0x01]);
+    digest[2..34].copy_from_slice(domain_separator.as_slice());
+    digest[34..].copy_from_slice(order.eip712_hash_struct().as_slice());
+    keccak256(digest)
+}
+
+/// Recover the address that produced a hex `signature` over `hash`
+pub(crate) fn recover_signer(signature: &str, hash: &B256) -> Result<Address> {
+    let signature = PrimitiveSignature::from_str(signature)
+        .map_err(|e| Error::Signing(format!("Invalid signature: {}", e)))?;
+    signature
+        .recover_address_from_prehash(hash)
+        .map_err(|e| Error::Signing(format!("Failed to recover signer: {}", e)))
+}
+
+/// Rebuild the EIP-712 order struct that was signed for a request
+pub(crate) fn order_from_request(order: &SignedOrderRequest) -> Result<Order> {
+    let address = |field: &str, value: &str| {
+        Address::from_str(value)
+            .map_err(|e| Error::InvalidOrder(format!("Invalid {} address: {}", field, e)))
+    };
+    let number = |field: &str, value: &str| {
+        U256::from_str_radix(value, 10)
+            .map_err(|e| Error::InvalidOrder(format!("Invalid {}: {}", field, e)))
+    };
+    let side = match order.side.as_str() {
+        "BUY" => 0,
+        "SELL" => 1,
+        other => return Err(Error::InvalidOrder(format!("Invalid order side {}", other))),
+    };
+
+    Ok(Order {
+        salt: U256::from(order.salt),
+        maker: address("maker", &order.maker)?,
+        signer: address("signer", &order.signer)?,
+        taker: address("taker", &order.taker)?,
+        tokenId: number("token_id", &order.token_id)?,
+        makerAmount: number("maker_amount", &order.maker_amount)?,
+        takerAmount: number("taker_amount", &order.taker_amount)?,
+        expiration: number("expiration", &order.expiration)?,
+        nonce: number("nonce", &order.nonce)?,
+        feeRateBps: number("fee_rate_bps", &order.fee_rate_bps)?,
+        side,
+        signatureType: order.signature_type,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orders::OrderBuilder;
+    use crate::types::{CreateOrderOptions, ExtraOrderArgs, OrderArgs, Side};
+    use alloy_signer_local::PrivateKeySigner;
+    use rust_decimal_macros::dec;
+
+    // Well-known development key, address 0xf39F...2266
+    const KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+    const ADDRESS: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+    const PROXY: &str = "0x70997970C51812dc3A010C7d01b50e0d17dc79C8";
+
+    fn signed_order(
+        sig_type: SignatureType,
+        funder: Option<Address>,
+        neg_risk: bool,
+    ) -> SignedOrderRequest {
+        let signer = PrivateKeySigner::from_str(KEY).unwrap();
+        let builder = OrderBuilder::new(signer, Some(sig_type), funder);
+        let args = OrderArgs::new("1234", dec!(0.45), dec!(10), Side::Buy);
+        let options = CreateOrderOptions::default()
+            .tick_size(dec!(0.01))
+            .neg_risk(neg_risk);
+        builder
+            .create_order(137, &args, 0, &ExtraOrderArgs::default(), options)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_order_hash_with_separator() {
+        let order = order_from_request(&signed_order(SignatureType::Eoa, None, false)).unwrap();
+        let exchange = Address::from_str(PROXY).unwrap();
+        let domain = eip712_domain!(
+            name: "Polymarket CTF Exchange",
+            version: "1",
+            chain_id: 137,
+            verifying_contract: exchange,
+        );
+        assert_eq!(
+            order_signing_hash(&order, 137, exchange),
+            order.eip712_signing_hash(&domain)
+        );
+    }
+
+    #[test]
+    fn test_order_hash_and_signature_known_answer() {
+        // Expected values computed independently from the EIP-712 spec, with
+        // an RFC 6979 deterministic signature
+        let address = Address::from_str(ADDRESS).unwrap();
+        let order = Order {
+            salt: U256::from(479249096354u64),
+            maker: address,
+            signer: address,
+            taker: Address::ZERO,
+            tokenId: U256::from(1234),
+            makerAmount: U256::from(4_500_000),
+            takerAmount: U256::from(10_000_000),
+            expiration: U256::ZERO,
+            nonce: U256::ZERO,
+            feeRateBps: U256::ZERO,
+            side: 0,
+            signatureType: 0,
+        };
+
+        let hash = order_hash(&order, 137, false).unwrap();
+        assert_eq!(
+            hash.to_string(),
+            "0x62a0f46f1e3112457e60dee421602e77ffd9cafe205b712c6ff215e4606c2222"
+        );
+
+        let signer = PrivateKeySigner::from_str(KEY).unwrap();
+        let exchange =
+            Address::from_str(&get_contract_config(137, false).unwrap().exchange).unwrap();
+        let signature = sign_order_message(&signer, order, 137, exchange).unwrap();
+        assert_eq!(
+            signature,
+            "0x9590b62c663dd021cbd1002941d302e8f38590675f9865764aabc2494018fb0e\
+             068ecfeae77bebe7b3f1c629c30517b631234b3a1875f723230ee50cfb8584a91c"
+        );
+        assert_eq!(recover_signer(&signature, &hash).unwrap(), address);
+    }
+
+    #[test]
+    fn test_order_hash() {
+        let order = signed_order(SignatureType::Eoa, None, false);
+        let hash = order.order_hash(137, false).unwrap();
+        assert_eq!(hash.as_str().len(), 66);
+        assert!(hash.as_str().starts_with("0x"));
+
+        // The hash depends on the exchange and on every signed field
+        assert_ne!(order.order_hash(137, true).unwrap(), hash);
+        let mut other = order.clone();
+        other.salt += 1;
+        assert_ne!(other.order_hash(137, false).unwrap(), hash);
+    }
+
+    #[test]
+    fn test_verify_eoa_order() {
+        let order = signed_order(SignatureType::Eoa, None, false);
+        let signer = verify_order_signature(&order, 137, false).unwrap();
+        assert_eq!(signer, Address::from_str(ADDRESS).unwrap());
+
+        // Signed for the standard exchange, so the neg risk domain doesn't match
+        assert!(matches!(
+            verify_order_signature(&order, 137, true),
+            Err(Error::Signing(_))
+        ));
+        assert!(matches!(
+            verify_order_signature(&order, 80002, false),
+            Err(Error::Signing(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_order() {
+        let mut order = signed_order(SignatureType::Eoa, None, true);
+        assert!(verify_order_signature(&order, 137, true).is_ok());
+
+        order.maker_amount = "9000000".to_string();
+        assert!(matches!(
+            verify_order_signature(&order, 137, true),
+            Err(Error::Signing(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_maker_matches_signature_type() {
+        let proxy = Address::from_str(PROXY).unwrap();
+        let order = signed_order(SignatureType::PolyProxy, Some(proxy), false);
+        assert!(verify_order_signature(&order, 137, false).is_ok());
+
+        // Proxy orders must be made by the proxy, not the signer
+        let order = signed_order(SignatureType::PolyGnosisSafe, None, false);
+        assert!(matches!(
+            verify_order_signature(&order, 137, false),
+            Err(Error::InvalidOrder(_))
+        ));
+
+        // EOA orders must be made by the signer
+        let order = signed_order(SignatureType::Eoa, Some(proxy), false);
+        assert!(matches!(
+            verify_order_signature(&order, 137, false),
+            Err(Error::InvalidOrder(_))
+        ));
+    }
 }
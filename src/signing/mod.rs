@@ -1,5 +1,17 @@
 mod eip712;
+mod remote;
 mod signer;
 
-pub use eip712::{sign_clob_auth_message, sign_order_message, ClobAuth, Order};
-pub use signer::EthSigner;
+pub use eip712::{
+    order_hash, sign_clob_auth_message, sign_clob_auth_message_async, sign_order_message,
+    sign_order_message_async, verify_order_signature, ClobAuth, Order,
+};
+pub use remote::RemoteSigner;
+pub use signer::{AsyncEthSigner, EthSigner};
+
+#[cfg(any(test, feature = "test-server"))]
+pub(crate) use eip712::{clob_auth_signing_hash, recover_signer};
+pub(crate) use eip712::{
+    order_domain_separator, order_from_request, order_signing_hash,
+    order_signing_hash_with_separator,
+};
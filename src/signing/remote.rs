@@ -0,0 +1,124 @@
+use alloy_primitives::{Address, ChainId, PrimitiveSignature, B256};
+use alloy_signer::Signer;
+use async_trait::async_trait;
+use std::str::FromStr;
+
+/// Signer backed by a Web3Signer-compatible HTTP signing service
+///
+/// The private key stays in the service. Each signature is requested with
+/// `POST {url}/api/v1/eth1/sign/{address}` and a `{"data": "0x..."}` body
+/// holding the 32-byte EIP-712 digest, which the service must sign as is.
+/// The response body is the 65-byte signature in hex.
+///
+/// Signatures are checked to recover to `address` before they are returned,
+/// so a misconfigured service can't produce orders for the wrong wallet.
+///
+/// `RemoteSigner` only signs asynchronously; use it with
+/// [`OrderBuilder::new_async`](crate::orders::OrderBuilder::new_async),
+/// [`AuthenticatedClient`](crate::client::AuthenticatedClient) and
+/// [`TradingClient`](crate::client::TradingClient).
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::client::AuthenticatedClient;
+/// use polymarket_rs::signing::RemoteSigner;
+/// use polymarket_rs::Address;
+/// use std::str::FromStr;
+///
+/// # async fn example() -> polymarket_rs::Result<()> {
+/// let address = Address::from_str("0x0000000000000000000000000000000000000001").unwrap();
+/// let signer = RemoteSigner::new("http://127.0.0.1:9000", address);
+/// let client = AuthenticatedClient::new("https://clob.polymarket.com", signer, 137, None, None);
+/// let creds = client.create_or_derive_api_key().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    client: reqwest::Client,
+    url: String,
+    address: Address,
+    chain_id: Option<ChainId>,
+}
+
+impl RemoteSigner {
+    /// Create a signer for the key of `address` held by the service at `url`
+    ///
+    /// # Arguments
+    /// * `url` - Base URL of the signing service
+    /// * `address` - Address of the key to sign with
+    pub fn new(url: impl Into<String>, address: Address) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url: url.into().trim_end_matches('/').to_string(),
+            address,
+            chain_id: None,
+        }
+    }
+
+    /// Use a custom HTTP client, e.g. one with TLS client certificates or
+    /// default authentication headers for the signing service
+    pub fn with_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Base URL of the signing service
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+#[async_trait]
+impl Signer for RemoteSigner {
+    async fn sign_hash(&self, hash: &B256) -> alloy_signer::Result<PrimitiveSignature> {
+        let url = format!(
+            "{}/api/v1/eth1/sign/{}",
+            self.url,
+            self.address.to_checksum(None)
+        );
+        let response = self
+            .client
+            .post(url)
+            .json(&serde_json::json!({ "data": hash.to_string() }))
+            .send()
+            .await
+            .map_err(alloy_signer::Error::other)?;
+
+        let status = response.status();
+        let body = response.text().await.map_err(alloy_signer::Error::other)?;
+        if !status.is_success() {
+            return Err(alloy_signer::Error::other(format!(
+                "Signing service returned {}: {}",
+                status, body
+            )));
+        }
+
+        let signature = PrimitiveSignature::from_str(body.trim().trim_matches('"'))
+            .map_err(alloy_signer::Error::other)?;
+        let signer = signature
+            .recover_address_from_prehash(hash)
+            .map_err(alloy_signer::Error::other)?;
+        if signer != self.address {
+            return Err(alloy_signer::Error::other(format!(
+                "Signing service signed with {} instead of {}",
+                signer, self.address
+            )));
+        }
+
+        Ok(signature)
+    }
+
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn chain_id(&self) -> Option<ChainId> {
+        self.chain_id
+    }
+
+    fn set_chain_id(&mut self, chain_id: Option<ChainId>) {
+        self.chain_id = chain_id;
+    }
+}
@@ -4,7 +4,20 @@ use alloy_signer::{Signer, SignerSync};
 ///
 /// This trait combines the required traits for signing EIP-712 messages
 /// both synchronously and asynchronously.
-pub trait EthSigner: Signer + SignerSync + Send + Sync {}
+pub trait EthSigner: AsyncEthSigner + SignerSync {}
 
 // Blanket implementation for any type that meets the requirements
 impl<T: Signer + SignerSync + Send + Sync> EthSigner for T {}
+
+/// Trait for Ethereum signers that can only sign asynchronously
+///
+/// Remote signers, hardware wallets and KMS-backed signers implement
+/// [`Signer`] but not [`SignerSync`]. They can be used wherever signing
+/// happens in an `async` method, such as
+/// [`OrderBuilder::new_async`](crate::orders::OrderBuilder::new_async) and the
+/// API key methods of
+/// [`AuthenticatedClient`](crate::client::AuthenticatedClient).
+pub trait AsyncEthSigner: Signer + Send + Sync {}
+
+// Blanket implementation for any type that meets the requirements
+impl<T: Signer + Send + Sync + ?Sized> AsyncEthSigner for T {}
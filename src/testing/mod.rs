@@ -0,0 +1,12 @@
+//! Test support for code built on this crate.
+//!
+//! Enabled with the `test-server` feature. [`TestServer`] is an in-process
+//! stand-in for the CLOB: it serves the HTTP endpoints the clients call and the
+//! market and user WebSocket channels on a local port, checks L1/L2
+//! authentication headers and EIP-712 order signatures, and keeps posted
+//! orders in memory so integration tests can run in CI without the network.
+
+mod http;
+mod server;
+
+pub use server::{PostedOrder, TestServer};
@@ -0,0 +1,1199 @@
+use alloy_primitives::hex::encode_prefixed;
+use alloy_primitives::{Address, B256, U256};
+use alloy_signer::SignerSync;
+use alloy_signer_local::PrivateKeySigner;
+use base64::{engine::general_purpose::URL_SAFE, Engine};
+use futures_util::{SinkExt, StreamExt};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::protocol::Role;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::WebSocketStream;
+
+use super::http::{read_request, write_response, write_upgrade, Request, Response};
+use crate::book::LocalOrderBook;
+use crate::error::{Error, Result};
+use crate::signing::{clob_auth_signing_hash, recover_signer, verify_order_signature};
+use crate::types::{
+    ApiCreds, BookEvent, MarketSubscription, OrderEvent, OrderId, OrderType, SignedOrderRequest,
+    UserAuthentication, UserWsEvent, WsEvent,
+};
+use crate::utils::{build_hmac_signature_raw, get_current_unix_time_secs};
+
+/// Capacity of the WebSocket broadcast channels
+const WS_CHANNEL_CAPACITY: usize = 1024;
+
+/// Cursor the API returns on the last page
+const END_CURSOR: &str = "LTE=";
+
+/// Web3Signer-style signing endpoint, followed by the key's address
+const SIGN_PATH: &str = "/api/v1/eth1/sign/";
+
+const POLY_ADDR_HEADER: &str = "POLY_ADDRESS";
+const POLY_SIG_HEADER: &str = "POLY_SIGNATURE";
+const POLY_TS_HEADER: &str = "POLY_TIMESTAMP";
+const POLY_NONCE_HEADER: &str = "POLY_NONCE";
+const POLY_API_KEY_HEADER: &str = "POLY_API_KEY";
+const POLY_PASS_HEADER: &str = "POLY_PASSPHRASE";
+
+/// An order accepted by a [`TestServer`]
+#[derive(Debug, Clone)]
+pub struct PostedOrder {
+    /// Order ID returned to the client
+    pub id: OrderId,
+    /// API key that posted the order
+    pub owner: String,
+    /// Time in force the order was posted with
+    pub order_type: OrderType,
+    /// The signed order as posted
+    pub order: SignedOrderRequest,
+    /// `LIVE` or `CANCELED`
+    pub status: String,
+    /// Unix seconds
+    pub created_at: u64,
+}
+
+/// API key issued by the server
+#[derive(Debug, Clone)]
+struct IssuedKey {
+    creds: ApiCreds,
+    address: Address,
+    nonce: U256,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PostOrderBody {
+    order: SignedOrderRequest,
+    owner: String,
+    order_type: OrderType,
+}
+
+struct ServerState {
+    books: HashMap<String, LocalOrderBook>,
+    /// Tick size and neg risk flag by token
+    token_options: HashMap<String, (Decimal, bool)>,
+    /// Fee rate in basis points by token; 0 if unset
+    fee_rates: HashMap<String, u32>,
+    keys: Vec<IssuedKey>,
+    orders: Vec<PostedOrder>,
+    /// Keys served by the signing endpoint
+    signing_keys: HashMap<Address, PrivateKeySigner>,
+}
+
+struct Shared {
+    chain_id: u64,
+    state: Mutex<ServerState>,
+    market_tx: broadcast::Sender<WsEvent>,
+    /// Events tagged with the API key they are for
+    user_tx: broadcast::Sender<(String, UserWsEvent)>,
+}
+
+/// In-process stand-in for the CLOB HTTP and WebSocket APIs
+///
+/// The server listens on a random local port. Point clients at it with their
+/// custom-URL constructors: [`url`](Self::url) for the HTTP clients and
+/// [`market_ws_url`](Self::market_ws_url) / [`user_ws_url`](Self::user_ws_url)
+/// for [`MarketWsClient::with_url`](crate::websocket::MarketWsClient::with_url)
+/// and [`UserWsClient::with_url`](crate::websocket::UserWsClient::with_url).
+///
+/// It serves:
+///
+/// - `GET /book` from books set with [`publish_market_event`](Self::publish_market_event)
+/// - `GET /tick-size` and `GET /neg-risk` (by `token_id`) from
+///   [`set_token_options`](Self::set_token_options), and `GET /fee-rate` from
+///   [`set_fee_rate`](Self::set_fee_rate)
+/// - `POST /auth/api-key`, `GET /auth/derive-api-key` (L1 headers),
+///   `GET /auth/api-keys` and `DELETE /auth/api-key` (L2 headers)
+/// - `POST /order`, `POST /orders`, `GET /data/orders`, `GET /data/order/{id}`,
+///   `DELETE /order`, `DELETE /orders`, `DELETE /cancel-all` and
+///   `DELETE /cancel-market-orders` (L2 headers)
+/// - the `/ws/market` and `/ws/user` channels
+/// - `POST /api/v1/eth1/sign/{address}`, a Web3Signer-style signing endpoint
+///   for keys added with [`add_signing_key`](Self::add_signing_key), as a
+///   stand-in for the service behind a [`RemoteSigner`](crate::signing::RemoteSigner)
+///
+/// L1 headers must carry a valid `ClobAuth` signature and L2 headers a valid
+/// HMAC of the request. Posted orders must be signed by the API key's address
+/// for one of the chain's exchange contracts. Orders rest until cancelled;
+/// there is no matching engine. Placements and cancellations are sent to the
+/// owner's user channel.
+///
+/// The server stops when it is dropped.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::testing::TestServer;
+/// use polymarket_rs::{ClobClient, TokenId};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let server = TestServer::start(137).await?;
+/// let client = ClobClient::new(server.url());
+/// let book = client.get_order_book(&TokenId::new("1")).await;
+/// # Ok(())
+/// # }
+/// ```
+pub struct TestServer {
+    addr: SocketAddr,
+    shared: Arc<Shared>,
+    task: JoinHandle<()>,
+}
+
+impl TestServer {
+    /// Start a server for the given chain on a random local port
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`](crate::Error::Io) if the port cannot be bound.
+    pub async fn start(chain_id: u64) -> Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let (market_tx, _) = broadcast::channel(WS_CHANNEL_CAPACITY);
+        let (user_tx, _) = broadcast::channel(WS_CHANNEL_CAPACITY);
+        let shared = Arc::new(Shared {
+            chain_id,
+            state: Mutex::new(ServerState {
+                books: HashMap::new(),
+                token_options: HashMap::new(),
+                fee_rates: HashMap::new(),
+                keys: Vec::new(),
+                orders: Vec::new(),
+                signing_keys: HashMap::new(),
+            }),
+            market_tx,
+            user_tx,
+        });
+
+        let accept_shared = shared.clone();
+        let task = tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, _)) => {
+                        tokio::spawn(handle_connection(accept_shared.clone(), stream));
+                    }
+                    Err(e) => log::warn!("Test server accept failed: {}", e),
+                }
+            }
+        });
+
+        Ok(Self { addr, shared, task })
+    }
+
+    /// Base URL for the HTTP clients
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// URL of the market channel
+    pub fn market_ws_url(&self) -> String {
+        format!("ws://{}/ws/market", self.addr)
+    }
+
+    /// URL of the user channel
+    pub fn user_ws_url(&self) -> String {
+        format!("ws://{}/ws/user", self.addr)
+    }
+
+    /// Chain the server verifies signatures for
+    pub fn chain_id(&self) -> u64 {
+        self.shared.chain_id
+    }
+
+    /// Issue API credentials for `address` without an L1 request
+    ///
+    /// Returns the existing credentials if the address already has a key.
+    pub fn create_api_key(&self, address: Address) -> ApiCreds {
+        let mut state = self.shared.lock();
+        state.issue_key(address, U256::ZERO).creds
+    }
+
+    /// Set the tick size and neg risk flag served for a token
+    ///
+    /// Tokens without options get a 404 from `GET /tick-size` and
+    /// `GET /neg-risk`, like unknown markets.
+    pub fn set_token_options(
+        &self,
+        token_id: impl Into<String>,
+        tick_size: Decimal,
+        neg_risk: bool,
+    ) {
+        self.shared
+            .lock()
+            .token_options
+            .insert(token_id.into(), (tick_size, neg_risk));
+    }
+
+    /// Set the fee rate served for a token, in basis points
+    ///
+    /// `GET /fee-rate` serves 0 for tokens with options but no fee rate.
+    pub fn set_fee_rate(&self, token_id: impl Into<String>, fee_rate_bps: u32) {
+        self.shared
+            .lock()
+            .fee_rates
+            .insert(token_id.into(), fee_rate_bps);
+    }
+
+    /// Update the server's books and send an event to market subscribers
+    ///
+    /// A [`WsEvent::Book`] sets the book served by `GET /book`; price changes
+    /// update it. A [`WsEvent::TickSizeChange`] updates the tick size served
+    /// by `GET /tick-size`.
+    pub fn publish_market_event(&self, event: WsEvent) {
+        self.shared.lock().apply_market_event(&event);
+        // No subscribers is fine
+        let _ = self.shared.market_tx.send(event);
+    }
+
+    /// Send an event to user subscribers authenticated with `api_key`
+    pub fn publish_user_event(&self, api_key: &str, event: UserWsEvent) {
+        let _ = self.shared.user_tx.send((api_key.to_string(), event));
+    }
+
+    /// Hold `signer`'s key for the signing endpoint and return its address
+    ///
+    /// Point a [`RemoteSigner`](crate::signing::RemoteSigner) at
+    /// [`url`](Self::url) with the returned address to sign with the key.
+    pub fn add_signing_key(&self, signer: PrivateKeySigner) -> Address {
+        let address = signer.address();
+        self.shared.lock().signing_keys.insert(address, signer);
+        address
+    }
+
+    /// Every order accepted so far, oldest first
+    pub fn orders(&self) -> Vec<PostedOrder> {
+        self.shared.lock().orders.clone()
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, ServerState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    // `map_or` rather than `is_none_or`, which needs Rust 1.82
+    #[allow(clippy::unnecessary_map_or)]
+    fn route(&self, req: &Request) -> Response {
+        let path = req.path.as_str();
+        match (req.method.as_str(), path) {
+            ("GET", "/book") => self.get_book(req),
+            ("GET", "/tick-size") => self.get_token_option(
+                req,
+                |(tick_size, _)| serde_json::json!({ "minimum_tick_size": tick_size }),
+            ),
+            ("GET", "/neg-risk") => self.get_token_option(
+                req,
+                |(_, neg_risk)| serde_json::json!({ "neg_risk": neg_risk }),
+            ),
+            ("GET", "/fee-rate") => {
+                let token_id = req.query.get("token_id").cloned().unwrap_or_default();
+                let fee_rate_bps = self.lock().fee_rates.get(&token_id).copied();
+                self.get_token_option(
+                    req,
+                    |_| serde_json::json!({ "base_fee": fee_rate_bps.unwrap_or(0) }),
+                )
+            }
+            ("POST", "/auth/api-key") => self.l1(req, |state, address, nonce| {
+                if state.key_for(address, nonce).is_some() {
+                    return Response::error(400, "Could not create api key");
+                }
+                Response::json(state.issue_key(address, nonce).creds)
+            }),
+            ("GET", "/auth/derive-api-key") => self.l1(req, |state, address, nonce| {
+                match state.key_for(address, nonce) {
+                    Some(key) => Response::json(&key.creds),
+                    None => Response::error(400, "Could not derive api key"),
+                }
+            }),
+            ("GET", "/auth/api-keys") => self.l2(req, |state, key| {
+                let keys: Vec<&str> = state
+                    .keys
+                    .iter()
+                    .filter(|k| k.address == key.address)
+                    .map(|k| k.creds.api_key.as_str())
+                    .collect();
+                Response::json(serde_json::json!({ "apiKeys": keys }))
+            }),
+            ("DELETE", "/auth/api-key") => self.l2(req, |state, key| {
+                state.keys.retain(|k| k.creds.api_key != key.creds.api_key);
+                Response::json("OK")
+            }),
+            ("POST", "/order") => self.l2(req, |state, key| {
+                let body: PostOrderBody = match serde_json::from_str(&req.body) {
+                    Ok(body) => body,
+                    Err(e) => return Response::error(400, format!("Invalid order payload: {}", e)),
+                };
+                match state.post_order(self, &key, body) {
+                    Ok(id) => Response::json(post_order_response(Ok(&id))),
+                    Err(message) => Response::error(400, message),
+                }
+            }),
+            ("POST", "/orders") => self.l2(req, |state, key| {
+                let bodies: Vec<PostOrderBody> = match serde_json::from_str(&req.body) {
+                    Ok(bodies) => bodies,
+                    Err(e) => return Response::error(400, format!("Invalid order payload: {}", e)),
+                };
+                let responses: Vec<serde_json::Value> = bodies
+                    .into_iter()
+                    .map(|body| post_order_response(state.post_order(self, &key, body).as_ref()))
+                    .collect();
+                Response::json(responses)
+            }),
+            ("GET", "/data/orders") => self.l2(req, |state, key| {
+                let data: Vec<serde_json::Value> = state
+                    .orders
+                    .iter()
+                    .filter(|o| o.owner == key.creds.api_key && o.status == "LIVE")
+                    .filter(|o| req.query.get("id").map_or(true, |id| o.id.as_str() == id))
+                    .filter(|o| {
+                        req.query
+                            .get("asset_id")
+                            .map_or(true, |a| &o.order.token_id == a)
+                    })
+                    .filter(|o| {
+                        req.query
+                            .get("market")
+                            .map_or(true, |m| &state.market_of(&o.order.token_id) == m)
+                    })
+                    .map(|o| state.open_order_json(o))
+                    .collect();
+                Response::json(serde_json::json!({
+                    "limit": data.len(),
+                    "count": data.len(),
+                    "next_cursor": END_CURSOR,
+                    "data": data,
+                }))
+            }),
+            ("GET", _) if path.starts_with("/data/order/") => self.l2(req, |state, key| {
+                let id = &path["/data/order/".len()..];
+                match state
+                    .orders
+                    .iter()
+                    .find(|o| o.owner == key.creds.api_key && o.id.as_str() == id)
+                {
+                    Some(order) => Response::json(state.open_order_json(order)),
+                    None => Response::error(404, format!("order {} not found", id)),
+                }
+            }),
+            ("DELETE", "/order") => self.l2(req, |state, key| {
+                let body: serde_json::Value = serde_json::from_str(&req.body).unwrap_or_default();
+                let id = body["orderID"].as_str().unwrap_or_default();
+                Response::json(state.cancel(self, &key, |o| o.id.as_str() == id, &[id]))
+            }),
+            ("DELETE", "/orders") => self.l2(req, |state, key| {
+                let ids: Vec<String> = serde_json::from_str(&req.body).unwrap_or_default();
+                let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+                Response::json(state.cancel(self, &key, |o| ids.contains(&o.id.as_str()), &ids))
+            }),
+            ("DELETE", "/cancel-all") => self.l2(req, |state, key| {
+                Response::json(state.cancel(self, &key, |_| true, &[]))
+            }),
+            ("DELETE", "/cancel-market-orders") => self.l2(req, |state, key| {
+                let body: serde_json::Value = serde_json::from_str(&req.body).unwrap_or_default();
+                let market = body["market"].as_str().unwrap_or_default().to_string();
+                let asset_id = body["asset_id"].as_str().unwrap_or_default().to_string();
+                let markets: HashMap<String, String> = state
+                    .orders
+                    .iter()
+                    .map(|o| (o.id.to_string(), state.market_of(&o.order.token_id)))
+                    .collect();
+                let filter = |o: &PostedOrder| {
+                    (market.is_empty() || markets[o.id.as_str()] == market)
+                        && (asset_id.is_empty() || o.order.token_id == asset_id)
+                };
+                Response::json(state.cancel(self, &key, filter, &[]))
+            }),
+            ("POST", path) if path.starts_with(SIGN_PATH) => {
+                self.sign(req, &path[SIGN_PATH.len()..])
+            }
+            _ => Response::error(404, format!("{} {} not found", req.method, path)),
+        }
+    }
+
+    /// Sign the `data` digest with a key added by `add_signing_key`
+    fn sign(&self, req: &Request, address: &str) -> Response {
+        let Some(signer) = Address::from_str(address)
+            .ok()
+            .and_then(|address| self.lock().signing_keys.get(&address).cloned())
+        else {
+            return Response::error(404, format!("No key for {}", address));
+        };
+        let body: serde_json::Value = serde_json::from_str(&req.body).unwrap_or_default();
+        let Some(hash) = body["data"].as_str().and_then(|d| B256::from_str(d).ok()) else {
+            return Response::error(400, "data must be a 32-byte hex digest");
+        };
+        match signer.sign_hash_sync(&hash) {
+            Ok(signature) => Response::text(encode_prefixed(signature.as_bytes())),
+            Err(e) => Response::error(500, e.to_string()),
+        }
+    }
+
+    fn get_book(&self, req: &Request) -> Response {
+        let token_id = req
+            .query
+            .get("token_id")
+            .map(String::as_str)
+            .unwrap_or_default();
+        match self.lock().books.get(token_id) {
+            Some(book) => {
+                let summary = book.snapshot();
+                Response::json(serde_json::json!({
+                    "market": summary.market,
+                    "asset_id": summary.asset_id,
+                    "hash": summary.hash,
+                    "timestamp": summary.timestamp.to_string(),
+                    "bids": summary.bids,
+                    "asks": summary.asks,
+                }))
+            }
+            None => Response::error(404, "No orderbook exists for the requested token id"),
+        }
+    }
+
+    fn get_token_option(
+        &self,
+        req: &Request,
+        body: impl FnOnce((Decimal, bool)) -> serde_json::Value,
+    ) -> Response {
+        let token_id = req
+            .query
+            .get("token_id")
+            .map(String::as_str)
+            .unwrap_or_default();
+        match self.lock().token_options.get(token_id) {
+            Some(options) => Response::json(body(*options)),
+            None => Response::error(404, "market not found"),
+        }
+    }
+
+    /// Check L1 headers, then handle the request for the signing address and nonce
+    fn l1(
+        &self,
+        req: &Request,
+        handler: impl FnOnce(&mut ServerState, Address, U256) -> Response,
+    ) -> Response {
+        let checked = (|| {
+            let address = Address::from_str(req.header(POLY_ADDR_HEADER)?).ok()?;
+            let timestamp = req.header(POLY_TS_HEADER)?.to_string();
+            let nonce = U256::from_str(req.header(POLY_NONCE_HEADER).unwrap_or("0")).ok()?;
+            let hash = clob_auth_signing_hash(address, timestamp, nonce, self.chain_id);
+            let signer = recover_signer(req.header(POLY_SIG_HEADER)?, &hash).ok()?;
+            (signer == address).then_some((address, nonce))
+        })();
+
+        match checked {
+            Some((address, nonce)) => handler(&mut self.lock(), address, nonce),
+            None => Response::error(401, "Invalid L1 Request headers"),
+        }
+    }
+
+    /// Check L2 headers, then handle the request for the API key
+    fn l2(
+        &self,
+        req: &Request,
+        handler: impl FnOnce(&mut ServerState, IssuedKey) -> Response,
+    ) -> Response {
+        let mut state = self.lock();
+        let checked = (|| {
+            let key = state.key(req.header(POLY_API_KEY_HEADER)?)?.clone();
+            let address = Address::from_str(req.header(POLY_ADDR_HEADER)?).ok()?;
+            let timestamp: u64 = req.header(POLY_TS_HEADER)?.parse().ok()?;
+            if req.header(POLY_PASS_HEADER)? != key.creds.passphrase || address != key.address {
+                return None;
+            }
+            let body = (!req.body.is_empty()).then_some(req.body.as_str());
+            let expected = build_hmac_signature_raw(
+                &key.creds.secret,
+                timestamp,
+                &req.method,
+                &req.path,
+                body,
+            )
+            .ok()?;
+            (req.header(POLY_SIG_HEADER)? == expected).then_some(key)
+        })();
+
+        match checked {
+            Some(key) => handler(&mut state, key),
+            None => Response::error(401, "Unauthorized/Invalid api key"),
+        }
+    }
+}
+
+impl ServerState {
+    fn key(&self, api_key: &str) -> Option<&IssuedKey> {
+        self.keys.iter().find(|k| k.creds.api_key == api_key)
+    }
+
+    fn key_for(&self, address: Address, nonce: U256) -> Option<&IssuedKey> {
+        self.keys
+            .iter()
+            .find(|k| k.address == address && k.nonce == nonce)
+    }
+
+    /// Issue credentials derived from the address and nonce, or return the
+    /// existing ones
+    fn issue_key(&mut self, address: Address, nonce: U256) -> IssuedKey {
+        if let Some(key) = self.key_for(address, nonce) {
+            return key.clone();
+        }
+
+        let digest = |label: &str| {
+            Sha256::new()
+                .chain_update(label)
+                .chain_update(address)
+                .chain_update(nonce.to_be_bytes::<32>())
+                .finalize()
+        };
+        let id = digest("key");
+        let api_key = format!(
+            "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+            u32::from_be_bytes([id[0], id[1], id[2], id[3]]),
+            u16::from_be_bytes([id[4], id[5]]),
+            u16::from_be_bytes([id[6], id[7]]),
+            u16::from_be_bytes([id[8], id[9]]),
+            u64::from_be_bytes([0, 0, id[10], id[11], id[12], id[13], id[14], id[15]]),
+        );
+        let key = IssuedKey {
+            creds: ApiCreds::new(
+                api_key,
+                URL_SAFE.encode(digest("secret")),
+                hex_string(&digest("passphrase")),
+            ),
+            address,
+            nonce,
+        };
+        self.keys.push(key.clone());
+        key
+    }
+
+    // Events the books reject, such as a bad timestamp, are still broadcast
+    // so tests can feed clients malformed data
+    fn apply_market_event(&mut self, event: &WsEvent) {
+        match event {
+            WsEvent::Book(book) => {
+                if let Ok(local) = LocalOrderBook::from_book_event(book) {
+                    self.books.insert(book.asset_id.clone(), local);
+                }
+            }
+            WsEvent::TickSizeChange(change) => {
+                if let Some(options) = self.token_options.get_mut(&change.asset_id) {
+                    options.0 = change.new_tick_size;
+                }
+            }
+            _ => {
+                for book in self.books.values_mut() {
+                    let _ = book.apply_event(event);
+                }
+            }
+        }
+    }
+
+    fn market_of(&self, token_id: &str) -> String {
+        self.books
+            .get(token_id)
+            .map(|b| b.market().to_string())
+            .unwrap_or_default()
+    }
+
+    /// Verify and store an order; returns its ID or the rejection reason
+    fn post_order(
+        &mut self,
+        shared: &Shared,
+        key: &IssuedKey,
+        body: PostOrderBody,
+    ) -> std::result::Result<OrderId, String> {
+        if body.owner != key.creds.api_key {
+            return Err("the order owner has to be the owner of the API KEY".to_string());
+        }
+        let order = body.order;
+        order.price().map_err(|e| e.to_string())?;
+
+        let (signer, neg_risk) = verify_any_exchange(&order, shared.chain_id)?;
+        if signer != key.address {
+            return Err(
+                "the order signer address has to be the address of the API KEY".to_string(),
+            );
+        }
+
+        // Orders are identified by their EIP-712 hash, like on the exchange
+        let id = order
+            .order_hash(shared.chain_id, neg_risk)
+            .map_err(|e| e.to_string())?;
+        if self.orders.iter().any(|o| o.id == id) {
+            return Err("order already exists".to_string());
+        }
+        let posted = PostedOrder {
+            id: id.clone(),
+            owner: body.owner,
+            order_type: body.order_type,
+            order,
+            status: "LIVE".to_string(),
+            created_at: get_current_unix_time_secs().unwrap_or_default(),
+        };
+        let event = self.order_event(&posted, "PLACEMENT");
+        self.orders.push(posted);
+        let _ = shared.user_tx.send((key.creds.api_key.clone(), event));
+        Ok(id)
+    }
+
+    /// Cancel the key's live orders matching `filter`; `requested` IDs that
+    /// were not cancelled are reported with a reason
+    fn cancel(
+        &mut self,
+        shared: &Shared,
+        key: &IssuedKey,
+        filter: impl Fn(&PostedOrder) -> bool,
+        requested: &[&str],
+    ) -> serde_json::Value {
+        let mut canceled = Vec::new();
+        let mut events = Vec::new();
+        for order in &mut self.orders {
+            if order.owner == key.creds.api_key && order.status == "LIVE" && filter(order) {
+                order.status = "CANCELED".to_string();
+                canceled.push(order.id.to_string());
+            }
+        }
+        for order in self
+            .orders
+            .iter()
+            .filter(|o| canceled.contains(&o.id.to_string()))
+        {
+            events.push(self.order_event(order, "CANCELLATION"));
+        }
+        for event in events {
+            let _ = shared.user_tx.send((key.creds.api_key.clone(), event));
+        }
+
+        let not_canceled: serde_json::Map<String, serde_json::Value> = requested
+            .iter()
+            .filter(|id| !canceled.iter().any(|c| c == *id))
+            .map(|id| {
+                let known = self
+                    .orders
+                    .iter()
+                    .any(|o| o.owner == key.creds.api_key && o.id.as_str() == *id);
+                let reason = if known {
+                    "order can't be found - already canceled or matched"
+                } else {
+                    "order not found"
+                };
+                (id.to_string(), reason.into())
+            })
+            .collect();
+
+        serde_json::json!({ "canceled": canceled, "not_canceled": not_canceled })
+    }
+
+    fn open_order_json(&self, order: &PostedOrder) -> serde_json::Value {
+        let signed = &order.order;
+        serde_json::json!({
+            "id": order.id,
+            "associate_trades": [],
+            "status": order.status,
+            "market": self.market_of(&signed.token_id),
+            "original_size": signed.size().unwrap_or_default().to_string(),
+            "outcome": "",
+            "maker_address": signed.maker,
+            "owner": order.owner,
+            "price": order_price(signed).to_string(),
+            "side": signed.side,
+            "size_matched": "0",
+            "asset_id": signed.token_id,
+            "expiration": signed.expiration,
+            "order_type": order.order_type,
+            "created_at": order.created_at,
+        })
+    }
+
+    fn order_event(&self, order: &PostedOrder, event_type: &str) -> UserWsEvent {
+        let signed = &order.order;
+        UserWsEvent::Order(OrderEvent {
+            event_type: "order".to_string(),
+            id: order.id.to_string(),
+            owner: Some(order.owner.clone()),
+            market: self.market_of(&signed.token_id),
+            asset_id: signed.token_id.clone(),
+            side: signed.order_side().unwrap_or_default(),
+            order_owner: Some(order.owner.clone()),
+            original_size: signed.size().unwrap_or_default(),
+            size_matched: Default::default(),
+            price: order_price(signed),
+            associate_trades: None,
+            outcome: String::new(),
+            order_event_type: event_type.to_string(),
+            created_at: Some(order.created_at.to_string()),
+            expiration: Some(signed.expiration.clone()),
+            order_type: order.order_type.as_str().to_string(),
+            status: order.status.clone(),
+            maker_address: signed.maker.clone(),
+            timestamp: get_current_unix_time_secs().ok().map(|t| t.to_string()),
+        })
+    }
+}
+
+/// Price of a posted order; the smallest tick is 0.0001
+fn order_price(order: &SignedOrderRequest) -> Decimal {
+    order
+        .price()
+        .map(|p| p.round_dp(4).normalize())
+        .unwrap_or_default()
+}
+
+/// Recover the signer of an order signed for either of the chain's
+/// exchanges, along with whether it was the neg risk one
+fn verify_any_exchange(
+    order: &SignedOrderRequest,
+    chain_id: u64,
+) -> std::result::Result<(Address, bool), String> {
+    let mut last_error = String::from("invalid signature");
+    for neg_risk in [false, true] {
+        match verify_order_signature(order, chain_id, neg_risk) {
+            Ok(signer) => return Ok((signer, neg_risk)),
+            Err(e @ Error::InvalidOrder(_)) => return Err(e.to_string()),
+            Err(e) => last_error = e.to_string(),
+        }
+    }
+    Err(last_error)
+}
+
+fn post_order_response(result: std::result::Result<&OrderId, &String>) -> serde_json::Value {
+    match result {
+        Ok(id) => serde_json::json!({
+            "errorMsg": "",
+            "orderID": id,
+            "status": "live",
+            "success": true,
+        }),
+        Err(message) => serde_json::json!({
+            "errorMsg": message,
+            "orderID": "",
+            "status": "",
+            "success": false,
+        }),
+    }
+}
+
+fn hex_string(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+async fn handle_connection(shared: Arc<Shared>, mut stream: TcpStream) {
+    let request = match read_request(&mut stream).await {
+        Ok(Some(request)) => request,
+        Ok(None) => return,
+        Err(e) => {
+            log::warn!("Test server failed to read request: {}", e);
+            return;
+        }
+    };
+
+    if request.is_websocket_upgrade() {
+        let channel = request.path.clone();
+        if channel != "/ws/market" && channel != "/ws/user" {
+            let _ = write_response(&mut stream, &Response::error(404, "unknown channel")).await;
+            return;
+        }
+        // Subscribe before completing the handshake so no event sent after
+        // the client connects is missed
+        let market_events = shared.market_tx.subscribe();
+        let user_events = shared.user_tx.subscribe();
+        if let Err(e) = write_upgrade(&mut stream, &request).await {
+            log::warn!("Test server WebSocket upgrade failed: {}", e);
+            return;
+        }
+        let ws = WebSocketStream::from_raw_socket(stream, Role::Server, None).await;
+        if channel == "/ws/market" {
+            serve_market(shared, ws, market_events).await;
+        } else {
+            serve_user(shared, ws, user_events).await;
+        }
+        return;
+    }
+
+    let response = shared.route(&request);
+    if let Err(e) = write_response(&mut stream, &response).await {
+        log::warn!("Test server failed to write response: {}", e);
+    }
+}
+
+/// Wait for the first text message, answering PINGs
+async fn first_text(ws: &mut WebSocketStream<TcpStream>) -> Option<String> {
+    while let Some(Ok(message)) = ws.next().await {
+        match message {
+            Message::Text(text) if text == "PING" => {
+                ws.send(Message::Text("PONG".to_string())).await.ok()?;
+            }
+            Message::Text(text) => return Some(text),
+            Message::Close(_) => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+async fn serve_market(
+    shared: Arc<Shared>,
+    mut ws: WebSocketStream<TcpStream>,
+    mut events: broadcast::Receiver<WsEvent>,
+) {
+    let Some(subscription) = first_text(&mut ws)
+        .await
+        .and_then(|text| serde_json::from_str::<MarketSubscription>(&text).ok())
+    else {
+        let _ = ws.close(None).await;
+        return;
+    };
+    let assets: HashSet<String> = subscription.assets_ids.into_iter().collect();
+
+    let snapshot: Vec<WsEvent> = {
+        let state = shared.lock();
+        assets
+            .iter()
+            .filter_map(|asset| state.books.get(asset))
+            .map(|book| {
+                let summary = book.snapshot();
+                WsEvent::Book(BookEvent {
+                    event_type: "book".to_string(),
+                    market: summary.market,
+                    asset_id: summary.asset_id,
+                    timestamp: summary.timestamp.to_string(),
+                    hash: summary.hash,
+                    bids: summary.bids,
+                    asks: summary.asks,
+                    last_trade_price: None,
+                })
+            })
+            .collect()
+    };
+    if !snapshot.is_empty() {
+        let text = serde_json::to_string(&snapshot).unwrap_or_default();
+        if ws.send(Message::Text(text)).await.is_err() {
+            return;
+        }
+    }
+
+    loop {
+        tokio::select! {
+            event = events.recv() => match event {
+                Ok(event) if concerns(&event, &assets) => {
+                    let text = serde_json::to_string(&event).unwrap_or_default();
+                    if ws.send(Message::Text(text)).await.is_err() {
+                        return;
+                    }
+                }
+                Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => {}
+                Err(broadcast::error::RecvError::Closed) => return,
+            },
+            message = ws.next() => match message {
+                Some(Ok(Message::Text(text))) if text == "PING" => {
+                    if ws.send(Message::Text("PONG".to_string())).await.is_err() {
+                        return;
+                    }
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => return,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+}
+
+async fn serve_user(
+    shared: Arc<Shared>,
+    mut ws: WebSocketStream<TcpStream>,
+    mut events: broadcast::Receiver<(String, UserWsEvent)>,
+) {
+    let authenticated = first_text(&mut ws)
+        .await
+        .and_then(|text| serde_json::from_str::<UserAuthentication>(&text).ok())
+        .and_then(|auth| {
+            let state = shared.lock();
+            let key = state.key(&auth.auth.api_key)?;
+            (key.creds.secret == auth.auth.secret && key.creds.passphrase == auth.auth.passphrase)
+                .then(|| key.creds.api_key.clone())
+        });
+    let Some(api_key) = authenticated else {
+        let _ = ws.close(None).await;
+        return;
+    };
+
+    loop {
+        tokio::select! {
+            event = events.recv() => match event {
+                Ok((owner, event)) if owner == api_key => {
+                    let text = serde_json::to_string(&event).unwrap_or_default();
+                    if ws.send(Message::Text(text)).await.is_err() {
+                        return;
+                    }
+                }
+                Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => {}
+                Err(broadcast::error::RecvError::Closed) => return,
+            },
+            message = ws.next() => match message {
+                Some(Ok(Message::Text(text))) if text == "PING" => {
+                    if ws.send(Message::Text("PONG".to_string())).await.is_err() {
+                        return;
+                    }
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => return,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+}
+
+/// Whether a market event is for one of the subscribed assets
+fn concerns(event: &WsEvent, assets: &HashSet<String>) -> bool {
+    match event {
+        WsEvent::Book(e) => assets.contains(&e.asset_id),
+        WsEvent::PriceChange(e) => e.price_changes.iter().any(|c| assets.contains(&c.asset_id)),
+        WsEvent::LastTradePrice(e) => assets.contains(&e.asset_id),
+        WsEvent::TickSizeChange(e) => assets.contains(&e.asset_id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{AuthenticatedClient, ClobClient, TradingClient};
+    use crate::fixtures::{book, server_client, server_client_for};
+    use crate::orders::OrderBuilder;
+    use crate::signing::RemoteSigner;
+    use crate::types::{
+        CreateOrderOptions, OpenOrderParams, OrderArgs, PriceChange, PriceChangeEvent, Side,
+        TokenId,
+    };
+    use crate::websocket::{MarketWsClient, UserWsClient};
+    use crate::Error;
+    use alloy_signer_local::PrivateKeySigner;
+    use futures_util::Stream;
+    use rust_decimal_macros::dec;
+    use std::time::Duration;
+
+    const CHAIN_ID: u64 = 137;
+
+    fn options() -> CreateOrderOptions {
+        CreateOrderOptions::default()
+            .tick_size(dec!(0.01))
+            .neg_risk(false)
+    }
+
+    async fn next<T>(stream: &mut (impl Stream<Item = Result<T>> + Unpin)) -> T {
+        tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .expect("timed out waiting for an event")
+            .expect("stream ended")
+            .expect("stream error")
+    }
+
+    #[tokio::test]
+    async fn test_api_key_lifecycle() {
+        let server = TestServer::start(CHAIN_ID).await.unwrap();
+        let signer = PrivateKeySigner::random();
+        let mut client =
+            AuthenticatedClient::new(server.url(), signer.clone(), CHAIN_ID, None, None);
+
+        let creds = client.create_api_key(None).await.unwrap();
+        assert!(client.create_api_key(None).await.is_err());
+        let derived = client.create_or_derive_api_key().await.unwrap();
+        assert_eq!(derived.api_key, creds.api_key);
+
+        client.set_api_creds(Some(creds.clone()));
+        let keys = client.get_api_keys().await.unwrap();
+        assert_eq!(keys.api_keys, vec![creds.api_key.clone()]);
+
+        // Credentials for another address are rejected
+        let mut other = AuthenticatedClient::new(
+            server.url(),
+            PrivateKeySigner::random(),
+            CHAIN_ID,
+            Some(creds),
+            None,
+        );
+        match other.get_api_keys().await {
+            Err(Error::Api { status: 401, .. }) => {}
+            other => panic!("expected 401, got {:?}", other),
+        }
+
+        // L1 headers signed for another chain are rejected
+        other = AuthenticatedClient::new(server.url(), signer, 80002, None, None);
+        match other.derive_api_key().await {
+            Err(Error::Api { status: 401, .. }) => {}
+            other => panic!("expected 401, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_order_lifecycle() {
+        let server = TestServer::start(CHAIN_ID).await.unwrap();
+        server.publish_market_event(book());
+        let signer = PrivateKeySigner::random();
+        let client = server_client_for(&server, signer.clone());
+        let creds = server.create_api_key(signer.address());
+
+        let mut user_events = UserWsClient::with_url(server.user_ws_url())
+            .subscribe(creds.api_key, creds.secret, creds.passphrase)
+            .await
+            .unwrap();
+
+        let args = OrderArgs::new("1", dec!(0.45), dec!(10), Side::Buy);
+        let order = client.create_order(&args, None, None, options()).unwrap();
+        let hash = order.order_hash(CHAIN_ID, false).unwrap();
+        let posted = client.post_order(order, OrderType::Gtc).await.unwrap();
+        assert!(posted.success);
+        assert_eq!(posted.order_id, hash);
+
+        let UserWsEvent::Order(placement) = next(&mut user_events).await else {
+            panic!("expected an order event");
+        };
+        assert_eq!(placement.order_event_type, "PLACEMENT");
+        assert_eq!(placement.id, posted.order_id.as_str());
+
+        let open = client.get_orders(OpenOrderParams::default()).await.unwrap();
+        assert_eq!(open.data.len(), 1);
+        assert_eq!(open.data[0].price, dec!(0.45));
+        assert_eq!(open.data[0].original_size, dec!(10));
+        assert_eq!(open.data[0].market, "m");
+        let order = client.get_order(&posted.order_id).await.unwrap();
+        assert_eq!(order.side, Side::Buy);
+
+        let canceled = client.cancel_all().await.unwrap();
+        assert_eq!(canceled.canceled, vec![posted.order_id.clone()]);
+        let UserWsEvent::Order(cancellation) = next(&mut user_events).await else {
+            panic!("expected an order event");
+        };
+        assert_eq!(cancellation.order_event_type, "CANCELLATION");
+        assert!(client
+            .get_orders(OpenOrderParams::default())
+            .await
+            .unwrap()
+            .data
+            .is_empty());
+        assert_eq!(server.orders()[0].status, "CANCELED");
+    }
+
+    #[tokio::test]
+    async fn test_rejects_bad_orders() {
+        let server = TestServer::start(CHAIN_ID).await.unwrap();
+        let client = server_client(&server);
+        let args = OrderArgs::new("1", dec!(0.45), dec!(10), Side::Buy);
+
+        let mut tampered = client.create_order(&args, None, None, options()).unwrap();
+        tampered.taker_amount = "20000000".to_string();
+        match client.post_order(tampered, OrderType::Gtc).await {
+            Err(Error::Api { status: 400, .. }) => {}
+            other => panic!("expected a rejection, got {:?}", other),
+        }
+
+        // Signed by a wallet other than the API key's
+        let stranger = OrderBuilder::new(PrivateKeySigner::random(), None, None);
+        let foreign = stranger
+            .create_order(CHAIN_ID, &args, 0, &Default::default(), options())
+            .unwrap();
+        let responses = client
+            .post_orders(&[crate::types::PostOrderArgs::new(foreign, OrderType::Gtc)])
+            .await
+            .unwrap();
+        assert!(!responses[0].success);
+        assert!(server.orders().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_market_data() {
+        let server = TestServer::start(CHAIN_ID).await.unwrap();
+        server.publish_market_event(book());
+
+        let summary = ClobClient::new(server.url())
+            .get_order_book(&TokenId::new("1"))
+            .await
+            .unwrap();
+        assert_eq!(summary.asks[0].price, dec!(0.52));
+        assert!(ClobClient::new(server.url())
+            .get_order_book(&TokenId::new("2"))
+            .await
+            .is_err());
+
+        let mut events = MarketWsClient::with_url(server.market_ws_url())
+            .subscribe(vec!["1".to_string()])
+            .await
+            .unwrap();
+        let WsEvent::Book(snapshot) = next(&mut events).await else {
+            panic!("expected a book snapshot");
+        };
+        assert_eq!(snapshot.bids[0].price, dec!(0.48));
+
+        server.publish_market_event(WsEvent::PriceChange(PriceChangeEvent {
+            event_type: "price_change".to_string(),
+            market: "m".to_string(),
+            timestamp: Some("2000".to_string()),
+            hash: None,
+            price_changes: vec![PriceChange {
+                asset_id: "1".to_string(),
+                side: Side::Buy,
+                price: dec!(0.49),
+                size: dec!(5),
+                hash: None,
+            }],
+        }));
+        let WsEvent::PriceChange(change) = next(&mut events).await else {
+            panic!("expected a price change");
+        };
+        assert_eq!(change.price_changes[0].price, dec!(0.49));
+
+        let summary = ClobClient::new(server.url())
+            .get_order_book(&TokenId::new("1"))
+            .await
+            .unwrap();
+        assert_eq!(summary.bids.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_remote_signer() {
+        let server = TestServer::start(CHAIN_ID).await.unwrap();
+        let address = server.add_signing_key(PrivateKeySigner::random());
+        let signer = RemoteSigner::new(server.url(), address);
+
+        // L1 headers are signed remotely
+        let auth = AuthenticatedClient::new(server.url(), signer.clone(), CHAIN_ID, None, None);
+        let creds = auth.create_api_key(None).await.unwrap();
+
+        let builder = OrderBuilder::new_async(signer.clone(), None, None);
+        let client = TradingClient::new(server.url(), signer, CHAIN_ID, creds, builder);
+        let args = OrderArgs::new("1", dec!(0.45), dec!(10), Side::Buy);
+        assert!(matches!(
+            client.create_order(&args, None, None, options()),
+            Err(Error::Signing(_))
+        ));
+
+        let posted = client
+            .create_and_post_order(&args, None, None, options(), OrderType::Gtc)
+            .await
+            .unwrap();
+        assert!(posted.success);
+        let orders = server.orders();
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].order.signer, address.to_checksum(None));
+
+        // The service has no key for other addresses
+        let unknown = RemoteSigner::new(server.url(), PrivateKeySigner::random().address());
+        let auth = AuthenticatedClient::new(server.url(), unknown, CHAIN_ID, None, None);
+        assert!(matches!(
+            auth.create_api_key(None).await,
+            Err(Error::Signing(_))
+        ));
+    }
+}
@@ -9,7 +9,7 @@ pub enum AssetType {
 }
 
 /// Order side (BUY or SELL)
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Side {
     #[default]
@@ -44,6 +44,14 @@ impl Side {
             _ => None,
         }
     }
+
+    /// The other side (BUY <-> SELL)
+    pub fn opposite(self) -> Self {
+        match self {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+        }
+    }
 }
 
 /// Order type
@@ -64,6 +72,18 @@ pub enum OrderType {
     Gtd,
 }
 
+impl OrderType {
+    /// Convert order type to string ("GTC", "FOK", "FAK" or "GTD")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderType::Gtc => "GTC",
+            OrderType::Fok => "FOK",
+            OrderType::Fak => "FAK",
+            OrderType::Gtd => "GTD",
+        }
+    }
+}
+
 /// Signature type for orders
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SignatureType {
@@ -112,6 +132,18 @@ pub enum OrderStatus {
     Expired,
 }
 
+impl OrderStatus {
+    /// Convert order status to string ("LIVE", "MATCHED", "CANCELED" or "EXPIRED")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderStatus::Live => "LIVE",
+            OrderStatus::Matched => "MATCHED",
+            OrderStatus::Canceled => "CANCELED",
+            OrderStatus::Expired => "EXPIRED",
+        }
+    }
+}
+
 /// Notification type
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
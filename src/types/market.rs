@@ -1,6 +1,7 @@
 use chrono::{DateTime, TimeDelta, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 /// Full market information
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -119,13 +120,109 @@ pub struct PriceResponse {
 }
 
 /// Price history response
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct PriceHistoryResponse {
     pub history: Vec<PriceHistory>,
 }
 
+impl PriceHistoryResponse {
+    /// Last known price at or before `timestamp` (Unix seconds)
+    pub fn price_at(&self, timestamp: u64) -> Option<Decimal> {
+        Self::price_in(&self.sorted(), timestamp)
+    }
+
+    /// Last known price at each timestamp of a grid
+    ///
+    /// Grid points before the first history point are `None`; every other
+    /// point carries the most recent price forward.
+    pub fn forward_fill(&self, grid: &[u64]) -> Vec<Option<Decimal>> {
+        let sorted = self.sorted();
+        grid.iter().map(|&t| Self::price_in(&sorted, t)).collect()
+    }
+
+    /// Resample onto a regular grid aligned to multiples of `step`
+    ///
+    /// The grid runs from the first multiple of `step` at or after the first
+    /// point to the first multiple at or after the last point, so the final
+    /// price is always included and a history shorter than `step` yields one
+    /// point. Each point carries the last price at or before it, so gaps are
+    /// forward-filled and bursts collapse to their final price. Steps shorter
+    /// than one second are treated as one second.
+    pub fn resample(&self, step: Duration) -> Vec<PriceHistory> {
+        let sorted = self.sorted();
+        let (Some(first), Some(last)) = (sorted.first(), sorted.last()) else {
+            return Vec::new();
+        };
+
+        time_grid(first.timestamp, last.timestamp, step)
+            .into_iter()
+            .filter_map(|t| {
+                Self::price_in(&sorted, t).map(|price| PriceHistory {
+                    price,
+                    timestamp: t,
+                })
+            })
+            .collect()
+    }
+
+    /// Align several histories on one regular grid aligned to multiples of `step`
+    ///
+    /// The grid runs from the first multiple of `step` at or after the earliest
+    /// point of any history to the first multiple at or after the latest one.
+    /// `prices[i]` holds the forward-filled prices of `histories[i]`, with
+    /// `None` before that history's first point.
+    pub fn align(histories: &[&PriceHistoryResponse], step: Duration) -> AlignedPriceHistory {
+        let bounds = histories
+            .iter()
+            .flat_map(|h| h.history.iter().map(|p| p.timestamp))
+            .fold(None, |acc: Option<(u64, u64)>, t| {
+                Some(acc.map_or((t, t), |(lo, hi)| (lo.min(t), hi.max(t))))
+            });
+
+        let timestamps = match bounds {
+            Some((start, end)) => time_grid(start, end, step),
+            None => Vec::new(),
+        };
+        let prices = histories
+            .iter()
+            .map(|h| h.forward_fill(&timestamps))
+            .collect();
+
+        AlignedPriceHistory { timestamps, prices }
+    }
+
+    fn sorted(&self) -> Vec<&PriceHistory> {
+        let mut sorted: Vec<&PriceHistory> = self.history.iter().collect();
+        sorted.sort_by_key(|p| p.timestamp);
+        sorted
+    }
+
+    fn price_in(sorted: &[&PriceHistory], timestamp: u64) -> Option<Decimal> {
+        let idx = sorted.partition_point(|p| p.timestamp <= timestamp);
+        idx.checked_sub(1).map(|i| sorted[i].price)
+    }
+}
+
+/// Step-aligned timestamps (Unix seconds) from `start` rounded up to `end`
+/// rounded up
+fn time_grid(start: u64, end: u64, step: Duration) -> Vec<u64> {
+    let step = step.as_secs().max(1);
+    let first = start.div_ceil(step) * step;
+    let last = end.div_ceil(step) * step;
+    (first..=last).step_by(step as usize).collect()
+}
+
+/// Several price histories sampled on a shared time grid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlignedPriceHistory {
+    /// Grid timestamps, Unix seconds
+    pub timestamps: Vec<u64>,
+    /// Prices per input history, one entry per grid timestamp
+    pub prices: Vec<Vec<Option<Decimal>>>,
+}
+
 /// Price at a specific timestamp
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 pub struct PriceHistory {
     #[serde(
         rename = "p",
@@ -156,6 +253,13 @@ pub struct NegRiskResponse {
     pub neg_risk: bool,
 }
 
+/// Fee rate response
+#[derive(Debug, Deserialize)]
+pub struct FeeRateResponse {
+    /// Taker fee rate in basis points
+    pub base_fee: u32,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -243,4 +347,64 @@ mod tests {
         assert!(market.ends_within(TimeDelta::hours(1)));
         assert!(market.ends_within(TimeDelta::days(7)));
     }
+
+    fn history(points: &[(u64, Decimal)]) -> PriceHistoryResponse {
+        PriceHistoryResponse {
+            history: points
+                .iter()
+                .map(|&(timestamp, price)| PriceHistory { price, timestamp })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_price_history_resample_forward_fills() {
+        use rust_decimal_macros::dec;
+
+        let h = history(&[(65, dec!(0.5)), (70, dec!(0.6)), (250, dec!(0.7))]);
+        let resampled = h.resample(Duration::from_secs(60));
+
+        let points: Vec<_> = resampled.iter().map(|p| (p.timestamp, p.price)).collect();
+        assert_eq!(
+            points,
+            vec![
+                (120, dec!(0.6)),
+                (180, dec!(0.6)),
+                (240, dec!(0.6)),
+                (300, dec!(0.7))
+            ]
+        );
+
+        // Shorter than one step: the point after it still carries the price
+        let short = history(&[(10, dec!(0.4)), (20, dec!(0.45))]);
+        let resampled = short.resample(Duration::from_secs(60));
+        assert_eq!(
+            resampled,
+            vec![PriceHistory {
+                price: dec!(0.45),
+                timestamp: 60
+            }]
+        );
+        assert_eq!(h.price_at(64), None);
+        assert_eq!(h.price_at(250), Some(dec!(0.7)));
+    }
+
+    #[test]
+    fn test_price_history_align() {
+        use rust_decimal_macros::dec;
+
+        let a = history(&[(0, dec!(0.1)), (120, dec!(0.2))]);
+        let b = history(&[(60, dec!(0.9))]);
+        let aligned = PriceHistoryResponse::align(&[&a, &b], Duration::from_secs(60));
+
+        assert_eq!(aligned.timestamps, vec![0, 60, 120]);
+        assert_eq!(
+            aligned.prices[0],
+            vec![Some(dec!(0.1)), Some(dec!(0.1)), Some(dec!(0.2))]
+        );
+        assert_eq!(
+            aligned.prices[1],
+            vec![None, Some(dec!(0.9)), Some(dec!(0.9))]
+        );
+    }
 }
@@ -1,6 +1,8 @@
 use super::enums::{OrderType, Side};
-use crate::error::Result;
-use crate::{orders::calculate_market_price, OrderId};
+use crate::book::{compute_book_hash, hashes_match, LocalOrderBook};
+use crate::error::{Error, Result};
+use crate::orders::{calculate_market_price, walk_market_order, BookWalk};
+use crate::OrderId;
 use alloy_primitives::U256;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
@@ -27,12 +29,36 @@ impl OrderArgs {
     }
 }
 
+/// What to do when a market order cannot be filled within its price cap
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SlippageAction {
+    /// Reject the order before signing
+    #[default]
+    Reject,
+    /// Reduce the amount to the liquidity available within the cap
+    Truncate,
+}
+
 /// Arguments for creating a market order
+///
+/// `amount` is the USDC notional to spend for a BUY and the number of shares
+/// to sell for a SELL.
+///
+/// The worst price the order may fill at can be bounded with
+/// [`max_slippage`](Self::max_slippage) and/or [`limit_price`](Self::limit_price);
+/// when both are set the tighter one applies.
 #[derive(Debug, Clone)]
 pub struct MarketOrderArgs {
     pub token_id: String,
     pub amount: Decimal,
     pub side: Side,
+    /// Maximum distance of the worst fill from the best price, as a fraction
+    /// of the best price (e.g. `0.02` for 2%)
+    pub max_slippage: Option<Decimal>,
+    /// Worst acceptable fill price (highest for a BUY, lowest for a SELL)
+    pub limit_price: Option<Decimal>,
+    /// What to do when the amount cannot be filled within the price cap
+    pub on_slippage: SlippageAction,
 }
 
 impl MarketOrderArgs {
@@ -41,8 +67,96 @@ impl MarketOrderArgs {
             token_id: token_id.into(),
             amount,
             side,
+            max_slippage: None,
+            limit_price: None,
+            on_slippage: SlippageAction::Reject,
+        }
+    }
+
+    pub fn max_slippage(mut self, max_slippage: Decimal) -> Self {
+        self.max_slippage = Some(max_slippage);
+        self
+    }
+
+    pub fn limit_price(mut self, limit_price: Decimal) -> Self {
+        self.limit_price = Some(limit_price);
+        self
+    }
+
+    pub fn on_slippage(mut self, action: SlippageAction) -> Self {
+        self.on_slippage = action;
+        self
+    }
+}
+
+/// How the size of a ladder is set
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LadderSizing {
+    /// USDC notional to spread over all levels by their weights
+    TotalNotional(Decimal),
+    /// Shares at a level of weight 1; every level gets this size times its weight
+    PerLevel(Decimal),
+}
+
+/// How size is weighted across the levels of a ladder, from the start price
+/// to the end price
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SizeDistribution {
+    /// Weights step linearly from 1 at the start price to `last_weight` at
+    /// the end price; a `last_weight` of 1 gives every level the same size
+    Linear { last_weight: Decimal },
+    /// Each level weighs `ratio` times the one before it
+    Geometric { ratio: Decimal },
+}
+
+impl Default for SizeDistribution {
+    fn default() -> Self {
+        Self::Linear {
+            last_weight: Decimal::ONE,
+        }
+    }
+}
+
+/// Arguments for a ladder of limit orders between two prices
+///
+/// `levels` prices are spaced evenly from `start_price` to `end_price`, both
+/// included, and sized by `sizing` and `distribution`. Either end may be the
+/// higher price; sizes are weighted starting from `start_price`.
+#[derive(Debug, Clone)]
+pub struct LadderArgs {
+    pub token_id: String,
+    pub side: Side,
+    pub start_price: Decimal,
+    pub end_price: Decimal,
+    pub levels: usize,
+    pub sizing: LadderSizing,
+    pub distribution: SizeDistribution,
+}
+
+impl LadderArgs {
+    pub fn new(
+        token_id: impl Into<String>,
+        side: Side,
+        start_price: Decimal,
+        end_price: Decimal,
+        levels: usize,
+        sizing: LadderSizing,
+    ) -> Self {
+        Self {
+            token_id: token_id.into(),
+            side,
+            start_price,
+            end_price,
+            levels,
+            sizing,
+            distribution: SizeDistribution::default(),
         }
     }
+
+    pub fn distribution(mut self, distribution: SizeDistribution) -> Self {
+        self.distribution = distribution;
+        self
+    }
 }
 
 /// Extra optional arguments for order creation
@@ -126,6 +240,91 @@ pub struct SignedOrderRequest {
     pub signature: String,
 }
 
+impl SignedOrderRequest {
+    /// Scale of the fixed-point maker and taker amounts
+    const AMOUNT_DECIMALS: u32 = 6;
+
+    /// Decode the `side` field
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidOrder`] if it is not `BUY` or `SELL`.
+    pub fn order_side(&self) -> Result<Side> {
+        match self.side.as_str() {
+            "BUY" => Ok(Side::Buy),
+            "SELL" => Ok(Side::Sell),
+            other => Err(Error::InvalidOrder(format!("Invalid order side {}", other))),
+        }
+    }
+
+    /// Order size in shares, decoded from the signed amounts
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidOrder`] if the side or amounts cannot be decoded.
+    pub fn size(&self) -> Result<Decimal> {
+        Ok(self.decode_amounts()?.1)
+    }
+
+    /// Price in USDC per share implied by the signed amounts
+    ///
+    /// Amounts are rounded when an order is built, so this may be slightly off
+    /// the tick grid; round it to the market's tick size where that matters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidOrder`] if the side or amounts cannot be
+    /// decoded, or the size is zero.
+    pub fn price(&self) -> Result<Decimal> {
+        let (usdc, shares) = self.decode_amounts()?;
+        if shares.is_zero() {
+            return Err(Error::InvalidOrder("Order size is zero".to_string()));
+        }
+        Ok(usdc / shares)
+    }
+
+    /// EIP-712 hash of the signed order, which the exchange uses as its ID
+    ///
+    /// Known before posting, so it can be matched against
+    /// `PostOrderResponse.order_id`, `OrderEvent.id` and on-chain
+    /// `OrderFilled` logs, or used to check whether a retried post already
+    /// went through.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id` - Chain the order was signed for
+    /// * `neg_risk` - Whether the order targets the neg risk exchange
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidOrder`] if a field cannot be decoded, or an
+    /// error if there is no exchange for `chain_id`.
+    pub fn order_hash(&self, chain_id: u64, neg_risk: bool) -> Result<OrderId> {
+        let order = crate::signing::order_from_request(self)?;
+        let hash = crate::signing::order_hash(&order, chain_id, neg_risk)?;
+        Ok(OrderId::new(hash.to_string()))
+    }
+
+    /// USDC and share amounts, in that order
+    fn decode_amounts(&self) -> Result<(Decimal, Decimal)> {
+        let parse = |amount: &str| {
+            amount
+                .parse::<u64>()
+                .ok()
+                .and_then(|units| i64::try_from(units).ok())
+                .map(|units| Decimal::new(units, Self::AMOUNT_DECIMALS))
+                .ok_or_else(|| Error::InvalidOrder(format!("Invalid order amount {}", amount)))
+        };
+        let maker = parse(&self.maker_amount)?;
+        let taker = parse(&self.taker_amount)?;
+
+        Ok(match self.order_side()? {
+            Side::Buy => (maker, taker),
+            Side::Sell => (taker, maker),
+        })
+    }
+}
+
 /// Order to be posted to the API
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -227,7 +426,7 @@ impl OpenOrderParams {
 }
 
 /// Price level in order book (price and size pair)
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PriceLevel {
     /// Price at this level
     #[serde(with = "rust_decimal::serde::str")]
@@ -238,7 +437,7 @@ pub struct PriceLevel {
 }
 
 /// Order book summary with bids and asks
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct OrderBookSummary {
     pub market: String,
     pub asset_id: String,
@@ -261,21 +460,72 @@ impl OrderBookSummary {
         )
     }
 
+    /// Walk the book for a market order amount
+    ///
+    /// A BUY `amount` is USDC notional and walks the asks; a SELL `amount` is
+    /// shares and walks the bids.
+    pub fn walk_market_order(&self, side: Side, amount: Decimal) -> Result<BookWalk> {
+        walk_market_order(
+            match side {
+                Side::Buy => &self.asks,
+                Side::Sell => &self.bids,
+            },
+            amount,
+            side,
+        )
+    }
+
     pub fn is_empty(&self) -> bool {
         self.bids.is_empty() && self.asks.is_empty()
     }
 
+    /// Highest bid, without sorting or cloning the levels
+    pub fn best_bid(&self) -> Option<&PriceLevel> {
+        self.bids.iter().max_by_key(|l| l.price)
+    }
+
+    /// Lowest ask, without sorting or cloning the levels
+    pub fn best_ask(&self) -> Option<&PriceLevel> {
+        self.asks.iter().min_by_key(|l| l.price)
+    }
+
     pub fn sort_bids(&self) -> Vec<PriceLevel> {
         let mut bids = self.bids.clone();
-        bids.sort_by(|a, b| b.price.cmp(&a.price));
+        bids.sort_by_key(|l| std::cmp::Reverse(l.price));
         bids
     }
 
     pub fn sort_asks(&self) -> Vec<PriceLevel> {
         let mut asks = self.asks.clone();
-        asks.sort_by(|a, b| a.price.cmp(&b.price));
+        asks.sort_by_key(|l| l.price);
         asks
     }
+
+    /// Recompute the server hash for this summary
+    ///
+    /// Levels are hashed in the order the server returned them. See
+    /// [`compute_book_hash`] for the algorithm.
+    pub fn compute_hash(&self) -> String {
+        compute_book_hash(
+            &self.market,
+            &self.asset_id,
+            &self.timestamp.to_string(),
+            &self.bids,
+            &self.asks,
+        )
+    }
+
+    /// Check that the `hash` sent by the server matches the summary contents
+    pub fn verify_hash(&self) -> bool {
+        hashes_match(&self.compute_hash(), &self.hash)
+    }
+
+    /// Convert into a [`LocalOrderBook`] with sorted levels
+    ///
+    /// Prefer this over repeated calls to `sort_bids`/`sort_asks`.
+    pub fn to_local_book(&self) -> LocalOrderBook {
+        LocalOrderBook::from_summary(self)
+    }
 }
 
 /// Parameters for querying order book
@@ -330,3 +580,81 @@ pub struct CancelOrdersResponse {
     pub canceled: Vec<OrderId>,
     pub not_canceled: serde_json::Value,
 }
+
+impl CancelOrdersResponse {
+    /// Whether `order_id` was cancelled, or the reason it was not
+    pub fn result_for(&self, order_id: &OrderId) -> Result<()> {
+        if self.canceled.contains(order_id) {
+            return Ok(());
+        }
+        let reason = self
+            .not_canceled
+            .get(order_id.as_str())
+            .and_then(|r| r.as_str())
+            .unwrap_or("not in the cancelled set");
+        Err(Error::InvalidOrder(format!(
+            "Order {} not canceled: {}",
+            order_id, reason
+        )))
+    }
+}
+
+/// Order in which [`replace_order`](crate::client::TradingClient::replace_order)
+/// cancels the old order and posts the new one
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ReplaceMode {
+    /// Cancel the old order, then post the new one only if the cancel went
+    /// through. Never has both orders live, but leaves a gap with neither.
+    #[default]
+    CancelFirst,
+    /// Post the new order, then cancel the old one only if the post was
+    /// accepted. Keeps a quote on the book throughout, but both orders are
+    /// live until the cancel lands, or afterwards if it fails.
+    PostFirst,
+}
+
+/// Outcome of a [`replace_order`](crate::client::TradingClient::replace_order)
+///
+/// Each step is recorded separately so partial failures can be acted on, for
+/// example a cancel that went through followed by a rejected post.
+#[derive(Debug)]
+pub struct ReplaceOutcome {
+    /// Order in which the cancel and the post were attempted
+    pub mode: ReplaceMode,
+    /// The order being replaced
+    pub old_order_id: OrderId,
+    /// Result of cancelling the old order; `None` if it was not attempted
+    /// because the post failed in [`ReplaceMode::PostFirst`]
+    pub cancel: Option<Result<()>>,
+    /// Result of posting the new order; `None` if it was not attempted
+    /// because the cancel failed in [`ReplaceMode::CancelFirst`]. A post the
+    /// exchange answered with `success: false` is an error here.
+    pub post: Option<Result<PostOrderResponse>>,
+    /// The old order as read back after the cancel, with its fill state
+    pub old_order: Result<OpenOrder>,
+}
+
+impl ReplaceOutcome {
+    /// Whether the old order was cancelled and the new one accepted
+    pub fn is_replaced(&self) -> bool {
+        self.is_canceled() && self.new_order_id().is_some()
+    }
+
+    /// Whether the old order was cancelled
+    pub fn is_canceled(&self) -> bool {
+        matches!(self.cancel, Some(Ok(())))
+    }
+
+    /// ID of the new order, if it was accepted
+    pub fn new_order_id(&self) -> Option<&OrderId> {
+        match &self.post {
+            Some(Ok(response)) => Some(&response.order_id),
+            _ => None,
+        }
+    }
+
+    /// Size of the old order that was filled, if it could be read back
+    pub fn old_size_matched(&self) -> Option<Decimal> {
+        self.old_order.as_ref().ok().map(|o| o.size_matched)
+    }
+}
@@ -74,7 +74,7 @@ where
             }
 
             // Try RFC3339 first
-            if let Ok(dt) = DateTime::parse_from_rfc3339(&s) {
+            if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
                 return Ok(Some(dt.with_timezone(&Utc)));
             }
 
@@ -94,7 +94,7 @@ where
             }
 
             // Try date-only format: "2022-07-27"
-            if let Ok(date) = NaiveDate::parse_from_str(&s, "%Y-%m-%d") {
+            if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
                 let dt = date
                     .and_hms_opt(0, 0, 0)
                     .ok_or_else(|| serde::de::Error::custom("invalid date"))?
@@ -74,6 +74,9 @@ pub struct PriceChange {
     /// New size at this price level (0 means remove the level)
     #[serde(with = "rust_decimal::serde::str")]
     pub size: Decimal,
+    /// Hash of the order book after this change (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
 }
 
 /// Last trade price event
@@ -128,6 +131,7 @@ pub struct TickSizeChangeEvent {
 /// Websocket event from the authenticated user stream
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
 pub enum UserWsEvent {
     /// Trade execution event
     Trade(TradeEvent),
@@ -15,6 +15,14 @@ pub fn get_current_unix_time_secs() -> Result<u64> {
         .map_err(|e| Error::Config(format!("System time error: {}", e)))
 }
 
+/// Get current Unix timestamp in milliseconds
+pub fn get_current_unix_time_millis() -> Result<u64> {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .map_err(|e| Error::Config(format!("System time error: {}", e)))
+}
+
 /// Build HMAC-SHA256 signature for L2 authentication
 ///
 /// This generates the signature required for authenticated API requests
@@ -29,6 +37,19 @@ pub fn build_hmac_signature<T>(
 where
     T: ?Sized + Serialize,
 {
+    // Use compact JSON (no spaces) like standard JSON.stringify
+    let body = body.map(serde_json::to_string).transpose()?;
+    build_hmac_signature_raw(secret, timestamp, method, req_path, body.as_deref())
+}
+
+/// Build the L2 HMAC signature over an already serialized body
+pub fn build_hmac_signature_raw(
+    secret: &str,
+    timestamp: u64,
+    method: &str,
+    req_path: &str,
+    body: Option<&str>,
+) -> Result<String> {
     // Decode the base64-encoded secret
     let decoded = URL_SAFE
         .decode(secret)
@@ -37,11 +58,7 @@ where
     // Build the message to sign
     let message = match body {
         None => format!("{timestamp}{method}{req_path}"),
-        Some(s) => {
-            // Use compact JSON (no spaces) like standard JSON.stringify
-            let serialized = serde_json::to_string(&s)?;
-            format!("{timestamp}{method}{req_path}{serialized}")
-        }
+        Some(body) => format!("{timestamp}{method}{req_path}{body}"),
     };
 
     // Create HMAC
@@ -1,9 +1,10 @@
-use futures_util::{SinkExt, Stream, StreamExt};
+use futures_util::{stream, SinkExt, Stream, StreamExt};
 use std::pin::Pin;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 
+use super::raw::{raw_ws_message, RawMessage};
 use crate::error::{Error, Result};
 use crate::types::{MarketSubscription, WsEvent};
 
@@ -46,75 +47,76 @@ pub struct MarketWsClient {
     ws_url: String,
 }
 
-/// Parse a WebSocket message into a WsEvent
+/// Parse the text of a market channel message into WsEvents
+///
+/// The server batches events into a JSON array (e.g. one book snapshot per
+/// subscribed token), so a single message can produce several events.
+pub(crate) fn parse_market_text(text: &str) -> Vec<Result<WsEvent>> {
+    // Skip empty or whitespace-only messages
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return Vec::new();
+    }
+
+    // Skip PING/PONG messages sent as text (some servers do this)
+    if trimmed.eq_ignore_ascii_case("ping") || trimmed.eq_ignore_ascii_case("pong") {
+        return Vec::new();
+    }
+
+    // The server can send either a single object or an array
+    // Try to parse as array first
+    if let Ok(events) = serde_json::from_str::<Vec<serde_json::Value>>(text) {
+        // Got an array, emit every event (empty arrays are ignored)
+        return events
+            .into_iter()
+            .map(|value| serde_json::from_value::<WsEvent>(value).map_err(Error::Json))
+            .collect();
+    }
+
+    // Try parsing as single object
+    match serde_json::from_str::<WsEvent>(text) {
+        Ok(event) => vec![Ok(event)],
+        Err(e) => {
+            // Log unexpected message format for debugging
+            log::warn!(
+                "Unexpected WebSocket message (first 200 chars): {}",
+                &text.chars().take(200).collect::<String>()
+            );
+            vec![Err(Error::Json(e))]
+        }
+    }
+}
+
+/// Parse a WebSocket message into WsEvents
 ///
 /// This is a helper function that handles the parsing logic shared by both
 /// subscribe() and subscribe_with_handle() methods.
 fn parse_ws_message(
     msg: std::result::Result<Message, tokio_tungstenite::tungstenite::Error>,
-) -> Option<Result<WsEvent>> {
+) -> Vec<Result<WsEvent>> {
     match msg {
-        Ok(Message::Text(text)) => {
-            // Skip empty or whitespace-only messages
-            let trimmed = text.trim();
-            if trimmed.is_empty() {
-                return None;
-            }
-
-            // Skip PING/PONG messages sent as text (some servers do this)
-            if trimmed.eq_ignore_ascii_case("ping") || trimmed.eq_ignore_ascii_case("pong") {
-                return None;
-            }
-
-            // The server can send either a single object or an array
-            // Try to parse as array first
-            if let Ok(events) = serde_json::from_str::<Vec<serde_json::Value>>(&text) {
-                // Got an array, take the first event
-                if let Some(first) = events.first() {
-                    match serde_json::from_value::<WsEvent>(first.clone()) {
-                        Ok(event) => return Some(Ok(event)),
-                        Err(e) => return Some(Err(Error::Json(e))),
-                    }
-                } else {
-                    // Empty array, ignore
-                    return None;
-                }
-            }
-
-            // Try parsing as single object
-            match serde_json::from_str::<WsEvent>(&text) {
-                Ok(event) => Some(Ok(event)),
-                Err(e) => {
-                    // Log unexpected message format for debugging
-                    log::warn!(
-                        "Unexpected WebSocket message (first 200 chars): {}",
-                        &text.chars().take(200).collect::<String>()
-                    );
-                    Some(Err(Error::Json(e)))
-                }
-            }
-        }
+        Ok(Message::Text(text)) => parse_market_text(&text),
         Ok(Message::Close(_)) => {
             // Connection closed gracefully
-            Some(Err(Error::ConnectionClosed))
+            vec![Err(Error::ConnectionClosed)]
         }
         Ok(Message::Ping(_)) | Ok(Message::Pong(_)) => {
             // Ignore ping/pong frames (handled automatically)
-            None
+            Vec::new()
         }
         Ok(Message::Binary(_)) => {
             // Unexpected binary message
-            Some(Err(Error::WebSocket(
+            vec![Err(Error::WebSocket(
                 "Unexpected binary message".to_string(),
-            )))
+            ))]
         }
         Ok(Message::Frame(_)) => {
             // Raw frame (shouldn't happen)
-            None
+            Vec::new()
         }
         Err(e) => {
             // WebSocket error
-            Some(Err(Error::WebSocket(e.to_string())))
+            vec![Err(Error::WebSocket(e.to_string()))]
         }
     }
 }
@@ -203,7 +205,7 @@ impl MarketWsClient {
         let handle = SubscriptionHandle { current_tokens };
 
         // Return stream that parses events using the shared helper function
-        let stream = read.filter_map(|msg| async move { parse_ws_message(msg) });
+        let stream = read.flat_map(|msg| stream::iter(parse_ws_message(msg)));
 
         Ok((Box::pin(stream), handle))
     }
@@ -260,7 +262,51 @@ impl MarketWsClient {
         drop(write);
 
         // Return stream that parses events using the shared helper function
-        let stream = read.filter_map(|msg| async move { parse_ws_message(msg) });
+        let stream = read.flat_map(|msg| stream::iter(parse_ws_message(msg)));
+
+        Ok(Box::pin(stream))
+    }
+
+    /// Subscribe to market updates and receive the raw message text
+    ///
+    /// Each item is a text message exactly as the server sent it, stamped with
+    /// the local receive time. This is the stream to record with a
+    /// [`Recorder`](crate::recording::Recorder), which can't record the parsed
+    /// events of [`subscribe`](Self::subscribe); use
+    /// [`RawMessage::market_events`] to parse an item into [`WsEvent`]s.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_ids` - List of token/asset IDs to subscribe to
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The WebSocket connection fails
+    /// - The subscription message cannot be sent
+    pub async fn subscribe_raw(
+        &self,
+        token_ids: Vec<String>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<RawMessage>> + Send>>> {
+        // Connect to the WebSocket endpoint
+        let (ws_stream, _) = connect_async(&self.ws_url).await?;
+
+        let (mut write, read) = ws_stream.split();
+
+        let subscription_msg = serde_json::to_string(&MarketSubscription {
+            assets_ids: token_ids,
+        })?;
+
+        // Send subscription message
+        write
+            .send(Message::Text(subscription_msg))
+            .await
+            .map_err(|e| Error::WebSocket(e.to_string()))?;
+
+        // Drop the write half since we don't need to send any more messages
+        drop(write);
+
+        let stream = read.filter_map(|msg| async move { raw_ws_message(msg) });
 
         Ok(Box::pin(stream))
     }
@@ -282,6 +328,17 @@ mod tests {
         assert_eq!(client.ws_url, MarketWsClient::DEFAULT_WS_URL);
     }
 
+    #[test]
+    fn test_parse_batched_message() {
+        let text = r#"[
+            {"event_type":"book","market":"m","asset_id":"1","timestamp":"1","hash":"h","bids":[],"asks":[]},
+            {"event_type":"book","market":"m","asset_id":"2","timestamp":"1","hash":"h","bids":[],"asks":[]}
+        ]"#;
+        let events = parse_ws_message(Ok(Message::Text(text.to_string())));
+        assert_eq!(events.len(), 2);
+        assert!(matches!(&events[1], Ok(WsEvent::Book(b)) if b.asset_id == "2"));
+    }
+
     #[test]
     fn test_client_with_custom_url() {
         let custom_url = "wss://custom.example.com/ws";
@@ -4,6 +4,9 @@
 //! - [`MarketWsClient`]: Streams real-time order book updates for markets
 //! - [`UserWsClient`]: Streams authenticated user events (trades and order updates)
 //!
+//! Both clients can also stream [`RawMessage`]s, the unparsed message text with a
+//! local receive timestamp, for recording sessions.
+//!
 //! # Connection Management
 //!
 //! The Polymarket WebSocket server may disconnect idle connections after 1-2 minutes.
@@ -11,10 +14,12 @@
 //! handle disconnections and reconnect with exponential backoff.
 
 mod market;
+mod raw;
 mod stream;
 mod user;
 
 pub use market::{MarketWsClient, SubscriptionHandle};
+pub use raw::RawMessage;
 pub use stream::{ReconnectConfig, ReconnectingStream};
 pub use user::UserWsClient;
 
@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+use tokio_tungstenite::tungstenite::Message;
+
+use super::market::parse_market_text;
+use super::user::parse_user_text;
+use crate::error::{Error, Result};
+use crate::types::{UserWsEvent, WsEvent};
+use crate::utils::get_current_unix_time_millis;
+
+/// A WebSocket text message as received, before parsing
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RawMessage {
+    /// Local receive time, Unix milliseconds
+    pub received_at: u64,
+    /// Message text exactly as sent by the server
+    pub text: String,
+}
+
+impl RawMessage {
+    /// Create a message with an explicit receive time
+    pub fn new(received_at: u64, text: impl Into<String>) -> Self {
+        Self {
+            received_at,
+            text: text.into(),
+        }
+    }
+
+    /// Create a message stamped with the current time
+    pub fn received_now(text: impl Into<String>) -> Self {
+        Self::new(get_current_unix_time_millis().unwrap_or_default(), text)
+    }
+
+    /// Parse the message as market channel events
+    pub fn market_events(&self) -> Vec<Result<WsEvent>> {
+        parse_market_text(&self.text)
+    }
+
+    /// Parse the message as user channel events
+    pub fn user_events(&self) -> Vec<Result<UserWsEvent>> {
+        parse_user_text(&self.text)
+    }
+}
+
+/// Convert a WebSocket message into a [`RawMessage`]
+///
+/// Control frames and empty or PING/PONG text messages are skipped.
+pub(crate) fn raw_ws_message(
+    msg: std::result::Result<Message, tokio_tungstenite::tungstenite::Error>,
+) -> Option<Result<RawMessage>> {
+    match msg {
+        Ok(Message::Text(text)) => {
+            let trimmed = text.trim();
+            if trimmed.is_empty()
+                || trimmed.eq_ignore_ascii_case("ping")
+                || trimmed.eq_ignore_ascii_case("pong")
+            {
+                return None;
+            }
+            Some(Ok(RawMessage::received_now(text)))
+        }
+        Ok(Message::Close(_)) => Some(Err(Error::ConnectionClosed)),
+        Ok(Message::Ping(_)) | Ok(Message::Pong(_)) | Ok(Message::Frame(_)) => None,
+        Ok(Message::Binary(_)) => Some(Err(Error::WebSocket(
+            "Unexpected binary message".to_string(),
+        ))),
+        Err(e) => Some(Err(Error::WebSocket(e.to_string()))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raw_message_keeps_text_and_skips_keepalives() {
+        assert!(raw_ws_message(Ok(Message::Text("PONG".to_string()))).is_none());
+        assert!(raw_ws_message(Ok(Message::Ping(vec![]))).is_none());
+
+        let text = r#"[{"event_type":"book","market":"m","asset_id":"1","timestamp":"1","hash":"h","bids":[],"asks":[]}]"#;
+        let raw = raw_ws_message(Ok(Message::Text(text.to_string())))
+            .unwrap()
+            .unwrap();
+        assert_eq!(raw.text, text);
+        assert!(raw.received_at > 0);
+        assert!(matches!(&raw.market_events()[..], [Ok(WsEvent::Book(_))]));
+    }
+}
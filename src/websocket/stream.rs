@@ -34,6 +34,7 @@ impl Default for ReconnectConfig {
 /// Exponential backoff calculator
 #[derive(Debug, Clone)]
 struct ExponentialBackoff {
+    initial_delay: Duration,
     current_delay: Duration,
     max_delay: Duration,
     multiplier: f64,
@@ -42,6 +43,7 @@ struct ExponentialBackoff {
 impl ExponentialBackoff {
     fn new(initial_delay: Duration, max_delay: Duration, multiplier: f64) -> Self {
         Self {
+            initial_delay,
             current_delay: initial_delay,
             max_delay,
             multiplier,
@@ -60,7 +62,7 @@ impl ExponentialBackoff {
 
     /// Reset the backoff to initial delay
     fn reset(&mut self) {
-        self.current_delay = Duration::from_secs(1);
+        self.current_delay = self.initial_delay;
     }
 }
 
@@ -133,6 +135,8 @@ where
     backoff: ExponentialBackoff,
     /// Sleep future for reconnection delay
     sleep_future: Option<Pin<Box<tokio::time::Sleep>>>,
+    /// Number of successful connections made so far
+    connection_count: u32,
 }
 
 impl<T, S, F, Fut> ReconnectingStream<T, S, F, Fut>
@@ -163,26 +167,41 @@ where
             config,
             backoff,
             sleep_future: None,
+            connection_count: 0,
         }
     }
 
+    /// Number of successful connections made so far
+    ///
+    /// This is `1` after the initial connection and increases by one on every
+    /// reconnect. Consumers that keep derived state (such as a local order book)
+    /// can compare it between items to detect that a reconnect happened and
+    /// the state may be stale.
+    pub fn connection_count(&self) -> u32 {
+        self.connection_count
+    }
+
     /// Handle a disconnection and prepare for reconnection
-    fn handle_disconnection(&mut self, attempts: u32) -> Poll<Option<Result<T>>> {
+    ///
+    /// Returns the final item to yield if reconnection has been given up,
+    /// otherwise `None` so the caller keeps polling and the backoff sleep
+    /// registers a wakeup.
+    fn handle_disconnection(&mut self, attempts: u32) -> Option<Poll<Option<Result<T>>>> {
         // Check if we've exceeded max attempts
         if let Some(max) = self.config.max_attempts {
             if attempts >= max {
                 self.state = StreamState::Terminated;
-                return Poll::Ready(Some(Err(Error::ReconnectFailed {
+                return Some(Poll::Ready(Some(Err(Error::ReconnectFailed {
                     attempts,
                     last_error: "Maximum reconnection attempts reached".to_string(),
-                })));
+                }))));
             }
         }
 
         let delay = self.backoff.next_delay();
         self.state = StreamState::Reconnecting { attempts, delay };
         self.sleep_future = Some(Box::pin(sleep(delay)));
-        Poll::Pending
+        None
     }
 }
 
@@ -206,7 +225,10 @@ where
                         }
                         Poll::Ready(Some(Err(Error::ConnectionClosed))) => {
                             // Connection closed, prepare to reconnect
-                            return self.handle_disconnection(1);
+                            if let Some(poll) = self.handle_disconnection(1) {
+                                return poll;
+                            }
+                            continue;
                         }
                         Poll::Ready(Some(Err(e))) => {
                             // Other error, pass through and prepare to reconnect
@@ -215,7 +237,10 @@ where
                         }
                         Poll::Ready(None) => {
                             // Stream ended, prepare to reconnect
-                            return self.handle_disconnection(1);
+                            if let Some(poll) = self.handle_disconnection(1) {
+                                return poll;
+                            }
+                            continue;
                         }
                         Poll::Pending => {
                             return Poll::Pending;
@@ -262,6 +287,7 @@ where
                     match boxed_fut.as_mut().poll(cx) {
                         Poll::Ready(Ok(stream)) => {
                             self.state = StreamState::Connected(stream);
+                            self.connection_count += 1;
                             self.backoff.reset();
                             continue;
                         }
@@ -269,7 +295,10 @@ where
                             // Connection failed, prepare to reconnect
                             // Increment attempts (or start at 1 if this is the first attempt)
                             let next_attempts = if current_attempts == 0 { 1 } else { current_attempts + 1 };
-                            return self.handle_disconnection(next_attempts);
+                            if let Some(poll) = self.handle_disconnection(next_attempts) {
+                                return poll;
+                            }
+                            continue;
                         }
                         Poll::Pending => {
                             // Store the future for next poll
@@ -337,4 +366,41 @@ mod tests {
 
         assert_eq!(backoff.next_delay(), Duration::from_secs(1));
     }
+
+    #[test]
+    fn test_backoff_reset_uses_initial_delay() {
+        let mut backoff = ExponentialBackoff::new(
+            Duration::from_millis(10),
+            Duration::from_secs(60),
+            2.0,
+        );
+
+        backoff.next_delay();
+        backoff.next_delay();
+        backoff.reset();
+
+        assert_eq!(backoff.next_delay(), Duration::from_millis(10));
+    }
+
+    #[tokio::test]
+    async fn test_connection_count_increments_on_reconnect() {
+        use futures_util::StreamExt;
+
+        let config = ReconnectConfig {
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(1),
+            multiplier: 1.0,
+            max_attempts: None,
+        };
+        // Every connection yields a single item and then ends
+        let mut stream = ReconnectingStream::new(config, || async {
+            Ok(futures_util::stream::iter(vec![Ok::<_, Error>(1u32)]))
+        });
+
+        assert_eq!(stream.connection_count(), 0);
+        stream.next().await.unwrap().unwrap();
+        assert_eq!(stream.connection_count(), 1);
+        stream.next().await.unwrap().unwrap();
+        assert_eq!(stream.connection_count(), 2);
+    }
 }
@@ -1,7 +1,8 @@
-use futures_util::{SinkExt, Stream, StreamExt};
+use futures_util::{stream, SinkExt, Stream, StreamExt};
 use std::pin::Pin;
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 
+use super::raw::{raw_ws_message, RawMessage};
 use crate::error::{Error, Result};
 use crate::types::{ApiCreds, UserAuthentication, UserWsEvent};
 
@@ -183,66 +184,112 @@ impl UserWsClient {
             .map_err(|e| Error::WebSocket(e.to_string()))?;
 
         // Return stream that parses events
-        let stream = read.filter_map(|msg| async move {
-            match msg {
-                Ok(Message::Text(text)) => {
-                    // The server can send either a single object or an array
-                    // Try to parse as array first
-                    if let Ok(events) = serde_json::from_str::<Vec<serde_json::Value>>(&text) {
-                        // Got an array, take the first event
-                        if let Some(first) = events.first() {
-                            match serde_json::from_value::<UserWsEvent>(first.clone()) {
-                                Ok(event) => return Some(Ok(event)),
-                                Err(e) => return Some(Err(Error::Json(e))),
-                            }
-                        } else {
-                            // Empty array, ignore
-                            return None;
-                        }
-                    }
+        let stream = read.flat_map(|msg| stream::iter(parse_ws_message(msg)));
 
-                    // Try parsing as single object
-                    match serde_json::from_str::<UserWsEvent>(&text) {
-                        Ok(event) => Some(Ok(event)),
-                        Err(e) => Some(Err(Error::Json(e))),
-                    }
-                }
-                Ok(Message::Close(close_frame)) => {
-                    // Connection closed - may indicate auth failure
-                    if let Some(frame) = close_frame {
-                        Some(Err(Error::WebSocket(format!(
-                            "Connection closed: code={}, reason={}",
-                            frame.code, frame.reason
-                        ))))
-                    } else {
-                        Some(Err(Error::ConnectionClosed))
-                    }
-                }
-                Ok(Message::Ping(_)) | Ok(Message::Pong(_)) => {
-                    // Ignore ping/pong frames (handled automatically)
-                    None
-                }
-                Ok(Message::Binary(_)) => {
-                    // Unexpected binary message
-                    Some(Err(Error::WebSocket(
-                        "Unexpected binary message".to_string(),
-                    )))
-                }
-                Ok(Message::Frame(_)) => {
-                    // Raw frame (shouldn't happen)
-                    None
-                }
-                Err(e) => {
-                    // WebSocket error
-                    Some(Err(Error::WebSocket(e.to_string())))
-                }
-            }
-        });
+        Ok(Box::pin(stream))
+    }
+
+    /// Subscribe to user events and receive the raw message text
+    ///
+    /// Each item is a text message exactly as the server sent it, stamped with
+    /// the local receive time. This is the stream to record with a
+    /// [`Recorder`](crate::recording::Recorder), which can't record the parsed
+    /// events of [`subscribe`](Self::subscribe); use
+    /// [`RawMessage::user_events`] to parse an item into [`UserWsEvent`]s.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The WebSocket connection fails
+    /// - The authentication message cannot be sent
+    pub async fn subscribe_raw(
+        &self,
+        creds: &ApiCreds,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<RawMessage>> + Send>>> {
+        // Connect to the WebSocket endpoint
+        let (ws_stream, _) = connect_async(&self.ws_url).await?;
+
+        let (mut write, read) = ws_stream.split();
+
+        let auth = UserAuthentication::new(
+            creds.api_key.clone(),
+            creds.secret.clone(),
+            creds.passphrase.clone(),
+        );
+        let auth_msg = serde_json::to_string(&auth)?;
+
+        // Send authentication message
+        write
+            .send(Message::Text(auth_msg))
+            .await
+            .map_err(|e| Error::WebSocket(e.to_string()))?;
+
+        let stream = read.filter_map(|msg| async move { raw_ws_message(msg) });
 
         Ok(Box::pin(stream))
     }
 }
 
+/// Parse the text of a user channel message into UserWsEvents
+///
+/// The server can batch events into a JSON array, so a single message can
+/// produce several events.
+pub(crate) fn parse_user_text(text: &str) -> Vec<Result<UserWsEvent>> {
+    // The server can send either a single object or an array
+    // Try to parse as array first
+    if let Ok(events) = serde_json::from_str::<Vec<serde_json::Value>>(text) {
+        // Got an array, emit every event (empty arrays are ignored)
+        return events
+            .into_iter()
+            .map(|value| serde_json::from_value::<UserWsEvent>(value).map_err(Error::Json))
+            .collect();
+    }
+
+    // Try parsing as single object
+    match serde_json::from_str::<UserWsEvent>(text) {
+        Ok(event) => vec![Ok(event)],
+        Err(e) => vec![Err(Error::Json(e))],
+    }
+}
+
+/// Parse a WebSocket message into UserWsEvents
+fn parse_ws_message(
+    msg: std::result::Result<Message, tokio_tungstenite::tungstenite::Error>,
+) -> Vec<Result<UserWsEvent>> {
+    match msg {
+        Ok(Message::Text(text)) => parse_user_text(&text),
+        Ok(Message::Close(close_frame)) => {
+            // Connection closed - may indicate auth failure
+            if let Some(frame) = close_frame {
+                vec![Err(Error::WebSocket(format!(
+                    "Connection closed: code={}, reason={}",
+                    frame.code, frame.reason
+                )))]
+            } else {
+                vec![Err(Error::ConnectionClosed)]
+            }
+        }
+        Ok(Message::Ping(_)) | Ok(Message::Pong(_)) => {
+            // Ignore ping/pong frames (handled automatically)
+            Vec::new()
+        }
+        Ok(Message::Binary(_)) => {
+            // Unexpected binary message
+            vec![Err(Error::WebSocket(
+                "Unexpected binary message".to_string(),
+            ))]
+        }
+        Ok(Message::Frame(_)) => {
+            // Raw frame (shouldn't happen)
+            Vec::new()
+        }
+        Err(e) => {
+            // WebSocket error
+            vec![Err(Error::WebSocket(e.to_string()))]
+        }
+    }
+}
+
 impl Default for UserWsClient {
     fn default() -> Self {
         Self::new()
@@ -258,4 +305,39 @@ mod tests {
         let client = UserWsClient::new();
         assert_eq!(client.ws_url, UserWsClient::DEFAULT_WS_URL);
     }
+
+    #[test]
+    fn test_parse_user_text_emits_every_batched_event() {
+        let order = |id: &str| {
+            serde_json::json!({
+                "event_type": "order",
+                "id": id,
+                "market": "m",
+                "asset_id": "1",
+                "side": "BUY",
+                "original_size": "10",
+                "size_matched": "0",
+                "price": "0.5",
+                "outcome": "Yes",
+                "type": "PLACEMENT",
+                "order_type": "GTC",
+                "status": "LIVE",
+                "maker_address": "0x0"
+            })
+        };
+        let ids = |text: &str| -> Vec<String> {
+            parse_user_text(text)
+                .into_iter()
+                .map(|event| match event.unwrap() {
+                    UserWsEvent::Order(order) => order.id,
+                    other => panic!("expected an order event, got {:?}", other),
+                })
+                .collect()
+        };
+
+        let batch = serde_json::json!([order("a"), order("b")]).to_string();
+        assert_eq!(ids(&batch), vec!["a", "b"]);
+        assert_eq!(ids(&order("c").to_string()), vec!["c"]);
+        assert!(ids("[]").is_empty());
+    }
 }